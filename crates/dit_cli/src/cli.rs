@@ -22,6 +22,26 @@ pub enum CommandKind {
     Reset(ResetSubcommand),
     Clear(ClearSubcommand),
     Config(ConfigSubcommand),
+    Diff(DiffSubcommand),
+    Tag(TagSubcommand),
+    Stash(StashSubcommand),
+    Gc(GcSubcommand),
+    Fsck(FsckSubcommand),
+    Repack(RepackSubcommand),
+    Remote(RemoteSubcommand),
+    Fetch(FetchSubcommand),
+    Push(PushSubcommand),
+    Clone(CloneSubcommand),
+    Serve(ServeSubcommand),
+    Reflog(ReflogSubcommand),
+    Undo(UndoSubcommand),
+    Op(OpSubcommand),
+    Rebase(RebaseSubcommand),
+    CherryPick(CherryPickSubcommand),
+    Revert(RevertSubcommand),
+    Keygen(KeygenSubcommand),
+    VerifyCommit(VerifyCommitSubcommand),
+    Recover(RecoverSubcommand),
 }
 
 impl CommandKind {
@@ -37,6 +57,26 @@ impl CommandKind {
             Self::Reset(cmd) => cmd.handle(),
             Self::Clear(cmd) => cmd.handle(),
             Self::Config(cmd) => cmd.handle(),
+            Self::Diff(cmd) => cmd.handle(),
+            Self::Tag(cmd) => cmd.handle(),
+            Self::Stash(cmd) => cmd.handle(),
+            Self::Gc(cmd) => cmd.handle(),
+            Self::Fsck(cmd) => cmd.handle(),
+            Self::Repack(cmd) => cmd.handle(),
+            Self::Remote(cmd) => cmd.handle(),
+            Self::Fetch(cmd) => cmd.handle(),
+            Self::Push(cmd) => cmd.handle(),
+            Self::Clone(cmd) => cmd.handle(),
+            Self::Serve(cmd) => cmd.handle(),
+            Self::Reflog(cmd) => cmd.handle(),
+            Self::Undo(cmd) => cmd.handle(),
+            Self::Op(cmd) => cmd.handle(),
+            Self::Rebase(cmd) => cmd.handle(),
+            Self::CherryPick(cmd) => cmd.handle(),
+            Self::Revert(cmd) => cmd.handle(),
+            Self::Keygen(cmd) => cmd.handle(),
+            Self::VerifyCommit(cmd) => cmd.handle(),
+            Self::Recover(cmd) => cmd.handle(),
         }
     }
 }
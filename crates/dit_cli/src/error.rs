@@ -9,6 +9,9 @@ pub enum DitCliError {
 
     #[error("Could not get current working directory")]
     CwdError,
+
+    #[error("The repository check found {0} problem(s)")]
+    FsckFailed(usize),
 }
 
 pub type CliResult<T> = Result<T, DitCliError>;
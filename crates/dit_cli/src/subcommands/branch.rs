@@ -1,7 +1,11 @@
 use crate::subcommands::HandleSubcommand;
 use crate::error::CliResult;
-use crate::success;
+use crate::{hint, info, success, warning};
 use clap::{Args, Subcommand};
+use console::style;
+use dit_core::api_models::checkout::CheckoutMode;
+use dit_core::api_models::merge::MergeResult;
+use dit_core::helpers::path_to_string;
 
 #[derive(Subcommand)]
 pub enum BranchCommand {
@@ -12,8 +16,14 @@ pub enum BranchCommand {
     Switch {
         name: String,
 
-        #[arg(long)]
+        #[arg(long, conflicts_with = "merge", help = "Throw away the staged and unstaged changes (implies --force)")]
         hard: bool,
+
+        #[arg(long, conflicts_with = "merge", help = "Overwrite the untracked files and local changes in the way")]
+        force: bool,
+
+        #[arg(long, help = "Carry the local changes over by merging them into the files of the branch")]
+        merge: bool,
     },
 
     Remove {
@@ -21,7 +31,10 @@ pub enum BranchCommand {
     },
 
     Merge {
-        from: String
+        from: String,
+
+        #[arg(long, help = "Overwrite the untracked files and local changes in the way")]
+        force: bool,
     }
 }
 
@@ -37,9 +50,11 @@ impl HandleSubcommand for BranchSubcommand {
     fn handle(self) -> CliResult<()> {
         match &self.command {
             BranchCommand::New { name } => self.handle_new(name),
-            BranchCommand::Switch { name, hard } => self.handle_switch(name, *hard),
+            BranchCommand::Switch { name, hard, force, merge } => {
+                self.handle_switch(name, *hard, checkout_mode(*hard || *force, *merge))
+            }
             BranchCommand::Remove { name } => self.handle_remove(name),
-            BranchCommand::Merge { from } => self.handle_merge(from),
+            BranchCommand::Merge { from, force } => self.handle_merge(from, checkout_mode(*force, false)),
         }
     }
 }
@@ -53,9 +68,9 @@ impl BranchSubcommand {
         Ok(())
     }
 
-    fn handle_switch(&self, name: &String, hard: bool) -> CliResult<()> {
+    fn handle_switch(&self, name: &String, hard: bool, mode: CheckoutMode) -> CliResult<()> {
         let mut dit = Self::require_dit()?;
-        dit.switch_branch(name, hard)?;
+        dit.switch_branch(name, hard, mode)?;
         success!("Switched to branch '{name}'");
         Ok(())
     }
@@ -67,11 +82,37 @@ impl BranchSubcommand {
         Ok(())
     }
 
-    fn handle_merge(&self, from: &String) -> CliResult<()> {
+    fn handle_merge(&self, from: &String, mode: CheckoutMode) -> CliResult<()> {
         let mut dit = Self::require_dit()?;
-        dit.merge_branch(from)?;
-        success!("Merged the branch '{from}' into the current branch");
+        match dit.merge_branch(from, mode)? {
+            MergeResult::UpToDate => {
+                info!("Already up to date with the branch '{from}'");
+            }
+            MergeResult::FastForward(commit) => {
+                success!("Fast-forwarded to the branch '{from}' (commit '{commit}')");
+            }
+            MergeResult::Merged(commit) => {
+                success!("Merged the branch '{from}' into the current branch (commit '{commit}')");
+            }
+            MergeResult::Conflicted(paths) => {
+                warning!("Merging the branch '{from}' stopped because of conflicts in:");
+                for path in paths {
+                    println!("\t{}", style(path_to_string(&path)).red().bold());
+                }
+                hint!("Resolve the conflicts, stage the files with `dit add` and run `dit commit`");
+            }
+        }
         Ok(())
     }
 }
 
+/// Maps the `--force` and `--merge` flags of the commands which update the working tree
+pub(super) fn checkout_mode(force: bool, merge: bool) -> CheckoutMode {
+    if force {
+        CheckoutMode::Force
+    } else if merge {
+        CheckoutMode::Merge
+    } else {
+        CheckoutMode::Safe
+    }
+}
@@ -0,0 +1,41 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::remote::short_hash;
+use crate::error::CliResult;
+use crate::{hint, success, warning};
+use clap::Args;
+use console::style;
+use dit_core::api_models::pick::PickResult;
+use dit_core::helpers::path_to_string;
+
+#[derive(Args)]
+pub struct CherryPickSubcommand {
+    #[arg(help = "Commit (or any other revision) whose changes to apply on top of HEAD")]
+    revision: String,
+}
+
+
+impl HandleSubcommand for CherryPickSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let result = dit.cherry_pick(&self.revision)?;
+        print_pick_result(result, "Applied the changes of", &self.revision);
+        Ok(())
+    }
+}
+
+/// Prints the outcome of a cherry-pick or a revert
+pub(super) fn print_pick_result(result: PickResult, action: &str, revision: &str) {
+    match result {
+        PickResult::Committed(commit) => {
+            success!("{action} '{revision}' (commit '{}')", short_hash(&commit));
+        }
+        PickResult::Conflicted { paths, message } => {
+            warning!("Could not apply '{revision}' because of conflicts in:");
+            for path in paths {
+                println!("\t{}", style(path_to_string(&path)).red().bold());
+            }
+            hint!("Resolve the conflicts, stage the files with `dit add` and commit them with the message:");
+            println!("\n{}\n", style(message).yellow());
+        }
+    }
+}
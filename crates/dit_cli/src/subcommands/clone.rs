@@ -0,0 +1,56 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use crate::{info, success};
+use clap::Args;
+use dit_core::Dit;
+use dit_core::helpers::NETWORK_URL_SCHEME;
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct CloneSubcommand {
+    #[arg(help = "Path or dit://host[:port] url of the repository to clone")]
+    url: String,
+
+    #[arg(help = "Directory to clone into. Defaults to the name of the cloned repository.")]
+    directory: Option<PathBuf>,
+}
+
+
+impl HandleSubcommand for CloneSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let directory = match self.directory {
+            Some(directory) => directory,
+            None => default_directory(&self.url),
+        };
+
+        let (dit, report) = Dit::clone_from(&self.url, &directory)?;
+
+        success!("Cloned '{}' into '{}' ({} objects)", self.url, directory.display(), report.objects);
+        match dit.get_branch()? {
+            Some(branch) if dit.get_head_commit()?.is_some() => info!("Checked out the branch '{branch}'"),
+            _ => info!("The cloned repository is empty"),
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Returns the name of the cloned repository: the last component of its path,
+/// or the host name of a `dit://` url
+fn default_directory(url: &str) -> PathBuf {
+    if let Some(address) = url.strip_prefix(NETWORK_URL_SCHEME) {
+        let host = address.trim_end_matches('/');
+        let host = match host.rfind(':') {
+            Some(port_start) if !host.ends_with(']') => &host[..port_start],
+            _ => host,
+        };
+        return PathBuf::from(host.trim_matches(['[', ']']).replace(':', "_"));
+    }
+
+    Path::new(url)
+        .canonicalize()
+        .ok()
+        .and_then(|path| path.file_name().map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from(url))
+}
@@ -5,19 +5,31 @@ use crate::success;
 
 #[derive(Args)]
 pub struct CommitSubcommand {
-    #[arg(short, long)]
-    message: String,
+    #[arg(short, long, help = "Commit message. The configured editor is opened if not given")]
+    message: Option<String>,
 
     #[arg(short, long)]
-    author: Option<String>
+    author: Option<String>,
+
+    #[arg(long, help = "Replace the HEAD commit, keeping its message and author unless new ones are given")]
+    amend: bool,
 }
 
 
 impl HandleSubcommand for CommitSubcommand {
     fn handle(self) -> CliResult<()> {
         let mut dit = Self::require_dit()?;
-        dit.commit(self.message, self.author)?;
-        success!("Committed the changes");
+        if self.amend {
+            dit.amend_commit(self.message, self.author)?;
+            success!("Amended the commit");
+        } else {
+            let message = match self.message {
+                Some(message) => message,
+                None => dit.edit_commit_message()?,
+            };
+            dit.commit(message, self.author)?;
+            success!("Committed the changes");
+        }
         Ok(())
     }
 }
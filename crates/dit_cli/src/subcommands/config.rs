@@ -3,6 +3,7 @@ use crate::subcommands::HandleSubcommand;
 use crate::{hint, info, success};
 use clap::{Args, Subcommand};
 use console::style;
+use std::path::PathBuf;
 
 impl HandleSubcommand for ConfigSubcommand {
     fn handle(self) -> CliResult<()> {
@@ -28,6 +29,33 @@ impl ConfigSubcommand {
                 dit.config_set_user_email(value)?;
                 success!("{msg}")
             }
+            ConfigSetCommand::CoreEditor { value } => {
+                let msg = format!("Set core.editor to {value}");
+                dit.config_set_editor(value)?;
+                success!("{msg}")
+            }
+            ConfigSetCommand::CommitMaxSubjectLength { value } => {
+                dit.config_set_max_subject_length(value)?;
+                success!("Set commit.max_subject_length to {value}")
+            }
+            ConfigSetCommand::CommitBlankSecondLine { value } => {
+                dit.config_set_blank_second_line(value)?;
+                success!("Set commit.blank_second_line to {value}")
+            }
+            ConfigSetCommand::UserSigningKey { path } => {
+                dit.config_set_signing_key(&path)?;
+                success!("Set user.signing_key to {}", path.display())
+            }
+            ConfigSetCommand::CommitTrustedKeys { values } => {
+                let msg = format!("Set commit.trusted_keys to [{}]", values.join(", "));
+                dit.config_set_trusted_keys(values)?;
+                success!("{msg}")
+            }
+            ConfigSetCommand::CommitSignedBranches { values } => {
+                let msg = format!("Set commit.signed_branches to [{}]", values.join(", "));
+                dit.config_set_signed_branches(values)?;
+                success!("{msg}")
+            }
         }
 
         Ok(())
@@ -53,6 +81,42 @@ impl ConfigSubcommand {
                     hint!("Set email with `dit config set user.email <EMAIL>`");
                 }
             }
+            ConfigGetCommand::CoreEditor => {
+                if let Some(value) = dit.config_get_editor()? {
+                    info!("core.editor: '{}'", style(value).green().bold());
+                } else {
+                    info!("core.editor: {}", style("none").yellow().bold());
+                    hint!("Set with `dit config set core.editor <COMMAND>`. $VISUAL or $EDITOR is used otherwise");
+                }
+            }
+            ConfigGetCommand::CommitMaxSubjectLength => {
+                if let Some(value) = dit.config_get_max_subject_length()? {
+                    info!("commit.max_subject_length: {}", style(value).green().bold());
+                } else {
+                    info!("commit.max_subject_length: {}", style("none").yellow().bold());
+                    hint!("Set with `dit config set commit.max_subject_length <LENGTH>`");
+                }
+            }
+            ConfigGetCommand::CommitBlankSecondLine => {
+                let value = dit.config_get_blank_second_line()?.unwrap_or(false);
+                info!("commit.blank_second_line: {}", style(value).green().bold());
+            }
+            ConfigGetCommand::UserSigningKey => {
+                if let Some(value) = dit.config_get_signing_key()? {
+                    info!("user.signing_key: '{}'", style(value).green().bold());
+                } else {
+                    info!("user.signing_key: {}", style("none").yellow().bold());
+                    hint!("Generate a key with `dit keygen <PATH>` and set it with `dit config set user.signing_key <PATH>`");
+                }
+            }
+            ConfigGetCommand::CommitTrustedKeys => {
+                let values = dit.config_get_trusted_keys()?;
+                info!("commit.trusted_keys: [{}]", style(values.join(", ")).green().bold());
+            }
+            ConfigGetCommand::CommitSignedBranches => {
+                let values = dit.config_get_signed_branches()?;
+                info!("commit.signed_branches: [{}]", style(values.join(", ")).green().bold());
+            }
             ConfigGetCommand::User => {
                 if let Some(value) = dit.config_get_user()? {
                     info!("user: '{}'", style(value).green().bold());
@@ -99,6 +163,40 @@ pub enum ConfigSetCommand {
     UserEmail {
         value: String
     },
+
+    #[clap(name = "core.editor")]
+    CoreEditor {
+        value: String
+    },
+
+    #[clap(name = "commit.max_subject_length")]
+    CommitMaxSubjectLength {
+        value: usize
+    },
+
+    #[clap(name = "commit.blank_second_line")]
+    CommitBlankSecondLine {
+        #[arg(action = clap::ArgAction::Set)]
+        value: bool
+    },
+
+    #[clap(name = "user.signing_key")]
+    UserSigningKey {
+        #[arg(help = "Path of the private key generated with `dit keygen`")]
+        path: PathBuf
+    },
+
+    #[clap(name = "commit.trusted_keys")]
+    CommitTrustedKeys {
+        #[arg(value_delimiter = ',', help = "Comma-separated public keys (none to clear the list)")]
+        values: Vec<String>
+    },
+
+    #[clap(name = "commit.signed_branches")]
+    CommitSignedBranches {
+        #[arg(value_delimiter = ',', help = "Comma-separated branch names (none to clear the list)")]
+        values: Vec<String>
+    },
 }
 
 #[derive(Subcommand)]
@@ -111,4 +209,22 @@ pub enum ConfigGetCommand {
 
     #[clap(name = "user")]
     User,
+
+    #[clap(name = "core.editor")]
+    CoreEditor,
+
+    #[clap(name = "commit.max_subject_length")]
+    CommitMaxSubjectLength,
+
+    #[clap(name = "commit.blank_second_line")]
+    CommitBlankSecondLine,
+
+    #[clap(name = "user.signing_key")]
+    UserSigningKey,
+
+    #[clap(name = "commit.trusted_keys")]
+    CommitTrustedKeys,
+
+    #[clap(name = "commit.signed_branches")]
+    CommitSignedBranches,
 }
\ No newline at end of file
@@ -0,0 +1,94 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use crate::info;
+use clap::Args;
+use console::style;
+use dit_core::api_models::diff::{Diff, DiffContent, DiffLine, FileDiffKind};
+use dit_core::helpers::path_to_string;
+use std::borrow::Cow;
+
+#[derive(Args)]
+pub struct DiffSubcommand {
+    #[arg(
+        num_args = 0..=2,
+        help = "Commits to compare (hashes, prefixes, branch names, HEAD~N, etc.). \
+        With one commit, compares it to the HEAD commit. \
+        Without commits, compares the working tree to the index.")]
+    commits: Vec<String>,
+
+    #[arg(long, help = "Compare the index to the HEAD commit")]
+    staged: bool,
+}
+
+
+impl HandleSubcommand for DiffSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let dit = Self::require_dit()?;
+
+        let diff = match self.commits.as_slice() {
+            [] if self.staged => dit.diff_staged()?,
+            [] => dit.diff_unstaged()?,
+            [old] => dit.diff_commits(old, "HEAD")?,
+            [old, new, ..] => dit.diff_commits(old, new)?,
+        };
+
+        if diff.is_empty() {
+            info!("No changes");
+        } else {
+            print_diff(&diff);
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Prints the diff in the unified format
+fn print_diff(diff: &Diff) {
+    for file in &diff.files {
+        let path = path_to_string(&file.path);
+        let (old_name, new_name) = match file.kind {
+            FileDiffKind::Added => (String::from("/dev/null"), format!("b/{path}")),
+            FileDiffKind::Deleted => (format!("a/{path}"), String::from("/dev/null")),
+            FileDiffKind::Modified => (format!("a/{path}"), format!("b/{path}")),
+        };
+
+        println!("{}", style(format!("diff --dit a/{path} b/{path}")).bold());
+
+        match &file.content {
+            DiffContent::Binary => {
+                println!("Binary files {old_name} and {new_name} differ");
+            }
+
+            DiffContent::Text(hunks) => {
+                println!("{}", style(format!("--- {old_name}")).bold());
+                println!("{}", style(format!("+++ {new_name}")).bold());
+
+                for hunk in hunks {
+                    println!("{}", style(format!(
+                        "@@ -{},{} +{},{} @@",
+                        hunk.old_start, hunk.old_len, hunk.new_start, hunk.new_len
+                    )).cyan());
+
+                    for line in &hunk.lines {
+                        match line {
+                            DiffLine::Context(text) => println!(" {}", show_line_end(text)),
+                            DiffLine::Added(text) => println!("{}", style(format!("+{}", show_line_end(text))).green()),
+                            DiffLine::Removed(text) => println!("{}", style(format!("-{}", show_line_end(text))).red()),
+                            DiffLine::NoNewlineAtEnd => println!("\\ No newline at end of file"),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Shows the carriage return which ends a CRLF line as `^M`, which would
+/// otherwise be invisible in the terminal
+fn show_line_end(text: &str) -> Cow<'_, str> {
+    match text.strip_suffix('\r') {
+        Some(text) => Cow::Owned(format!("{text}^M")),
+        None => Cow::Borrowed(text),
+    }
+}
@@ -0,0 +1,38 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::remote::short_hash;
+use crate::error::CliResult;
+use crate::{info, success};
+use clap::Args;
+use dit_core::helpers::DEFAULT_REMOTE_NAME;
+
+#[derive(Args)]
+pub struct FetchSubcommand {
+    #[arg(default_value = DEFAULT_REMOTE_NAME, help = "Remote to fetch from")]
+    remote: String,
+}
+
+
+impl HandleSubcommand for FetchSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let report = dit.fetch(&self.remote)?;
+
+        if report.updates.is_empty() {
+            info!("Already up to date with '{}'", report.remote);
+            return Ok(());
+        }
+
+        for update in &report.updates {
+            let name = format!("{}/{}", report.remote, update.branch);
+            match (&update.old, &update.new) {
+                (None, Some(new)) => println!("  {name}: new branch at {}", short_hash(new)),
+                (Some(old), Some(new)) => println!("  {name}: {}..{}", short_hash(old), short_hash(new)),
+                (Some(_), None) => println!("  {name}: deleted"),
+                (None, None) => {}
+            }
+        }
+
+        success!("Fetched {} objects from '{}'", report.objects, report.remote);
+        Ok(())
+    }
+}
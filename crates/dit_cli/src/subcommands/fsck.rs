@@ -0,0 +1,39 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::{CliResult, DitCliError};
+use crate::{hint, info, success, warning};
+use clap::Args;
+
+#[derive(Args)]
+pub struct FsckSubcommand {
+    #[arg(long, help = "Don't list the dangling objects")]
+    no_dangling: bool,
+}
+
+
+impl HandleSubcommand for FsckSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let dit = Self::require_dit()?;
+        let report = dit.fsck()?;
+
+        for problem in &report.problems {
+            warning!("{problem}");
+        }
+
+        if !self.no_dangling {
+            for object in &report.dangling {
+                info!("dangling {} {}", object.kind, object.hash);
+            }
+        }
+
+        if !report.dangling.is_empty() {
+            hint!("Dangling objects can be removed with `dit gc`");
+        }
+
+        if !report.is_ok() {
+            return Err(DitCliError::FsckFailed(report.problems.len()));
+        }
+
+        success!("Checked {} objects, no problems found", report.checked);
+        Ok(())
+    }
+}
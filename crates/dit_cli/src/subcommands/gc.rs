@@ -0,0 +1,84 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use crate::{info, success};
+use clap::Args;
+use dit_core::api_models::gc::GcReport;
+use dit_core::api_models::object::ObjectKind;
+use dit_core::helpers::DEFAULT_GC_GRACE_PERIOD_SECS;
+use std::time::Duration;
+
+#[derive(Args)]
+pub struct GcSubcommand {
+    #[arg(long, help = "Only report the unreachable objects without removing them")]
+    dry_run: bool,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = DEFAULT_GC_GRACE_PERIOD_SECS,
+        help = "Keep unreachable objects modified within this many seconds")]
+    grace_period: u64,
+}
+
+
+impl HandleSubcommand for GcSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let report = dit.gc(Duration::from_secs(self.grace_period), self.dry_run)?;
+
+        if report.dry_run {
+            for object in &report.removed {
+                println!("{} {} ({})", object.kind, object.hash, format_bytes(object.size));
+            }
+        }
+
+        let summary = summarize(&report);
+        if report.removed.is_empty() {
+            info!("Nothing to remove");
+        } else if report.dry_run {
+            info!("Would remove {summary}");
+        } else {
+            success!("Removed {summary}");
+        }
+
+        if report.kept_recent > 0 {
+            info!("Kept {} unreachable objects younger than the grace period", report.kept_recent);
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Describes the removed objects by kind and their total size
+fn summarize(report: &GcReport) -> String {
+    let counts = [
+        (ObjectKind::Blob, "blobs"),
+        (ObjectKind::Tree, "trees"),
+        (ObjectKind::Commit, "commits"),
+        (ObjectKind::TagObject, "tag objects"),
+    ]
+        .into_iter()
+        .map(|(kind, name)| format!("{} {name}", report.count(kind)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{counts} ({})", format_bytes(report.reclaimed_bytes()))
+}
+
+pub(super) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
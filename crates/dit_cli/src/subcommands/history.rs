@@ -1,6 +1,7 @@
 use crate::subcommands::HandleSubcommand;
+use crate::subcommands::verify_commit::format_signature_status;
 use crate::error::CliResult;
-use chrono::{DateTime, Local, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, Local, TimeZone, Utc};
 use comfy_table::{Table, ContentArrangement, presets::UTF8_FULL_CONDENSED};
 use clap::Args;
 use console::style;
@@ -12,6 +13,9 @@ pub struct HistorySubcommand {
         default_value = "5",
         help = "Number of history entries to show. -1 for all entries.")]
     count: isize,
+
+    #[arg(long, help = "Show whether each commit is signed with a trusted key")]
+    show_signature: bool,
 }
 
 
@@ -29,19 +33,34 @@ impl HandleSubcommand for HistorySubcommand {
 
         let mut table = Table::new();
 
+        let mut header = vec!["No", "Time", "Message", "Author", "Hash"];
+        if self.show_signature {
+            header.push("Signature");
+        }
         table.load_preset(UTF8_FULL_CONDENSED)
             .set_content_arrangement(ContentArrangement::Dynamic)
-            .set_header(["No", "Time", "Message", "Author",  "Hash"]);
+            .set_header(header);
 
         for (idx, commit) in history.commits.into_iter().enumerate() {
-            let timestamp = format_timestamp_local(commit.timestamp);
-            table.add_row([
+            let timestamp = format_timestamp_with_offset(commit.author.timestamp, commit.author.offset);
+            let mut author = commit.author.to_string();
+            if !commit.committer.same_identity(&commit.author) {
+                author = format!("{author}\ncommitted by {}", commit.committer);
+            }
+            let signature = if self.show_signature {
+                Some(format_signature_status(&dit.verify_commit(&commit.hash)?))
+            } else {
+                None
+            };
+            let mut row = vec![
                 (idx + 1).to_string(),
                 timestamp,
                 commit.message,
-                commit.author,
+                author,
                 commit.hash
-            ]);
+            ];
+            row.extend(signature);
+            table.add_row(row);
         }
 
         println!("{title}");
@@ -51,7 +70,16 @@ impl HandleSubcommand for HistorySubcommand {
     }
 }
 
-fn format_timestamp_local(epoch: u64) -> String {
+/// Formats the time in the timezone with the given offset from UTC in minutes,
+/// e.g. `2025-01-31 14:05:09 +02:00`
+fn format_timestamp_with_offset(epoch: u64, offset: i32) -> String {
+    FixedOffset::east_opt(offset * 60)
+        .and_then(|offset| offset.timestamp_opt(epoch as i64, 0).single())
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S %:z").to_string())
+        .unwrap_or_else(|| format_timestamp_local(epoch))
+}
+
+pub(super) fn format_timestamp_local(epoch: u64) -> String {
     Local.timestamp_opt(epoch as i64, 0)
         .single()
         .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
@@ -2,10 +2,14 @@ use crate::subcommands::HandleSubcommand;
 use crate::error::{CliResult, DitCliError};
 use clap::Args;
 use dit_core::{Dit, dit_component_paths::DIT_ROOT};
+use dit_core::helpers::DEFAULT_BRANCH_NAME;
 use crate::success;
 
 #[derive(Args)]
-pub struct InitSubcommand;
+pub struct InitSubcommand {
+    #[arg(long, help = "Create a repository without a current branch, so that any branch can be pushed to it")]
+    bare: bool,
+}
 
 
 impl HandleSubcommand for InitSubcommand {
@@ -20,8 +24,8 @@ impl HandleSubcommand for InitSubcommand {
 
         // default behavior:
         // if no head branch is found, a default "main" branch will be created
-        if dit.get_branch()?.is_none() {
-            dit.create_branch("main")?;
+        if !self.bare && dit.get_branch()?.is_none() {
+            dit.create_branch(DEFAULT_BRANCH_NAME)?;
         }
 
         if is_new {
@@ -0,0 +1,27 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use clap::Args;
+use console::style;
+use dit_core::Dit;
+use std::path::PathBuf;
+use crate::{hint, success};
+
+#[derive(Args)]
+pub struct KeygenSubcommand {
+    #[arg(help = "Path of the private key to create. The public key is written next to it with the .pub extension")]
+    path: PathBuf,
+}
+
+
+impl HandleSubcommand for KeygenSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let public_key = Dit::generate_signing_key(&self.path)?;
+        success!("Generated a signing key at {}", self.path.display());
+        println!("Public key: {}", style(public_key).green().bold());
+        hint!(
+            "Sign your commits with `dit config set user.signing_key {}`",
+            self.path.display()
+        );
+        Ok(())
+    }
+}
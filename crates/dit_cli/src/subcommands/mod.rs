@@ -30,3 +30,63 @@ pub use clear::ClearSubcommand;
 
 mod config;
 pub use config::ConfigSubcommand;
+
+mod diff;
+pub use diff::DiffSubcommand;
+
+mod tag;
+pub use tag::TagSubcommand;
+
+mod stash;
+pub use stash::StashSubcommand;
+
+mod gc;
+pub use gc::GcSubcommand;
+
+mod fsck;
+pub use fsck::FsckSubcommand;
+
+mod repack;
+pub use repack::RepackSubcommand;
+
+mod remote;
+pub use remote::RemoteSubcommand;
+
+mod fetch;
+pub use fetch::FetchSubcommand;
+
+mod push;
+pub use push::PushSubcommand;
+
+mod clone;
+pub use clone::CloneSubcommand;
+
+mod serve;
+pub use serve::ServeSubcommand;
+
+mod reflog;
+pub use reflog::ReflogSubcommand;
+
+mod undo;
+pub use undo::UndoSubcommand;
+
+mod op;
+pub use op::OpSubcommand;
+
+mod rebase;
+pub use rebase::RebaseSubcommand;
+
+mod cherry_pick;
+pub use cherry_pick::CherryPickSubcommand;
+
+mod revert;
+pub use revert::RevertSubcommand;
+
+mod keygen;
+pub use keygen::KeygenSubcommand;
+
+mod verify_commit;
+pub use verify_commit::VerifyCommitSubcommand;
+
+mod recover;
+pub use recover::RecoverSubcommand;
@@ -0,0 +1,65 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::history::format_timestamp_local;
+use crate::error::CliResult;
+use crate::info;
+use comfy_table::{Table, ContentArrangement, presets::UTF8_FULL_CONDENSED};
+use clap::{Args, Subcommand};
+
+#[derive(Subcommand)]
+pub enum OpCommand {
+    Log {
+        #[arg(
+            short, long,
+            default_value = "-1",
+            help = "Number of operations to show. -1 for all operations.")]
+        count: isize,
+    },
+}
+
+
+#[derive(Args)]
+pub struct OpSubcommand {
+    #[command(subcommand)]
+    command: OpCommand,
+}
+
+
+impl HandleSubcommand for OpSubcommand {
+    fn handle(self) -> CliResult<()> {
+        match self.command {
+            OpCommand::Log { count } => Self::handle_log(count),
+        }
+    }
+}
+
+
+impl OpSubcommand {
+    fn handle_log(count: isize) -> CliResult<()> {
+        let dit = Self::require_dit()?;
+        let operations = dit.list_operations(count)?;
+
+        if operations.is_empty() {
+            info!("The operation log is empty");
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+
+        table.load_preset(UTF8_FULL_CONDENSED)
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(["No", "Time", "Operation", "Author"]);
+
+        for operation in operations {
+            table.add_row([
+                operation.id.to_string(),
+                format_timestamp_local(operation.timestamp),
+                operation.description,
+                operation.author,
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
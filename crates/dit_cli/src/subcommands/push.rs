@@ -0,0 +1,43 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::remote::short_hash;
+use crate::error::CliResult;
+use crate::{info, success, warning};
+use clap::Args;
+use dit_core::helpers::DEFAULT_REMOTE_NAME;
+
+#[derive(Args)]
+pub struct PushSubcommand {
+    #[arg(default_value = DEFAULT_REMOTE_NAME, help = "Remote to push to")]
+    remote: String,
+
+    #[arg(help = "Branch to push. Defaults to the current branch.")]
+    branch: Option<String>,
+
+    #[arg(short, long, help = "Overwrite the remote branch even if it contains other commits")]
+    force: bool,
+}
+
+
+impl HandleSubcommand for PushSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let report = dit.push(&self.remote, self.branch, self.force)?;
+        let name = format!("{}/{}", report.remote, report.branch);
+
+        if report.is_up_to_date() {
+            info!("'{name}' is already up to date");
+            return Ok(());
+        }
+
+        match &report.old {
+            Some(old) if report.forced => {
+                warning!("Overwrote '{name}' ({} -> {})", short_hash(old), short_hash(&report.new));
+            }
+            Some(old) => println!("  {name}: {}..{}", short_hash(old), short_hash(&report.new)),
+            None => println!("  {name}: new branch at {}", short_hash(&report.new)),
+        }
+
+        success!("Pushed {} objects to '{}'", report.objects, report.remote);
+        Ok(())
+    }
+}
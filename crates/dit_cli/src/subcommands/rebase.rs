@@ -0,0 +1,75 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::remote::short_hash;
+use crate::error::CliResult;
+use crate::{hint, info, success, warning};
+use clap::Args;
+use console::style;
+use dit_core::api_models::rebase::RebaseResult;
+use dit_core::helpers::path_to_string;
+
+#[derive(Args)]
+pub struct RebaseSubcommand {
+    #[arg(
+        required_unless_present_any = ["continue_rebase", "skip", "abort"],
+        help = "Branch (or any other revision) to replay the commits of the current branch onto")]
+    upstream: Option<String>,
+
+    #[arg(
+        long = "continue",
+        conflicts_with_all = ["upstream", "skip", "abort"],
+        help = "Continue the rebase after resolving the conflicts")]
+    continue_rebase: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["upstream", "abort"],
+        help = "Skip the commit which conflicts and continue the rebase")]
+    skip: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "upstream",
+        help = "Abort the rebase and return to the state before it")]
+    abort: bool,
+}
+
+
+impl HandleSubcommand for RebaseSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+
+        let result = if self.continue_rebase {
+            dit.rebase_continue()?
+        } else if self.skip {
+            dit.rebase_skip()?
+        } else if self.abort {
+            dit.rebase_abort()?;
+            success!("Aborted the rebase");
+            return Ok(());
+        } else {
+            dit.rebase(self.upstream.unwrap_or_default())?
+        };
+
+        match result {
+            RebaseResult::UpToDate => {
+                info!("The current branch is up to date");
+            }
+            RebaseResult::FastForward(commit) => {
+                success!("Fast-forwarded the current branch to commit '{commit}'");
+            }
+            RebaseResult::Rebased(commit) => {
+                success!("Rebased the current branch (commit '{commit}')");
+            }
+            RebaseResult::Conflicted { commit, paths } => {
+                warning!("Replaying the commit '{}' stopped because of conflicts in:", short_hash(&commit));
+                for path in paths {
+                    println!("\t{}", style(path_to_string(&path)).red().bold());
+                }
+                hint!("Resolve the conflicts, stage the files with `dit add` and run `dit rebase --continue`. \
+                Run `dit rebase --skip` to skip the commit or `dit rebase --abort` to cancel the rebase");
+            }
+        }
+
+        Ok(())
+    }
+}
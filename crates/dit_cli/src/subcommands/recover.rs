@@ -0,0 +1,35 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use crate::success;
+use clap::Args;
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+pub struct RecoverSubcommand {
+    #[arg(
+        long = "continue",
+        help = "Finish the interrupted working tree update")]
+    continue_update: bool,
+
+    #[arg(
+        long,
+        help = "Undo the interrupted working tree update, restoring the files, the index and HEAD")]
+    rollback: bool,
+}
+
+
+impl HandleSubcommand for RecoverSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+
+        if self.continue_update {
+            let description = dit.recover_continue()?;
+            success!("Finished the interrupted update '{description}'");
+        } else {
+            let description = dit.recover_rollback()?;
+            success!("Rolled back the interrupted update '{description}'");
+        }
+
+        Ok(())
+    }
+}
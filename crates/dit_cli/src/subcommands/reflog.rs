@@ -0,0 +1,53 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::history::format_timestamp_local;
+use crate::subcommands::remote::short_hash;
+use crate::error::CliResult;
+use crate::info;
+use comfy_table::{Table, ContentArrangement, presets::UTF8_FULL_CONDENSED};
+use clap::Args;
+
+#[derive(Args)]
+pub struct ReflogSubcommand {
+    #[arg(default_value = "HEAD", help = "HEAD or a branch name")]
+    name: String,
+
+    #[arg(
+        short, long,
+        default_value = "-1",
+        help = "Number of reflog entries to show. -1 for all entries.")]
+    count: isize,
+}
+
+
+impl HandleSubcommand for ReflogSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let dit = Self::require_dit()?;
+        let reflog = dit.get_reflog(&self.name, self.count)?;
+
+        if reflog.entries.is_empty() {
+            info!("The reflog of '{}' is empty", reflog.name);
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+
+        table.load_preset(UTF8_FULL_CONDENSED)
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(["Entry", "Time", "Operation", "Author", "Old", "New"]);
+
+        for (n, entry) in reflog.entries.into_iter().enumerate() {
+            table.add_row([
+                format!("{}@{{{n}}}", reflog.name),
+                format_timestamp_local(entry.timestamp),
+                entry.message,
+                entry.author,
+                entry.old.as_deref().map(short_hash).unwrap_or("-").to_string(),
+                short_hash(&entry.new).to_string(),
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use crate::{info, success};
+use comfy_table::{Table, ContentArrangement, presets::UTF8_FULL_CONDENSED};
+use clap::{Args, Subcommand};
+
+#[derive(Subcommand)]
+pub enum RemoteCommand {
+    Add {
+        name: String,
+
+        #[arg(help = "Path or dit://host[:port] url of the remote repository")]
+        url: String,
+    },
+
+    Remove {
+        name: String,
+    },
+
+    List,
+}
+
+
+#[derive(Args)]
+pub struct RemoteSubcommand {
+    #[command(subcommand)]
+    command: RemoteCommand,
+}
+
+
+impl HandleSubcommand for RemoteSubcommand {
+    fn handle(self) -> CliResult<()> {
+        match self.command {
+            RemoteCommand::Add { name, url } => Self::handle_add(name, url),
+            RemoteCommand::Remove { name } => Self::handle_remove(name),
+            RemoteCommand::List => Self::handle_list(),
+        }
+    }
+}
+
+
+impl RemoteSubcommand {
+    fn handle_add(name: String, url: String) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        dit.add_remote(&name, &url)?;
+        success!("Added the remote '{name}'");
+        Ok(())
+    }
+
+    fn handle_remove(name: String) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        dit.remove_remote(&name)?;
+        success!("Removed the remote '{name}'");
+        Ok(())
+    }
+
+    fn handle_list() -> CliResult<()> {
+        let dit = Self::require_dit()?;
+        let remotes = dit.list_remotes()?;
+
+        if remotes.is_empty() {
+            info!("There are no remotes");
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+
+        table.load_preset(UTF8_FULL_CONDENSED)
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(["Name", "Url", "Branches"]);
+
+        for remote in remotes {
+            let branches = remote.branches
+                .iter()
+                .map(|(branch, commit)| format!("{branch} ({})", short_hash(commit)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            table.add_row([remote.name, remote.url, branches]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
+
+
+/// Shortens a commit hash for display
+pub(super) fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(8)]
+}
@@ -0,0 +1,40 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::gc::format_bytes;
+use crate::error::CliResult;
+use crate::{info, success};
+use clap::Args;
+
+#[derive(Args)]
+pub struct RepackSubcommand {
+    #[arg(long, help = "Compress the blobs with a dictionary trained on them")]
+    dictionary: bool,
+}
+
+
+impl HandleSubcommand for RepackSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let report = dit.repack(self.dictionary)?;
+
+        match &report.pack {
+            Some(pack) => {
+                let dictionary = if report.dictionary { ", with a dictionary" } else { "" };
+                success!(
+                    "Packed {} objects into {pack} ({}{dictionary})",
+                    report.packed,
+                    format_bytes(report.size));
+            }
+            None => info!("Nothing to pack"),
+        }
+
+        if report.removed_loose > 0 || report.removed_packs > 0 {
+            info!("Removed {} loose objects and {} old packs", report.removed_loose, report.removed_packs);
+        }
+
+        if report.loosened > 0 {
+            info!("Unpacked {} unreachable objects, run 'dit gc' to remove them", report.loosened);
+        }
+
+        Ok(())
+    }
+}
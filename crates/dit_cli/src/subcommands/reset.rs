@@ -1,4 +1,5 @@
 use crate::subcommands::HandleSubcommand;
+use crate::subcommands::branch::checkout_mode;
 use crate::error::CliResult;
 use crate::success;
 use clap::{Args, ValueEnum};
@@ -12,10 +13,17 @@ pub enum ResetMode {
 
 #[derive(Args)]
 pub struct ResetSubcommand {
+    #[arg(help = "Target commit (a hash, a unique hash prefix, a branch name, HEAD~N, etc.)")]
     commit: String,
 
     #[arg(value_enum, default_value_t = ResetMode::Mixed)]
     mode: ResetMode,
+
+    #[arg(long, conflicts_with = "merge", help = "With hard: overwrite the untracked files and local changes in the way")]
+    force: bool,
+
+    #[arg(long, help = "With hard: keep the local changes by merging them into the files of the target commit")]
+    merge: bool,
 }
 
 
@@ -32,7 +40,7 @@ impl HandleSubcommand for ResetSubcommand {
 impl ResetSubcommand {
     fn handle_soft(&self) -> CliResult<()> {
         let mut dit = Self::require_dit()?;
-        dit.mixed_reset(&self.commit)?;
+        dit.soft_reset(&self.commit)?;
         success!("Soft reset to commit '{}'", &self.commit);
         Ok(())
     }
@@ -46,7 +54,7 @@ impl ResetSubcommand {
 
     fn handle_hard(&self) -> CliResult<()> {
         let mut dit = Self::require_dit()?;
-        dit.hard_reset(&self.commit)?;
+        dit.hard_reset(&self.commit, checkout_mode(self.force, self.merge))?;
         success!("Hard reset to commit '{}'", &self.commit);
         Ok(())
     }
@@ -0,0 +1,20 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::cherry_pick::print_pick_result;
+use crate::error::CliResult;
+use clap::Args;
+
+#[derive(Args)]
+pub struct RevertSubcommand {
+    #[arg(help = "Commit (or any other revision) whose changes to undo on top of HEAD")]
+    revision: String,
+}
+
+
+impl HandleSubcommand for RevertSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let result = dit.revert(&self.revision)?;
+        print_pick_result(result, "Reverted", &self.revision);
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use crate::{info, warning};
+use clap::Args;
+use dit_core::helpers::DEFAULT_SERVE_PORT;
+use dit_server::Server;
+
+#[derive(Args)]
+pub struct ServeSubcommand {
+    #[arg(
+        long,
+        value_name = "ADDRESS",
+        default_value_t = format!("127.0.0.1:{DEFAULT_SERVE_PORT}"),
+        help = "Address to listen on. Use 0.0.0.0:<PORT> to accept connections from other machines.")]
+    listen: String,
+}
+
+
+impl HandleSubcommand for ServeSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let dit = Self::require_dit()?;
+        let server = Server::bind(dit.repo_path(), &self.listen)?;
+        let address = server.local_addr()?;
+
+        info!("Serving '{}' on dit://{address}", dit.repo_path().display());
+        warning!("Anyone who can connect can fetch from and push to the repository");
+
+        server.run()?;
+        Ok(())
+    }
+}
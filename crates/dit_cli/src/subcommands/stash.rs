@@ -0,0 +1,137 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::history::format_timestamp_local;
+use crate::error::CliResult;
+use crate::{hint, info, success, warning};
+use comfy_table::{Table, ContentArrangement, presets::UTF8_FULL_CONDENSED};
+use clap::{Args, Subcommand};
+use console::style;
+use dit_core::api_models::stash::StashApplyResult;
+use dit_core::helpers::path_to_string;
+
+#[derive(Subcommand)]
+pub enum StashCommand {
+    Push {
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+
+    Pop {
+        #[arg(default_value = "0", help = "Stash entry to pop. 0 is the most recent one.")]
+        entry: usize,
+    },
+
+    Apply {
+        #[arg(default_value = "0", help = "Stash entry to apply. 0 is the most recent one.")]
+        entry: usize,
+    },
+
+    List,
+
+    Drop {
+        #[arg(default_value = "0", help = "Stash entry to drop. 0 is the most recent one.")]
+        entry: usize,
+    },
+}
+
+
+#[derive(Args)]
+pub struct StashSubcommand {
+    #[command(subcommand)]
+    command: StashCommand,
+}
+
+
+impl HandleSubcommand for StashSubcommand {
+    fn handle(self) -> CliResult<()> {
+        match self.command {
+            StashCommand::Push { message } => Self::handle_push(message),
+            StashCommand::Pop { entry } => Self::handle_pop(entry),
+            StashCommand::Apply { entry } => Self::handle_apply(entry),
+            StashCommand::List => Self::handle_list(),
+            StashCommand::Drop { entry } => Self::handle_drop(entry),
+        }
+    }
+}
+
+
+impl StashSubcommand {
+    fn handle_push(message: Option<String>) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        dit.stash_push(message)?;
+        success!("Stashed the changes as stash@{{0}}");
+        Ok(())
+    }
+
+    fn handle_pop(entry: usize) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let result = dit.stash_pop(entry)?;
+        if Self::print_apply_result(entry, result) {
+            success!("Dropped stash@{{{entry}}}");
+        } else {
+            hint!("The stash entry is kept. Drop it with `dit stash drop {entry}` once the conflicts are resolved");
+        }
+        Ok(())
+    }
+
+    fn handle_apply(entry: usize) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let result = dit.stash_apply(entry)?;
+        Self::print_apply_result(entry, result);
+        Ok(())
+    }
+
+    fn handle_list() -> CliResult<()> {
+        let dit = Self::require_dit()?;
+        let entries = dit.stash_list()?;
+
+        if entries.is_empty() {
+            info!("There are no stashed changes");
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+
+        table.load_preset(UTF8_FULL_CONDENSED)
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(["Entry", "Time", "Message", "Hash"]);
+
+        for entry in entries {
+            table.add_row([
+                format!("stash@{{{}}}", entry.index),
+                format_timestamp_local(entry.timestamp),
+                entry.message,
+                entry.commit,
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+
+    fn handle_drop(entry: usize) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let commit = dit.stash_drop(entry)?;
+        success!("Dropped stash@{{{entry}}} ({commit})");
+        Ok(())
+    }
+
+    /// Prints the result of applying a stash entry.
+    /// Returns whether it was applied without conflicts
+    fn print_apply_result(entry: usize, result: StashApplyResult) -> bool {
+        match result {
+            StashApplyResult::Applied => {
+                success!("Applied stash@{{{entry}}}");
+                true
+            }
+            StashApplyResult::Conflicted(paths) => {
+                warning!("Applied stash@{{{entry}}} with conflicts in:");
+                for path in paths {
+                    println!("\t{}", style(path_to_string(&path)).red().bold());
+                }
+                hint!("Resolve the conflicts and stage the files with `dit add`");
+                false
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::subcommands::HandleSubcommand;
 use crate::error::CliResult;
+use crate::{hint, warning};
 use dit_core::api_models::status::ChangeType;
 use clap::Args;
 use console::style;
@@ -31,8 +32,21 @@ impl HandleSubcommand for StatusSubcommand {
             None => println!("No commits yet")
         }
 
+        if let Some(description) = dit.interrupted_checkout()? {
+            warning!("The working tree update '{description}' was interrupted");
+            hint!("Run `dit recover --continue` to finish it or `dit recover --rollback` to undo it");
+        }
+
         let status =  dit.get_status()?;
 
+        if status.has_any_unmerged() {
+            println!("Unmerged paths:");
+            for path in status.get_unmerged() {
+                println!("\tunmerged: {}", style(path_to_string(path)).red().bold());
+            }
+            println!();
+        }
+
         if status.has_any_tracked() {
             println!("Changes to be commited:");
             for path in status.get_tracked(ChangeType::New) {
@@ -0,0 +1,107 @@
+use crate::subcommands::HandleSubcommand;
+use crate::subcommands::history::format_timestamp_local;
+use crate::error::CliResult;
+use crate::{info, success};
+use comfy_table::{Table, ContentArrangement, presets::UTF8_FULL_CONDENSED};
+use clap::{Args, Subcommand};
+
+#[derive(Subcommand)]
+pub enum TagCommand {
+    New {
+        name: String,
+
+        #[arg(help = "Revision to tag. Defaults to HEAD.")]
+        revision: Option<String>,
+
+        #[arg(short, long, help = "Creates an annotated tag with the given message")]
+        message: Option<String>,
+
+        #[arg(short, long, requires = "message")]
+        tagger: Option<String>,
+    },
+
+    List,
+
+    Delete {
+        name: String,
+    },
+}
+
+
+#[derive(Args)]
+pub struct TagSubcommand {
+    #[command(subcommand)]
+    command: TagCommand,
+}
+
+
+impl HandleSubcommand for TagSubcommand {
+    fn handle(self) -> CliResult<()> {
+        match self.command {
+            TagCommand::New { name, revision, message, tagger } => {
+                Self::handle_new(name, revision, message, tagger)
+            }
+            TagCommand::List => Self::handle_list(),
+            TagCommand::Delete { name } => Self::handle_delete(name),
+        }
+    }
+}
+
+
+impl TagSubcommand {
+    fn handle_new(
+        name: String,
+        revision: Option<String>,
+        message: Option<String>,
+        tagger: Option<String>,
+    ) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let is_annotated = message.is_some();
+        dit.create_tag(&name, revision, message, tagger)?;
+        if is_annotated {
+            success!("Created an annotated tag '{name}'");
+        } else {
+            success!("Created a tag '{name}'");
+        }
+        Ok(())
+    }
+
+    fn handle_list() -> CliResult<()> {
+        let dit = Self::require_dit()?;
+        let tags = dit.list_tags()?;
+
+        if tags.is_empty() {
+            info!("There are no tags");
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+
+        table.load_preset(UTF8_FULL_CONDENSED)
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(["Name", "Commit", "Time", "Tagger", "Message"]);
+
+        for tag in tags {
+            let (time, tagger, message) = match tag.annotation {
+                Some(annotation) => (
+                    format_timestamp_local(annotation.timestamp),
+                    annotation.tagger,
+                    annotation.message,
+                ),
+                None => (String::new(), String::new(), String::new()),
+            };
+            table.add_row([tag.name, tag.commit, time, tagger, message]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+
+    fn handle_delete(name: String) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        dit.delete_tag(&name)?;
+        success!("Deleted the tag '{name}'");
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use crate::success;
+use clap::Args;
+
+#[derive(Args)]
+pub struct UndoSubcommand;
+
+impl HandleSubcommand for UndoSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let mut dit = Self::require_dit()?;
+        let operation = dit.undo()?;
+        success!("Undid '{}'", operation.description);
+        Ok(())
+    }
+}
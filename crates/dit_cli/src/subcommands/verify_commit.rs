@@ -0,0 +1,48 @@
+use crate::subcommands::HandleSubcommand;
+use crate::error::CliResult;
+use clap::Args;
+use console::style;
+use dit_core::api_models::signing::SignatureStatus;
+use crate::{failure, hint, success, warning};
+
+#[derive(Args)]
+pub struct VerifyCommitSubcommand {
+    #[arg(default_value = "HEAD", help = "Commit (or any other revision) whose signature to verify")]
+    revision: String,
+}
+
+
+impl HandleSubcommand for VerifyCommitSubcommand {
+    fn handle(self) -> CliResult<()> {
+        let dit = Self::require_dit()?;
+        let hash = dit.resolve_revision(&self.revision)?;
+
+        match dit.verify_commit(&hash)? {
+            SignatureStatus::Good(key) => {
+                success!("Good signature on {hash} from key {}", style(key).green().bold());
+            }
+            SignatureStatus::Untrusted(key) => {
+                warning!("Valid signature on {hash} from untrusted key {}", style(key).yellow().bold());
+                hint!("Trust the key with `dit config set commit.trusted_keys <KEYS>`");
+            }
+            SignatureStatus::Bad(key) => {
+                failure!("Bad signature on {hash} from key {}", style(key).red().bold());
+            }
+            SignatureStatus::Unsigned => {
+                warning!("Commit {hash} is not signed");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the signature status in a short form, used in the history
+pub(super) fn format_signature_status(status: &SignatureStatus) -> String {
+    match status {
+        SignatureStatus::Good(_) => style("good").green().to_string(),
+        SignatureStatus::Untrusted(_) => style("untrusted").yellow().to_string(),
+        SignatureStatus::Bad(_) => style("bad").red().bold().to_string(),
+        SignatureStatus::Unsigned => style("unsigned").dim().to_string(),
+    }
+}
@@ -0,0 +1,15 @@
+/// Represents how a checkout (a branch switch or a hard reset) handles the untracked
+/// files and the local modifications which it would overwrite
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CheckoutMode {
+    /// The checkout is refused, listing the files which would be lost
+    #[default]
+    Safe,
+
+    /// The files are overwritten and the local modifications are lost
+    Force,
+
+    /// The local modifications are merged into the checked out versions of the files.
+    /// The checkout is refused if they conflict or if untracked files are in the way
+    Merge,
+}
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+/// Represents the differences between two versions of the project files
+#[derive(Debug, Clone, Default)]
+pub struct Diff {
+    /// Represents the changed files, sorted by their paths
+    pub files: Vec<FileDiff>,
+}
+
+impl Diff {
+    /// Checks whether there are no differences
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// Represents the differences of a single file
+#[derive(Debug, Clone)]
+pub struct FileDiff {
+    /// Represents the path of the file relative to the project root
+    pub path: PathBuf,
+
+    /// Represents the kind of the file change
+    pub kind: FileDiffKind,
+
+    /// Represents the changed content
+    pub content: DiffContent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDiffKind {
+    Added,
+    Deleted,
+    Modified,
+}
+
+#[derive(Debug, Clone)]
+pub enum DiffContent {
+    /// The line changes of a text file
+    Text(Vec<DiffHunk>),
+
+    /// The file is binary, so only the fact that it changed is reported
+    Binary,
+}
+
+/// Represents a group of changed lines with the surrounding unchanged lines
+#[derive(Debug, Clone)]
+pub struct DiffHunk {
+    /// Represents the first line of the hunk in the old version (starting from 1)
+    pub old_start: usize,
+
+    /// Represents the number of the old version lines in the hunk
+    pub old_len: usize,
+
+    /// Represents the first line of the hunk in the new version (starting from 1)
+    pub new_start: usize,
+
+    /// Represents the number of the new version lines in the hunk
+    pub new_len: usize,
+
+    /// Represents the lines of the hunk (without the line feeds, a CRLF line keeps its carriage return).
+    /// A line without a terminator at the end of the file is followed by `NoNewlineAtEnd`
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone)]
+pub enum DiffLine {
+    Context(String),
+    Added(String),
+    Removed(String),
+    NoNewlineAtEnd,
+}
@@ -0,0 +1,90 @@
+use crate::api_models::object::ObjectKind;
+use std::fmt::{Display, Formatter};
+
+/// Represents a problem found by the repository check
+#[derive(Debug, Clone)]
+pub enum FsckProblem {
+    /// The object can't be read, decompressed or parsed
+    Corrupt {
+        kind: ObjectKind,
+        hash: String,
+        reason: String,
+    },
+
+    /// The content of the object doesn't match its hash
+    HashMismatch {
+        kind: ObjectKind,
+        hash: String,
+        actual: String,
+    },
+
+    /// The object is referenced, but doesn't exist
+    Missing {
+        kind: ObjectKind,
+        hash: String,
+        referenced_by: String,
+    },
+
+    /// The pack file doesn't match its hash or its index can't be read
+    CorruptPack {
+        name: String,
+        reason: String,
+    },
+
+    /// A reference (HEAD, a branch, a remote-tracking branch, a tag, the index or the stash) is invalid
+    InvalidRef {
+        name: String,
+        reason: String,
+    },
+}
+
+impl Display for FsckProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FsckProblem::Corrupt { kind, hash, reason } => {
+                write!(f, "corrupt {kind} {hash}: {reason}")
+            }
+            FsckProblem::HashMismatch { kind, hash, actual } => {
+                write!(f, "hash mismatch for {kind} {hash}: the content hashes to {actual}")
+            }
+            FsckProblem::Missing { kind, hash, referenced_by } => {
+                write!(f, "missing {kind} {hash} referenced by {referenced_by}")
+            }
+            FsckProblem::CorruptPack { name, reason } => {
+                write!(f, "corrupt pack {name}: {reason}")
+            }
+            FsckProblem::InvalidRef { name, reason } => {
+                write!(f, "invalid {name}: {reason}")
+            }
+        }
+    }
+}
+
+/// Represents an object which is neither reachable from the references
+/// nor referenced by any other object
+#[derive(Debug, Clone)]
+pub struct DanglingObject {
+    pub kind: ObjectKind,
+    pub hash: String,
+}
+
+/// Represents the result of the repository check
+#[derive(Debug, Clone, Default)]
+pub struct FsckReport {
+    /// Represents the problems found in the repository
+    pub problems: Vec<FsckProblem>,
+
+    /// Represents the dangling objects. They don't indicate a problem
+    /// and can be removed with the garbage collection
+    pub dangling: Vec<DanglingObject>,
+
+    /// Represents the number of checked objects
+    pub checked: usize,
+}
+
+impl FsckReport {
+    /// Checks whether no problems were found
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::api_models::object::ObjectKind;
+
+/// Represents an unreachable object
+#[derive(Debug, Clone)]
+pub struct GcObject {
+    pub kind: ObjectKind,
+
+    /// Represents the object hash (or the file name for leftover temporary files)
+    pub hash: String,
+
+    /// Represents the size of the stored object in bytes
+    pub size: u64,
+}
+
+/// Represents the result of a garbage collection
+#[derive(Debug, Clone, Default)]
+pub struct GcReport {
+    /// Represents the unreachable objects which were removed
+    /// (or would be removed in a dry run)
+    pub removed: Vec<GcObject>,
+
+    /// Represents the number of unreachable objects kept because they are
+    /// younger than the grace period
+    pub kept_recent: usize,
+
+    /// Indicates whether nothing was actually removed
+    pub dry_run: bool,
+}
+
+impl GcReport {
+    /// Returns the total size of the removed objects in bytes
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.removed.iter().map(|o| o.size).sum()
+    }
+
+    /// Returns the number of the removed objects of the given kind
+    pub fn count(&self, kind: ObjectKind) -> usize {
+        self.removed.iter().filter(|o| o.kind == kind).count()
+    }
+}
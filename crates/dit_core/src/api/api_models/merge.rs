@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+/// Represents the outcome of merging a branch into the current branch
+#[derive(Debug, Clone)]
+pub enum MergeResult {
+    /// The current branch already contains all the commits of the merged branch
+    UpToDate,
+
+    /// The current branch was moved forward to the given commit
+    FastForward(String),
+
+    /// The histories diverged and a merge commit with the given hash was created
+    Merged(String),
+
+    /// The merge stopped because the given files have conflicts. They need to be
+    /// resolved, staged and committed to conclude the merge
+    Conflicted(Vec<PathBuf>),
+}
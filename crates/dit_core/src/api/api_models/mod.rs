@@ -3,3 +3,18 @@
 
 pub mod status;
 pub mod history;
+pub mod merge;
+pub mod diff;
+pub mod tag;
+pub mod stash;
+pub mod gc;
+pub mod object;
+pub mod fsck;
+pub mod pack;
+pub mod remote;
+pub mod reflog;
+pub mod oplog;
+pub mod rebase;
+pub mod pick;
+pub mod signing;
+pub mod checkout;
@@ -0,0 +1,30 @@
+use crate::models::Operation;
+
+/// Represents an operation recorded in the operation log
+#[derive(Debug, Clone)]
+pub struct OperationInfo {
+    /// Represents the position of the operation in the log
+    pub id: u64,
+
+    /// Describes the operation \
+    /// Example: "commit: initial commit"
+    pub description: String,
+
+    /// Represents the name and email address of the person who performed the operation
+    pub author: String,
+
+    /// Represents the time of the operation as a Unix timestamp - number of seconds
+    /// since January 1, 1970 (UTC)
+    pub timestamp: u64,
+}
+
+impl From<Operation> for OperationInfo {
+    fn from(operation: Operation) -> Self {
+        Self {
+            id: operation.id,
+            description: operation.description,
+            author: operation.author,
+            timestamp: operation.timestamp,
+        }
+    }
+}
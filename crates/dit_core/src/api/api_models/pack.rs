@@ -0,0 +1,25 @@
+/// Represents the result of repacking
+#[derive(Debug, Clone, Default)]
+pub struct RepackReport {
+    /// Represents the name of the new pack (`None` if there was nothing to pack)
+    pub pack: Option<String>,
+
+    /// Represents the number of the packed objects
+    pub packed: usize,
+
+    /// Represents the size of the new pack file in bytes
+    pub size: u64,
+
+    /// Indicates whether the blobs were compressed with a trained dictionary
+    pub dictionary: bool,
+
+    /// Represents the number of the removed loose objects which were packed
+    pub removed_loose: usize,
+
+    /// Represents the number of the removed old packs
+    pub removed_packs: usize,
+
+    /// Represents the number of the unreachable objects from the old packs
+    /// which were written back as loose objects
+    pub loosened: usize,
+}
@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+/// Represents the outcome of cherry-picking or reverting a commit
+#[derive(Debug, Clone)]
+pub enum PickResult {
+    /// The change was applied and committed as the given commit
+    Committed(String),
+
+    /// The change was applied, but the given files have conflicts. They need to be
+    /// resolved and staged, then committed with the prepared message
+    Conflicted {
+        paths: Vec<PathBuf>,
+        message: String,
+    },
+}
@@ -0,0 +1,21 @@
+use std::path::PathBuf;
+
+/// Represents the outcome of rebasing the current branch (or continuing the rebase)
+#[derive(Debug, Clone)]
+pub enum RebaseResult {
+    /// The current branch already contains all the commits of the upstream
+    UpToDate,
+
+    /// The current branch had no commits of its own and was moved forward to the given commit
+    FastForward(String),
+
+    /// All the commits were replayed and the branch now points to the given commit
+    Rebased(String),
+
+    /// Replaying the given commit stopped because the given files have conflicts.
+    /// They need to be resolved and staged to continue the rebase
+    Conflicted {
+        commit: String,
+        paths: Vec<PathBuf>,
+    },
+}
@@ -0,0 +1,11 @@
+use crate::models::ReflogEntry;
+
+/// Represents the reflog of HEAD or of a branch
+#[derive(Debug, Clone, Default)]
+pub struct Reflog {
+    /// Represents the name of the reference (`HEAD` or a branch name)
+    pub name: String,
+
+    /// Represents the movements of the reference, the most recent (`<name>@{0}`) first
+    pub entries: Vec<ReflogEntry>,
+}
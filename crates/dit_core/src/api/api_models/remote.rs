@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+/// Represents a configured remote
+#[derive(Debug, Clone)]
+pub struct RemoteInfo {
+    pub name: String,
+
+    pub url: String,
+
+    /// Maps the remote branch names to their head commits as of the last fetch or push
+    pub branches: BTreeMap<String, String>,
+}
+
+/// Represents a change of a remote-tracking branch
+#[derive(Debug, Clone)]
+pub struct RefUpdate {
+    pub branch: String,
+
+    /// Represents the previous head commit (`None` if the branch is new)
+    pub old: Option<String>,
+
+    /// Represents the new head commit (`None` if the branch was removed)
+    pub new: Option<String>,
+}
+
+/// Represents the result of fetching from a remote
+#[derive(Debug, Clone)]
+pub struct FetchReport {
+    pub remote: String,
+
+    /// Represents the current branch of the remote repository (if any)
+    pub head: Option<String>,
+
+    /// Represents the remote-tracking branches which changed
+    pub updates: Vec<RefUpdate>,
+
+    /// Represents the number of the received objects
+    pub objects: usize,
+}
+
+/// Represents the result of pushing a branch to a remote
+#[derive(Debug, Clone)]
+pub struct PushReport {
+    pub remote: String,
+
+    pub branch: String,
+
+    /// Represents the previous head of the remote branch (`None` if the branch was created)
+    pub old: Option<String>,
+
+    /// Represents the new head of the remote branch
+    pub new: String,
+
+    /// Represents the number of the sent objects
+    pub objects: usize,
+
+    /// Indicates whether the remote branch was overwritten by a non-fast-forward push
+    pub forced: bool,
+}
+
+impl PushReport {
+    /// Checks whether the remote branch already pointed to the pushed commit
+    pub fn is_up_to_date(&self) -> bool {
+        self.old.as_ref() == Some(&self.new)
+    }
+}
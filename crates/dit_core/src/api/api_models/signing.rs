@@ -0,0 +1,22 @@
+/// Represents the outcome of verifying the signature of a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// The commit is not signed
+    Unsigned,
+
+    /// The commit is signed with the given public key, but the signature doesn't match the commit
+    Bad(String),
+
+    /// The commit is correctly signed, but the given public key is not trusted
+    Untrusted(String),
+
+    /// The commit is correctly signed with the given trusted public key
+    Good(String),
+}
+
+impl SignatureStatus {
+    /// Checks whether the commit is correctly signed with a trusted key
+    pub fn is_good(&self) -> bool {
+        matches!(self, Self::Good(_))
+    }
+}
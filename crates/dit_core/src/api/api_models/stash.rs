@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+/// Represents a stash entry
+#[derive(Debug, Clone)]
+pub struct StashEntry {
+    /// Represents the position of the entry in the stash (0 is the most recent)
+    pub index: usize,
+
+    /// Represents the hash of the stash commit
+    pub commit: String,
+
+    /// Represents the stash message
+    pub message: String,
+
+    /// Represents the time the changes were stashed as a Unix timestamp
+    pub timestamp: u64,
+}
+
+/// Represents the result of applying a stash entry
+#[derive(Debug, Clone)]
+pub enum StashApplyResult {
+    /// The changes were applied without conflicts
+    Applied,
+
+    /// The changes were applied, but the given files contain conflict markers
+    Conflicted(Vec<PathBuf>),
+}
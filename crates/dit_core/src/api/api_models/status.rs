@@ -14,6 +14,8 @@ pub struct Status {
 
     pub untracked_files: Vec<PathBuf>,
     pub unchanged_files: Vec<PathBuf>,
+
+    pub unmerged_files: Vec<PathBuf>,
 }
 
 /// Getters
@@ -26,6 +28,7 @@ impl Status {
             ChangeType::Deleted => &self.tracked_deletions,
             ChangeType::New => &self.tracked_creations,
             ChangeType::Unchanged => &self.unchanged_files,
+            ChangeType::Conflicted => &self.unmerged_files,
         }
     }
 
@@ -35,6 +38,7 @@ impl Status {
             ChangeType::Deleted => &self.unstaged_deletions,
             ChangeType::New => &self.untracked_files, // NOTE: use get_untracked() instead of this
             ChangeType::Unchanged => &self.unchanged_files,
+            ChangeType::Conflicted => &self.unmerged_files,
         }
     }
 
@@ -46,6 +50,10 @@ impl Status {
         &self.untracked_files
     }
 
+    pub fn get_unmerged(&self) -> &Vec<PathBuf> {
+        &self.unmerged_files
+    }
+
     /// Checks if there are any tracked changes
     pub fn has_any_tracked(&self) -> bool {
         !self.tracked_creations.is_empty() || !self.tracked_modifications.is_empty() || !self.tracked_deletions.is_empty()
@@ -65,6 +73,11 @@ impl Status {
     pub fn has_any_unchanged(&self) -> bool {
         !self.unchanged_files.is_empty()
     }
+
+    /// Checks if there are any unmerged (conflicting) files
+    pub fn has_any_unmerged(&self) -> bool {
+        !self.unmerged_files.is_empty()
+    }
 }
 
 /// Setters
@@ -75,6 +88,7 @@ impl Status {
             ChangeType::Deleted => self.tracked_deletions.push(rel_path),
             ChangeType::New => self.tracked_creations.push(rel_path),
             ChangeType::Unchanged => self.unchanged_files.push(rel_path),
+            ChangeType::Conflicted => self.unmerged_files.push(rel_path),
         }
     }
 
@@ -84,6 +98,7 @@ impl Status {
             ChangeType::Deleted => self.unstaged_deletions.push(rel_path),
             ChangeType::New => self.untracked_files.push(rel_path),
             ChangeType::Unchanged => self.unchanged_files.push(rel_path),
+            ChangeType::Conflicted => self.unmerged_files.push(rel_path),
         }
     }
 }
@@ -94,6 +109,7 @@ pub enum ChangeType {
     Modified,
     Deleted,
     Unchanged,
+    Conflicted,
 }
 
 impl ChangeType {
@@ -103,6 +119,7 @@ impl ChangeType {
             Change::Modified(_) => ChangeType::Modified,
             Change::Deleted(_) => ChangeType::Deleted,
             Change::Unchanged(_) => ChangeType::Unchanged,
+            Change::Conflicted(_) => ChangeType::Conflicted,
             _ => unreachable!()
         }
     }
@@ -0,0 +1,14 @@
+use crate::models::Tag;
+
+/// Represents a tag with the commit it points to
+#[derive(Debug, Clone)]
+pub struct TagInfo {
+    /// Represents the tag name
+    pub name: String,
+
+    /// Represents the hash of the tagged commit
+    pub commit: String,
+
+    /// Represents the tag object if the tag is annotated
+    pub annotation: Option<Tag>,
+}
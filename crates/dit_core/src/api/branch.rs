@@ -1,37 +1,86 @@
 ﻿use crate::Dit;
+use crate::api_models::merge::MergeResult;
+use crate::api_models::checkout::CheckoutMode;
 use crate::errors::DitResult;
 
 /// Manipulate branches
 impl Dit {
     /// Creates a new branch
     pub fn create_branch<S: AsRef<str>>(&mut self, name: S) -> DitResult<()> {
-        self.branch_mgr()?.borrow_mut().create_branch(name)
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let name = name.as_ref();
+
+        if let Some(head) = self.get_head_commit()? {
+            self.commit_mgr().borrow().verify_branch_update(name, None, &head, &self.config_mgr()?.borrow())?;
+        }
+        self.branch_mgr()?.borrow_mut().create_branch(name)?;
+
+        let message = format!("branch: created {name} from {}", Self::describe_head(&start.refs));
+        self.finish_operation(start, None, message, false)
     }
 
-    /// Switches to a different branch
-    pub fn switch_branch<S: AsRef<str>>(&mut self, name: S, is_hard: bool) -> DitResult<()> {
+    /// Switches to a different branch. With [`CheckoutMode::Safe`], the switch is refused
+    /// if it would lose untracked files or local modifications
+    pub fn switch_branch<S: AsRef<str>>(&mut self, name: S, is_hard: bool, mode: CheckoutMode) -> DitResult<()> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let name = name.as_ref();
+        let message = format!("switch: moving from {} to {name}", Self::describe_head(&start.refs));
+
         self.branch_mgr()?.borrow_mut().switch_branch(
             name,
             is_hard,
-            &mut self.blob_mgr().borrow_mut(),
-            &mut self.tree_mgr().borrow_mut(),
-            &mut self.commit_mgr().borrow_mut(),
+            mode,
+            message.clone(),
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
             &mut self.index_mgr()?.borrow_mut(),
-            &mut self.ignore_mgr()?.borrow_mut(),
-        )
+            &mut self.checkout_mgr()?.borrow_mut(),
+        )?;
+
+        self.finish_operation(start, None, message, true)
     }
 
-    /// Merge a branch into the current branch
-    pub fn merge_branch<S: AsRef<str>>(&mut self, branch: S) -> DitResult<()> {
-        self.branch_mgr()?.borrow_mut().merge_to(
+    /// Merge a branch (or any other revision) into the current branch. With [`CheckoutMode::Safe`],
+    /// the merge is refused if it would overwrite untracked files or local modifications
+    pub fn merge_branch<S: AsRef<str>>(&mut self, branch: S, mode: CheckoutMode) -> DitResult<MergeResult> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let branch = branch.as_ref();
+
+        let their_head = self.resolve_revision(branch)?;
+        self.require_signing_key()?;
+        self.verify_incoming_commits(&their_head)?;
+
+        let result = self.branch_mgr()?.borrow_mut().merge_into_current(
             branch,
-            &self.commit_mgr().borrow()
-        )
+            their_head,
+            mode,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &mut self.commit_mgr().borrow_mut(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &self.config_mgr()?.borrow(),
+        )?;
+
+        let message = match &result {
+            MergeResult::FastForward(_) => format!("merge {branch}: fast-forward"),
+            _ => format!("merge {branch}"),
+        };
+        self.finish_operation(start, None, message, true)?;
+        Ok(result)
     }
 
     /// Removes a given branch
     pub fn remove_branch<S: AsRef<str>>(&mut self, name: S) -> DitResult<()> {
-        self.branch_mgr()?.borrow_mut().remove_branch(name)
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+
+        self.branch_mgr()?.borrow_mut().remove_branch(name)?;
+
+        self.finish_operation(start, None, "branch: removed", false)
     }
 }
 
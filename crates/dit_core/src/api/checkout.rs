@@ -0,0 +1,63 @@
+use crate::Dit;
+use crate::models::{CheckoutJournal, OperationState};
+use crate::errors::DitResult;
+
+/// Recovery of interrupted working tree updates
+impl Dit {
+    /// Returns the description of the working tree update (a branch switch or a hard reset)
+    /// which was interrupted, if any. The repository can't be changed until it's recovered
+    pub fn interrupted_checkout(&self) -> DitResult<Option<String>> {
+        Ok(self.checkout_mgr()?.borrow().journal().map(|journal| journal.description.clone()))
+    }
+
+    /// Finishes the interrupted working tree update and records it in the operation log,
+    /// like if it hadn't been interrupted. Returns its description
+    pub fn recover_continue(&mut self) -> DitResult<String> {
+        let _lock = self.lock_for_recovery()?;
+        let start = self.journal_start(self.checkout_mgr()?.borrow().require_journal()?)?;
+
+        let journal = self.checkout_mgr()?.borrow_mut().continue_checkout(
+            &self.blob_mgr().borrow(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+        )?;
+
+        self.finish_operation(start, None, journal.description.clone(), true)?;
+        Ok(journal.description)
+    }
+
+    /// Rolls back the interrupted working tree update, restoring the files it changed,
+    /// the index and HEAD. Returns its description
+    pub fn recover_rollback(&mut self) -> DitResult<String> {
+        let _lock = self.lock_for_recovery()?;
+
+        let journal = self.checkout_mgr()?.borrow_mut().rollback_checkout(
+            &self.blob_mgr().borrow(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+        )?;
+
+        Ok(journal.description)
+    }
+}
+
+
+/// Private
+impl Dit {
+    /// Returns the state of the repository before the interrupted update,
+    /// to be recorded in the operation log once it's finished
+    fn journal_start(&self, journal: &CheckoutJournal) -> DitResult<OperationState> {
+        let mut refs = self.refs_snapshot()?;
+        refs.branch = journal.orig_branch.clone();
+        refs.head = journal.orig_head.clone();
+        if let Some(branch) = &journal.orig_branch {
+            refs.branches.insert(branch.clone(), journal.orig_head.clone());
+        }
+
+        Ok(OperationState {
+            refs,
+            index: journal.orig_index.clone(),
+            rebase: self.rebase_mgr()?.borrow().state().cloned(),
+        })
+    }
+}
@@ -1,5 +1,11 @@
 ﻿use crate::Dit;
-use crate::errors::DitResult;
+use crate::api_models::pick::PickResult;
+use crate::api_models::checkout::CheckoutMode;
+use crate::managers::commit::CommitMgr;
+use crate::helpers::{create_temp_file, read_to_string};
+use crate::errors::{CommitError, DitResult};
+use std::fs;
+use std::process::Command;
 
 /// Manipulate commits
 impl Dit {
@@ -7,44 +13,208 @@ impl Dit {
     pub fn commit<S1: Into<String>, S2: Into<String>>(&mut self, message: S1, author: Option<S2>)
         -> DitResult<()>
     {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        self.require_signing_key()?;
+
         self.commit_mgr().borrow_mut().create_commit(
             message,
             author,
             &self.tree_mgr().borrow_mut(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+            &self.config_mgr()?.borrow(),
+        )?;
+
+        let head = self.get_head_commit()?.unwrap_or_default();
+        let commit = self.commit_mgr().borrow().get_commit(head)?;
+        let kind = if commit.parents.len() > 1 { "commit (merge)" } else { "commit" };
+        let summary = commit.message.lines().next().unwrap_or_default();
+        self.finish_operation(start, Some(commit.author.to_string()), format!("{kind}: {summary}"), false)
+    }
+
+    /// Replaces the HEAD commit with a commit of the current index, keeping its parents.
+    /// The message and the author of the HEAD commit are kept unless new ones are given
+    pub fn amend_commit<S1: Into<String>, S2: Into<String>>(
+        &mut self,
+        message: Option<S1>,
+        author: Option<S2>,
+    ) -> DitResult<()> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        self.require_signing_key()?;
+
+        self.commit_mgr().borrow_mut().amend_commit(
+            message,
+            author,
+            &self.tree_mgr().borrow(),
             &self.index_mgr()?.borrow(),
             &mut self.branch_mgr()?.borrow_mut(),
             &self.config_mgr()?.borrow(),
-        )
+        )?;
+
+        let head = self.get_head_commit()?.unwrap_or_default();
+        let commit = self.commit_mgr().borrow().get_commit(head)?;
+        let summary = commit.message.lines().next().unwrap_or_default();
+        self.finish_operation(start, Some(commit.author.to_string()), format!("commit (amend): {summary}"), false)
     }
 
     /// Performs a mixed reset to a specific commit. All files not included in
     /// that commit tree stay the same.
     pub fn mixed_reset<S: AsRef<str>>(&mut self, commit: S) -> DitResult<()>
     {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let revision = commit.as_ref();
+
+        let commit = self.resolve_revision(revision)?;
+        self.verify_incoming_commits(&commit)?;
         self.commit_mgr().borrow_mut().mixed_reset(
             commit,
             &mut self.blob_mgr().borrow_mut(),
             &mut self.tree_mgr().borrow_mut(),
             &mut self.branch_mgr()?.borrow_mut()
-        )
+        )?;
+
+        self.finish_operation(start, None, format!("reset: moving to {revision}"), false)
     }
 
-    /// Performs a hard reset to a specific commit. The root of the project will be changed
-    /// to exactly match the target commit tree (except the ignored files)
-    pub fn hard_reset<S: AsRef<str>>(&mut self, commit: S) -> DitResult<()>
+    /// Performs a hard reset to a specific commit. The tracked files of the project will be
+    /// changed to exactly match the target commit tree, the untracked files are kept.
+    /// With [`CheckoutMode::Safe`], the reset is refused if it would lose untracked files
+    /// or unstaged changes
+    pub fn hard_reset<S: AsRef<str>>(&mut self, commit: S, mode: CheckoutMode) -> DitResult<()>
     {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let revision = commit.as_ref();
+
+        let message = format!("reset: moving to {revision}");
+
+        let commit = self.resolve_revision(revision)?;
+        self.verify_incoming_commits(&commit)?;
         self.commit_mgr().borrow_mut().hard_reset(
             commit,
+            mode,
+            message.clone(),
             &mut self.blob_mgr().borrow_mut(),
             &mut self.tree_mgr().borrow_mut(),
             &mut self.branch_mgr()?.borrow_mut(),
-            &mut self.ignore_mgr()?.borrow_mut(),
-        )
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.checkout_mgr()?.borrow_mut(),
+        )?;
+
+        self.finish_operation(start, None, message, true)
     }
 
     /// Performs a soft reset to a specific commit. Only changes the head pointer and leaves
     /// the files untouched
     pub fn soft_reset<S: AsRef<str>>(&mut self, commit: S) -> DitResult<()> {
-        self.commit_mgr().borrow_mut().soft_reset(commit, &mut self.branch_mgr()?.borrow_mut())
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let revision = commit.as_ref();
+
+        let commit = self.resolve_revision(revision)?;
+        self.verify_incoming_commits(&commit)?;
+        self.commit_mgr().borrow_mut().soft_reset(commit, &mut self.branch_mgr()?.borrow_mut())?;
+
+        self.finish_operation(start, None, format!("reset: moving to {revision}"), false)
+    }
+
+    /// Applies the change of a commit relative to its first parent on top of HEAD and
+    /// commits it, recording the original commit hash in the message
+    pub fn cherry_pick<S: AsRef<str>>(&mut self, revision: S) -> DitResult<PickResult> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let revision = revision.as_ref();
+        self.require_signing_key()?;
+
+        let hash = self.resolve_revision(revision)?;
+        let result = self.commit_mgr().borrow_mut().cherry_pick(
+            revision,
+            hash,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+            &self.config_mgr()?.borrow(),
+        )?;
+
+        self.finish_operation(start, None, format!("cherry-pick: {revision}"), true)?;
+        Ok(result)
+    }
+
+    /// Applies the inverse of the change of a commit relative to its first parent
+    /// on top of HEAD and commits it, recording the reverted commit hash in the message
+    pub fn revert<S: AsRef<str>>(&mut self, revision: S) -> DitResult<PickResult> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let revision = revision.as_ref();
+        self.require_signing_key()?;
+
+        let hash = self.resolve_revision(revision)?;
+        let result = self.commit_mgr().borrow_mut().revert(
+            revision,
+            hash,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+            &self.config_mgr()?.borrow(),
+        )?;
+
+        self.finish_operation(start, None, format!("revert: {revision}"), true)?;
+        Ok(result)
+    }
+}
+
+
+/// Getters
+impl Dit {
+    /// Resolves a revision (`HEAD`, a tag or branch name, `<remote>/<branch>`, a unique commit hash
+    /// prefix, optionally followed by `~N`/`^N` suffixes) to the full hash of a commit
+    pub fn resolve_revision<S: AsRef<str>>(&self, revision: S) -> DitResult<String> {
+        self.commit_mgr().borrow().resolve_revision(
+            revision,
+            &self.branch_mgr()?.borrow(),
+            &self.tag_mgr().borrow(),
+            &self.remote_mgr().borrow(),
+            &self.reflog_mgr().borrow(),
+        )
+    }
+}
+
+
+/// Commit messages
+impl Dit {
+    /// Opens the configured editor (`core.editor`, otherwise `$VISUAL` or `$EDITOR`) on
+    /// a file prefilled with a template describing the staged changes and returns the
+    /// written message without the comment lines. Fails if the message is empty
+    pub fn edit_commit_message(&self) -> DitResult<String> {
+        let editor = self.config_mgr()?.borrow().get_editor().ok_or(CommitError::NoEditor)?;
+
+        let template = CommitMgr::message_template(self.get_branch()?.as_deref(), &self.get_status()?);
+        let (_, path) = create_temp_file(self.repo.dit())?;
+        fs::write(&path, template)?;
+
+        // The editor may be configured with arguments, e.g. `code --wait`
+        let mut parts = editor.split_whitespace();
+        let status = Command::new(parts.next().unwrap_or_default())
+            .args(parts)
+            .arg(&path)
+            .status();
+
+        let raw = match status {
+            Ok(status) if status.success() => read_to_string(&path),
+            _ => Err(CommitError::EditorFailed(editor).into()),
+        };
+        let _ = fs::remove_file(&path);
+
+        let message = CommitMgr::strip_message(&raw?);
+        if message.is_empty() {
+            return Err(CommitError::EmptyMessage.into());
+        }
+
+        Ok(message)
     }
 }
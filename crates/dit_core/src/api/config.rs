@@ -1,15 +1,55 @@
 use crate::Dit;
+use crate::managers::commit::CommitMgr;
+use crate::helpers::{path_to_string, resolve_absolute_path};
 use crate::errors::DitResult;
+use std::path::Path;
 
 /// Set
 impl Dit {
     pub fn config_set_user_name(&mut self, value: String) -> DitResult<()> {
+        let _lock = self.lock()?;
         self.config_mgr()?.borrow_mut().set_user_name(value)
     }
 
     pub fn config_set_user_email(&mut self, value: String) -> DitResult<()> {
+        let _lock = self.lock()?;
         self.config_mgr()?.borrow_mut().set_user_email(value)
     }
+
+    pub fn config_set_editor(&mut self, value: String) -> DitResult<()> {
+        let _lock = self.lock()?;
+        self.config_mgr()?.borrow_mut().set_editor(value)
+    }
+
+    pub fn config_set_max_subject_length(&mut self, value: usize) -> DitResult<()> {
+        let _lock = self.lock()?;
+        self.config_mgr()?.borrow_mut().set_max_subject_length(value)
+    }
+
+    pub fn config_set_blank_second_line(&mut self, value: bool) -> DitResult<()> {
+        let _lock = self.lock()?;
+        self.config_mgr()?.borrow_mut().set_blank_second_line(value)
+    }
+
+    /// Sets the private key new commits are signed with. The path is stored as an
+    /// absolute path, and the file must contain a valid key
+    pub fn config_set_signing_key<P: AsRef<Path>>(&mut self, path: P) -> DitResult<()> {
+        let _lock = self.lock()?;
+        let path = resolve_absolute_path(path.as_ref())?;
+        CommitMgr::load_signing_key(&path)?;
+
+        self.config_mgr()?.borrow_mut().set_signing_key(path_to_string(&path))
+    }
+
+    pub fn config_set_trusted_keys(&mut self, value: Vec<String>) -> DitResult<()> {
+        let _lock = self.lock()?;
+        self.config_mgr()?.borrow_mut().set_trusted_keys(value)
+    }
+
+    pub fn config_set_signed_branches(&mut self, value: Vec<String>) -> DitResult<()> {
+        let _lock = self.lock()?;
+        self.config_mgr()?.borrow_mut().set_signed_branches(value)
+    }
 }
 
 /// Get
@@ -25,4 +65,28 @@ impl Dit {
     pub fn config_get_user(&self) -> DitResult<Option<String>> {
         Ok(self.config_mgr()?.borrow().get_user())
     }
+
+    pub fn config_get_editor(&self) -> DitResult<Option<String>> {
+        Ok(self.config_mgr()?.borrow().get_editor_config())
+    }
+
+    pub fn config_get_max_subject_length(&self) -> DitResult<Option<usize>> {
+        Ok(self.config_mgr()?.borrow().get_max_subject_length())
+    }
+
+    pub fn config_get_blank_second_line(&self) -> DitResult<Option<bool>> {
+        Ok(self.config_mgr()?.borrow().get_blank_second_line())
+    }
+
+    pub fn config_get_signing_key(&self) -> DitResult<Option<String>> {
+        Ok(self.config_mgr()?.borrow().get_signing_key())
+    }
+
+    pub fn config_get_trusted_keys(&self) -> DitResult<Vec<String>> {
+        Ok(self.config_mgr()?.borrow().get_trusted_keys().to_vec())
+    }
+
+    pub fn config_get_signed_branches(&self) -> DitResult<Vec<String>> {
+        Ok(self.config_mgr()?.borrow().get_signed_branches().to_vec())
+    }
 }
\ No newline at end of file
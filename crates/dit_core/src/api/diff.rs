@@ -0,0 +1,47 @@
+use crate::Dit;
+use crate::api_models::diff::Diff;
+use crate::errors::DitResult;
+
+/// Compare the versions of the files
+impl Dit {
+    /// Returns the changes in the working tree which are not staged yet
+    pub fn diff_unstaged(&self) -> DitResult<Diff> {
+        self.diff_mgr().borrow().diff_working_tree(
+            &self.index_mgr()?.borrow(),
+            &self.blob_mgr().borrow(),
+        )
+    }
+
+    /// Returns the staged changes (the index compared to the HEAD commit tree)
+    pub fn diff_staged(&self) -> DitResult<Diff> {
+        let head_index = self.branch_mgr()?.borrow()
+            .get_head_tree(&self.tree_mgr().borrow(), &self.commit_mgr().borrow())?
+            .unwrap_or_default();
+
+        self.diff_mgr().borrow().diff_indexes(
+            &head_index,
+            self.index_mgr()?.borrow().index(),
+            &self.blob_mgr().borrow(),
+        )
+    }
+
+    /// Returns the changes between two commits (given as revisions)
+    pub fn diff_commits<S1: AsRef<str>, S2: AsRef<str>>(&self, old: S1, new: S2) -> DitResult<Diff> {
+        let old = self.resolve_revision(old)?;
+        let new = self.resolve_revision(new)?;
+
+        let commit_mgr = self.commit_mgr().borrow();
+        let old_tree = commit_mgr.get_commit(old)?.tree;
+        let new_tree = commit_mgr.get_commit(new)?.tree;
+
+        self.diff_trees(old_tree, new_tree)
+    }
+
+    /// Returns the changes between two trees. Only the directories which differ are compared
+    pub fn diff_trees<S1: AsRef<str>, S2: AsRef<str>>(&self, old: S1, new: S2) -> DitResult<Diff> {
+        let (old_index, new_index) = self.tree_mgr().borrow()
+            .diff_trees(Some(old.as_ref()), Some(new.as_ref()))?;
+
+        self.diff_mgr().borrow().diff_indexes(&old_index, &new_index, &self.blob_mgr().borrow())
+    }
+}
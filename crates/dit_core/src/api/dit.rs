@@ -6,8 +6,19 @@ use crate::tree::TreeMgr;
 use crate::blob::BlobMgr;
 use crate::ignore::IgnoreMgr;
 use crate::config::ConfigMgr;
-use crate::errors::DitResult;
-use crate::Repo;
+use crate::diff::DiffMgr;
+use crate::tag::TagMgr;
+use crate::stash::StashMgr;
+use crate::gc::GcMgr;
+use crate::fsck::FsckMgr;
+use crate::pack::PackMgr;
+use crate::remote::RemoteMgr;
+use crate::reflog::ReflogMgr;
+use crate::oplog::OpLogMgr;
+use crate::rebase::RebaseMgr;
+use crate::checkout::CheckoutMgr;
+use crate::errors::{CheckoutError, DitResult};
+use crate::{Repo, RepoLock};
 use once_cell::unsync::OnceCell;
 use std::cell::RefCell;
 use std::path::Path;
@@ -23,6 +34,17 @@ pub struct Dit {
     branch_mgr: OnceCell<RefCell<BranchMgr>>,
     ignore_mgr: OnceCell<RefCell<IgnoreMgr>>,
     config_mgr: OnceCell<RefCell<ConfigMgr>>,
+    diff_mgr: OnceCell<RefCell<DiffMgr>>,
+    tag_mgr: OnceCell<RefCell<TagMgr>>,
+    stash_mgr: OnceCell<RefCell<StashMgr>>,
+    gc_mgr: OnceCell<RefCell<GcMgr>>,
+    fsck_mgr: OnceCell<RefCell<FsckMgr>>,
+    pack_mgr: OnceCell<RefCell<PackMgr>>,
+    remote_mgr: OnceCell<RefCell<RemoteMgr>>,
+    reflog_mgr: OnceCell<RefCell<ReflogMgr>>,
+    oplog_mgr: OnceCell<RefCell<OpLogMgr>>,
+    rebase_mgr: OnceCell<RefCell<RebaseMgr>>,
+    checkout_mgr: OnceCell<RefCell<CheckoutMgr>>,
 }
 
 
@@ -42,12 +64,56 @@ impl Dit {
             branch_mgr: OnceCell::new(),
             ignore_mgr: OnceCell::new(),
             config_mgr: OnceCell::new(),
+            diff_mgr: OnceCell::new(),
+            tag_mgr: OnceCell::new(),
+            stash_mgr: OnceCell::new(),
+            gc_mgr: OnceCell::new(),
+            fsck_mgr: OnceCell::new(),
+            pack_mgr: OnceCell::new(),
+            remote_mgr: OnceCell::new(),
+            reflog_mgr: OnceCell::new(),
+            oplog_mgr: OnceCell::new(),
+            rebase_mgr: OnceCell::new(),
+            checkout_mgr: OnceCell::new(),
         };
 
         Ok(dit)
     }
 }
 
+/// Getters
+impl Dit {
+    /// Returns the project path where the `.dit` is located
+    pub fn repo_path(&self) -> &Path {
+        self.repo.repo_path()
+    }
+}
+
+/// Locking
+impl Dit {
+    /// Locks the repository for an operation which changes it. Another process might
+    /// have changed the repository before it was locked, so the managers are reloaded.
+    ///
+    /// Fails if a working tree update was interrupted, until it's continued or rolled back
+    pub(super) fn lock(&mut self) -> DitResult<RepoLock> {
+        let lock = self.lock_for_recovery()?;
+        if let Some(journal) = self.checkout_mgr()?.borrow().journal() {
+            return Err(CheckoutError::Interrupted(journal.description.clone()).into());
+        }
+        Ok(lock)
+    }
+
+    /// Same as [`Dit::lock`], but doesn't fail if a working tree update was interrupted,
+    /// so that it can be recovered
+    pub(super) fn lock_for_recovery(&mut self) -> DitResult<RepoLock> {
+        let lock = self.repo.lock()?;
+        if lock.is_outermost() {
+            self.reload_managers();
+        }
+        Ok(lock)
+    }
+}
+
 /// Manager getters
 impl Dit {
     /// Returns the blob manager
@@ -91,4 +157,93 @@ impl Dit {
             Ok(RefCell::new(ConfigMgr::from(self.repo.clone())?))
         })
     }
+
+    /// Returns the diff manager
+    pub fn diff_mgr(&self) -> &RefCell<DiffMgr> {
+        self.diff_mgr.get_or_init(|| RefCell::new(DiffMgr::from(self.repo.clone())))
+    }
+
+    /// Returns the tag manager
+    pub fn tag_mgr(&self) -> &RefCell<TagMgr> {
+        self.tag_mgr.get_or_init(|| RefCell::new(TagMgr::from(self.repo.clone())))
+    }
+
+    /// Returns the stash manager
+    pub fn stash_mgr(&self) -> DitResult<&RefCell<StashMgr>> {
+        self.stash_mgr.get_or_try_init(|| {
+            Ok(RefCell::new(StashMgr::from(self.repo.clone())?))
+        })
+    }
+
+    /// Returns the garbage collection manager
+    pub fn gc_mgr(&self) -> &RefCell<GcMgr> {
+        self.gc_mgr.get_or_init(|| RefCell::new(GcMgr::from(self.repo.clone())))
+    }
+
+    /// Returns the repository check manager
+    pub fn fsck_mgr(&self) -> &RefCell<FsckMgr> {
+        self.fsck_mgr.get_or_init(|| RefCell::new(FsckMgr::from(self.repo.clone())))
+    }
+
+    /// Returns the pack manager
+    pub fn pack_mgr(&self) -> &RefCell<PackMgr> {
+        self.pack_mgr.get_or_init(|| RefCell::new(PackMgr::from(self.repo.clone())))
+    }
+
+    /// Returns the remote manager
+    pub fn remote_mgr(&self) -> &RefCell<RemoteMgr> {
+        self.remote_mgr.get_or_init(|| RefCell::new(RemoteMgr::from(self.repo.clone())))
+    }
+
+    /// Returns the reflog manager
+    pub fn reflog_mgr(&self) -> &RefCell<ReflogMgr> {
+        self.reflog_mgr.get_or_init(|| RefCell::new(ReflogMgr::from(self.repo.clone())))
+    }
+
+    /// Returns the operation log manager
+    pub fn oplog_mgr(&self) -> &RefCell<OpLogMgr> {
+        self.oplog_mgr.get_or_init(|| RefCell::new(OpLogMgr::from(self.repo.clone())))
+    }
+
+    /// Returns the rebase manager
+    pub fn rebase_mgr(&self) -> DitResult<&RefCell<RebaseMgr>> {
+        self.rebase_mgr.get_or_try_init(|| {
+            Ok(RefCell::new(RebaseMgr::from(self.repo.clone())?))
+        })
+    }
+
+    /// Returns the checkout manager
+    pub fn checkout_mgr(&self) -> DitResult<&RefCell<CheckoutMgr>> {
+        self.checkout_mgr.get_or_try_init(|| {
+            Ok(RefCell::new(CheckoutMgr::from(self.repo.clone())?))
+        })
+    }
+
+    /// Drops all the managers, so that they reload the state of the repository
+    fn reload_managers(&mut self) {
+        self.reload_packs();
+        self.index_mgr.take();
+        self.branch_mgr.take();
+        self.ignore_mgr.take();
+        self.config_mgr.take();
+        self.diff_mgr.take();
+        self.tag_mgr.take();
+        self.stash_mgr.take();
+        self.gc_mgr.take();
+        self.remote_mgr.take();
+        self.reflog_mgr.take();
+        self.oplog_mgr.take();
+        self.rebase_mgr.take();
+        self.checkout_mgr.take();
+    }
+
+    /// Drops the managers which cache the pack indexes,
+    /// so that they are reloaded after the packs change
+    pub(super) fn reload_packs(&mut self) {
+        self.blob_mgr.take();
+        self.tree_mgr.take();
+        self.commit_mgr.take();
+        self.fsck_mgr.take();
+        self.pack_mgr.take();
+    }
 }
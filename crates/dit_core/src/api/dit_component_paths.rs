@@ -3,7 +3,18 @@ pub const BLOBS_ROOT: &str = ".dit/blobs";
 pub const TREES_ROOT: &str = ".dit/trees";
 pub const COMMITS_ROOT: &str = ".dit/commits";
 pub const BRANCHES_ROOT: &str = ".dit/branches";
+pub const TAGS_ROOT: &str = ".dit/tags";
+pub const TAG_OBJECTS_ROOT: &str = ".dit/tag_objects";
+pub const PACKS_ROOT: &str = ".dit/packs";
+pub const REMOTES_ROOT: &str = ".dit/remotes";
+pub const BRANCH_LOGS_ROOT: &str = ".dit/logs/branches";
+pub const OPS_ROOT: &str = ".dit/ops";
 pub const HEAD_FILE: &str = ".dit/head";
 pub const IGNORE_FILE: &str =  ".ditignore";
 pub const INDEX_FILE: &str = ".dit/index";
 pub const CONFIG_FILE: &str = ".dit/config";
+pub const STASH_FILE: &str = ".dit/stash";
+pub const HEAD_LOG_FILE: &str = ".dit/logs/HEAD";
+pub const LOCK_FILE: &str = ".dit/lock";
+pub const REBASE_FILE: &str = ".dit/rebase";
+pub const CHECKOUT_FILE: &str = ".dit/checkout";
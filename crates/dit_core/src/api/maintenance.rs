@@ -0,0 +1,70 @@
+use crate::Dit;
+use crate::api_models::gc::GcReport;
+use crate::api_models::fsck::FsckReport;
+use crate::api_models::pack::RepackReport;
+use crate::errors::DitResult;
+use std::time::Duration;
+
+/// Maintenance
+impl Dit {
+    /// Removes the blobs, trees, commits and tag objects which are unreachable from
+    /// the branches, the remote-tracking branches, the tags, the head, the index, the stash,
+    /// the reflog, the operation log and the rebase in progress.
+    /// Objects younger than the grace period are kept. In a dry run, only reports what would
+    /// be removed
+    pub fn gc(&mut self, grace_period: Duration, dry_run: bool) -> DitResult<GcReport> {
+        let _lock = self.lock()?;
+        self.gc_mgr().borrow().collect_garbage(
+            grace_period,
+            dry_run,
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+            &self.index_mgr()?.borrow(),
+            &self.branch_mgr()?.borrow(),
+            &self.tag_mgr().borrow(),
+            &self.stash_mgr()?.borrow(),
+            &self.remote_mgr().borrow(),
+            &self.reflog_mgr().borrow(),
+            &self.oplog_mgr().borrow(),
+            &self.rebase_mgr()?.borrow(),
+        )
+    }
+
+    /// Packs all the reachable blobs, trees and commits into a single pack file,
+    /// optionally compressing the blobs with a trained dictionary. The old packs
+    /// and the loose copies of the packed objects are removed
+    pub fn repack(&mut self, use_dictionary: bool) -> DitResult<RepackReport> {
+        let _lock = self.lock()?;
+        let reachable = self.gc_mgr().borrow().mark(
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+            &self.index_mgr()?.borrow(),
+            &self.branch_mgr()?.borrow(),
+            &self.tag_mgr().borrow(),
+            &self.stash_mgr()?.borrow(),
+            &self.remote_mgr().borrow(),
+            &self.reflog_mgr().borrow(),
+            &self.oplog_mgr().borrow(),
+            &self.rebase_mgr()?.borrow(),
+        )?;
+
+        let report = self.pack_mgr().borrow_mut().repack(
+            &reachable,
+            use_dictionary,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+        );
+
+        self.reload_packs();
+        report
+    }
+
+    /// Checks the integrity of the repository: recalculates the hashes of all the
+    /// objects, follows the references between them and validates HEAD, the branches,
+    /// the remote-tracking branches, the tags, the index, the stash, the reflog,
+    /// the operation log and the rebase in progress
+    pub fn fsck(&self) -> DitResult<FsckReport> {
+        self.fsck_mgr().borrow().check()
+    }
+}
@@ -1,5 +1,6 @@
 ﻿mod dit;
 mod repo;
+mod repo_lock;
 mod commit;
 mod stage;
 mod branch;
@@ -7,8 +8,19 @@ mod status;
 mod history;
 mod glob;
 mod config;
+mod diff;
+mod tag;
+mod stash;
+mod maintenance;
+mod remote;
+mod reflog;
+mod oplog;
+mod rebase;
+mod signing;
+mod checkout;
 
 pub use dit::Dit;
 pub use repo::Repo;
+pub use repo_lock::RepoLock;
 pub mod api_models;
 pub mod dit_component_paths;
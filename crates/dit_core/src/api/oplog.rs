@@ -0,0 +1,82 @@
+use crate::Dit;
+use crate::api_models::oplog::OperationInfo;
+use crate::models::OperationState;
+use crate::errors::DitResult;
+
+/// Operation log
+impl Dit {
+    /// Undoes the most recent operation recorded in the operation log and returns it.
+    /// HEAD, the branches changed by the operation and the index are restored,
+    /// as well as the tracked files if the operation changed the working tree
+    pub fn undo(&mut self) -> DitResult<OperationInfo> {
+        let _lock = self.lock()?;
+        let before = self.refs_snapshot()?;
+
+        let operation = self.oplog_mgr().borrow().undo_last(
+            &before,
+            &self.blob_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+            &mut self.branch_mgr()?.borrow_mut(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.rebase_mgr()?.borrow_mut(),
+            &self.config_mgr()?.borrow(),
+        )?;
+
+        self.record_reflog(before, None, format!("undo: {}", operation.description))?;
+        Ok(operation.into())
+    }
+}
+
+
+/// Getters
+impl Dit {
+    /// Returns the operations recorded in the operation log, the most recent first.
+    /// At most `count` operations are returned, all of them if `count` is negative
+    pub fn list_operations(&self, count: isize) -> DitResult<Vec<OperationInfo>> {
+        let count = usize::try_from(count).unwrap_or(usize::MAX);
+
+        Ok(self.oplog_mgr().borrow().list_operations()?
+            .into_iter()
+            .rev()
+            .take(count)
+            .map(OperationInfo::from)
+            .collect())
+    }
+}
+
+
+/// Private
+impl Dit {
+    /// Captures the state of the repository before an operation,
+    /// to be passed to [`Dit::finish_operation`] after it
+    pub(super) fn start_operation(&self) -> DitResult<OperationState> {
+        self.operation_state()
+    }
+
+    /// Records the references moved by an operation in the reflog, and the operation
+    /// together with the state before it in the operation log. `worktree` tells whether
+    /// the operation updated the working tree. The author defaults to the configured user
+    pub(super) fn finish_operation<S: Into<String>>(
+        &self,
+        start: OperationState,
+        author: Option<String>,
+        description: S,
+        worktree: bool,
+    ) -> DitResult<()> {
+        let after = self.operation_state()?;
+        let author = self.resolve_author(author)?;
+        let description = description.into();
+
+        self.reflog_mgr().borrow().record_changes(&start.refs, &after.refs, &author, &description)?;
+        self.oplog_mgr().borrow().record(start, &after, author, description, worktree)
+    }
+
+    /// Captures the references, the index and the rebase in progress
+    fn operation_state(&self) -> DitResult<OperationState> {
+        Ok(OperationState {
+            refs: self.refs_snapshot()?,
+            index: self.index_mgr()?.borrow().index().clone(),
+            rebase: self.rebase_mgr()?.borrow().state().cloned(),
+        })
+    }
+}
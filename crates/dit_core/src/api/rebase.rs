@@ -0,0 +1,90 @@
+use crate::Dit;
+use crate::api_models::rebase::RebaseResult;
+use crate::errors::DitResult;
+
+/// Rebasing
+impl Dit {
+    /// Replays the commits of the current branch which are not in the upstream
+    /// (a branch or any other revision) on top of it
+    pub fn rebase<S: AsRef<str>>(&mut self, upstream: S) -> DitResult<RebaseResult> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        let upstream = upstream.as_ref();
+
+        let onto = self.resolve_revision(upstream)?;
+        self.require_signing_key()?;
+        self.verify_incoming_commits(&onto)?;
+
+        let result = self.rebase_mgr()?.borrow_mut().start_rebase(
+            upstream,
+            onto,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &mut self.commit_mgr().borrow_mut(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+            &self.config_mgr()?.borrow(),
+        )?;
+
+        let message = match &result {
+            RebaseResult::FastForward(_) => format!("rebase onto {upstream}: fast-forward"),
+            _ => format!("rebase onto {upstream}"),
+        };
+        self.finish_operation(start, None, message, true)?;
+        Ok(result)
+    }
+
+    /// Continues the rebase which stopped because of conflicts, once they are resolved
+    pub fn rebase_continue(&mut self) -> DitResult<RebaseResult> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        self.require_signing_key()?;
+
+        let result = self.rebase_mgr()?.borrow_mut().continue_rebase(
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &mut self.commit_mgr().borrow_mut(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+            &self.config_mgr()?.borrow(),
+        )?;
+
+        self.finish_operation(start, None, "rebase: continue", true)?;
+        Ok(result)
+    }
+
+    /// Skips the commit whose replay stopped because of conflicts and continues the rebase
+    pub fn rebase_skip(&mut self) -> DitResult<RebaseResult> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+        self.require_signing_key()?;
+
+        let result = self.rebase_mgr()?.borrow_mut().skip_commit(
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &mut self.commit_mgr().borrow_mut(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+            &self.config_mgr()?.borrow(),
+        )?;
+
+        self.finish_operation(start, None, "rebase: skip", true)?;
+        Ok(result)
+    }
+
+    /// Aborts the rebase and returns to the state before it
+    pub fn rebase_abort(&mut self) -> DitResult<()> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+
+        self.rebase_mgr()?.borrow_mut().abort_rebase(
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &mut self.branch_mgr()?.borrow_mut(),
+        )?;
+
+        self.finish_operation(start, None, "rebase: abort", true)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Dit;
+use crate::api_models::reflog::Reflog;
+use crate::models::RefsSnapshot;
+use crate::errors::DitResult;
+
+/// Name of the author recorded in the reflog when no user is configured
+const UNKNOWN_AUTHOR: &str = "unknown";
+
+/// Getters
+impl Dit {
+    /// Returns the reflog of HEAD or of a branch, the most recent entry first.
+    /// At most `count` entries are returned, all of them if `count` is negative
+    pub fn get_reflog<S: AsRef<str>>(&self, name: S, count: isize) -> DitResult<Reflog> {
+        let name = name.as_ref();
+        let count = usize::try_from(count).unwrap_or(usize::MAX);
+
+        let entries = self.reflog_mgr().borrow().read_log(name)?
+            .into_iter()
+            .rev()
+            .take(count)
+            .collect();
+
+        Ok(Reflog { name: name.to_string(), entries })
+    }
+}
+
+
+/// Private
+impl Dit {
+    /// Returns the commits HEAD and the branches point to before an operation,
+    /// to be passed to [`Dit::record_reflog`] after it
+    pub(super) fn refs_snapshot(&self) -> DitResult<RefsSnapshot> {
+        self.reflog_mgr().borrow().snapshot(&self.branch_mgr()?.borrow())
+    }
+
+    /// Records the references which have moved since the snapshot in the reflog.
+    /// The author defaults to the configured user
+    pub(super) fn record_reflog<S: Into<String>>(
+        &self,
+        before: RefsSnapshot,
+        author: Option<String>,
+        message: S,
+    ) -> DitResult<()> {
+        let after = self.refs_snapshot()?;
+        let author = self.resolve_author(author)?;
+        self.reflog_mgr().borrow().record_changes(&before, &after, author, message)
+    }
+
+    /// Returns the given author of an operation, or the configured user
+    pub(super) fn resolve_author(&self, author: Option<String>) -> DitResult<String> {
+        match author {
+            Some(author) => Ok(author),
+            None => Ok(self.config_mgr()?.borrow().get_user().unwrap_or_else(|| UNKNOWN_AUTHOR.to_string())),
+        }
+    }
+
+    /// Describes where HEAD was in a reflog message: the branch name, or the commit when detached
+    pub(super) fn describe_head(snapshot: &RefsSnapshot) -> String {
+        match (&snapshot.branch, &snapshot.head) {
+            (Some(branch), _) => branch.clone(),
+            (None, Some(head)) => head.clone(),
+            (None, None) => String::from("nothing"),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::Dit;
+use crate::api_models::remote::{FetchReport, PushReport, RemoteInfo};
+use crate::helpers::{path_to_string, DEFAULT_BRANCH_NAME, DEFAULT_REMOTE_NAME};
+use crate::errors::{DitResult, RemoteError};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::fs;
+
+/// Manipulate remotes
+impl Dit {
+    /// Adds a remote repository. Local paths are stored as absolute paths
+    pub fn add_remote<S1: AsRef<str>, S2: AsRef<str>>(&mut self, name: S1, url: S2) -> DitResult<()> {
+        let _lock = self.lock()?;
+        self.remote_mgr().borrow().add_remote(
+            name.as_ref(),
+            url.as_ref(),
+            &mut self.config_mgr()?.borrow_mut(),
+        )
+    }
+
+    /// Removes a remote together with its remote-tracking branches
+    pub fn remove_remote<S: AsRef<str>>(&mut self, name: S) -> DitResult<()> {
+        let _lock = self.lock()?;
+        self.remote_mgr().borrow().remove_remote(name.as_ref(), &mut self.config_mgr()?.borrow_mut())
+    }
+
+    /// Copies the missing objects from a remote and updates its remote-tracking branches
+    pub fn fetch<S: AsRef<str>>(&mut self, remote: S) -> DitResult<FetchReport> {
+        let _lock = self.lock()?;
+        self.remote_mgr().borrow().fetch(
+            remote.as_ref(),
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+            &self.branch_mgr()?.borrow(),
+            &self.config_mgr()?.borrow(),
+        )
+    }
+
+    /// Pushes a branch (the current one by default) to a remote. Unless forced,
+    /// only fast-forward updates of the remote branch are allowed
+    pub fn push<S: AsRef<str>>(&mut self, remote: S, branch: Option<String>, force: bool)
+        -> DitResult<PushReport>
+    {
+        let _lock = self.lock()?;
+        let branch = match branch {
+            Some(branch) => branch,
+            None => self.get_branch()?.ok_or(RemoteError::DetachedHead)?,
+        };
+
+        self.remote_mgr().borrow().push(
+            remote.as_ref(),
+            &branch,
+            force,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+            &self.branch_mgr()?.borrow(),
+            &self.config_mgr()?.borrow(),
+        )
+    }
+
+    /// Clones the repository at the url into a new (or empty) directory. The repository
+    /// is added as the `origin` remote and its current branch is checked out
+    pub fn clone_from<S: AsRef<str>, P: AsRef<Path>>(url: S, path: P) -> DitResult<(Self, FetchReport)> {
+        let path = path.as_ref();
+
+        let is_new = !path.exists();
+        if !is_new && fs::read_dir(path)?.next().is_some() {
+            return Err(RemoteError::CloneTargetNotEmpty(path_to_string(path)).into());
+        }
+        fs::create_dir_all(path)?;
+
+        let result = Self::clone_into(url.as_ref(), path);
+        if result.is_err() && is_new {
+            let _ = fs::remove_dir_all(path);
+        }
+        result
+    }
+}
+
+
+/// Serve
+impl Dit {
+    /// Serves the repository to a single `dit://` client until it disconnects
+    pub fn serve_connection<R: Read, W: Write>(&self, reader: R, writer: W) -> DitResult<()> {
+        self.remote_mgr().borrow().serve(reader, writer)
+    }
+}
+
+
+/// Getters
+impl Dit {
+    /// Returns the remotes sorted by name
+    pub fn list_remotes(&self) -> DitResult<Vec<RemoteInfo>> {
+        self.remote_mgr().borrow().list_remotes(&self.config_mgr()?.borrow())
+    }
+}
+
+
+/// Private
+impl Dit {
+    fn clone_into(url: &str, path: &Path) -> DitResult<(Self, FetchReport)> {
+        let mut dit = Self::from(path)?;
+        let lock = dit.lock()?;
+        let before = dit.refs_snapshot()?;
+        dit.add_remote(DEFAULT_REMOTE_NAME, url)?;
+        let report = dit.fetch(DEFAULT_REMOTE_NAME)?;
+
+        let heads = dit.remote_mgr().borrow().list_tracking_heads(DEFAULT_REMOTE_NAME)?;
+        let branch = report.head.clone()
+            .filter(|head| heads.contains_key(head))
+            .or_else(|| heads.contains_key(DEFAULT_BRANCH_NAME).then(|| DEFAULT_BRANCH_NAME.to_string()))
+            .or_else(|| heads.keys().next().cloned())
+            .unwrap_or_else(|| DEFAULT_BRANCH_NAME.to_string());
+
+        dit.create_branch(&branch)?;
+
+        if let Some(commit) = heads.get(&branch) {
+            dit.branch_mgr()?.borrow_mut().set_head_commit(commit)?;
+            let tree = dit.commit_mgr().borrow().get_commit_tree(commit, &dit.tree_mgr().borrow())?;
+            dit.index_mgr()?.borrow_mut().checkout_index(tree, &dit.blob_mgr().borrow())?;
+        }
+
+        dit.record_reflog(before, None, format!("clone: from {url}"))?;
+        drop(lock);
+        Ok((dit, report))
+    }
+}
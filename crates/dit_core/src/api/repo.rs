@@ -1,8 +1,10 @@
 ﻿use crate::errors::{DitResult, ProjectError};
 use crate::helpers::{get_cwd, path_to_string, resolve_absolute_path};
 use super::dit_component_paths::*;
+use super::repo_lock::LockState;
 use std::fs::{self, OpenOptions};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 /// Stores paths of the components of the dit repository
 pub struct Repo {
@@ -14,9 +16,24 @@ pub struct Repo {
     index_file: PathBuf,
     commits_root: PathBuf,
     branches_root: PathBuf,
+    tags_root: PathBuf,
+    tag_objects_root: PathBuf,
+    packs_root: PathBuf,
+    remotes_root: PathBuf,
+    branch_logs_root: PathBuf,
+    ops_root: PathBuf,
     head_file: PathBuf,
     ignore_file: PathBuf,
     config_file: PathBuf,
+    stash_file: PathBuf,
+    head_log_file: PathBuf,
+    lock_file: PathBuf,
+    rebase_file: PathBuf,
+    checkout_file: PathBuf,
+    /// The lock of this repository held by this process, see [`RepoLock`]
+    ///
+    /// [`RepoLock`]: crate::RepoLock
+    pub(super) lock_state: Mutex<LockState>,
 }
 
 /// Constructor
@@ -37,9 +54,17 @@ impl Repo {
         let trees_root = repo_path.join(TREES_ROOT);
         let commits_root = repo_path.join(COMMITS_ROOT);
         let branches_root = repo_path.join(BRANCHES_ROOT);
+        let tags_root = repo_path.join(TAGS_ROOT);
+        let tag_objects_root = repo_path.join(TAG_OBJECTS_ROOT);
+        let packs_root = repo_path.join(PACKS_ROOT);
+        let remotes_root = repo_path.join(REMOTES_ROOT);
+        let branch_logs_root = repo_path.join(BRANCH_LOGS_ROOT);
+        let ops_root = repo_path.join(OPS_ROOT);
 
         let component_dirs = [
-            &dit_root, &blobs_root, &trees_root, &commits_root, &branches_root
+            &dit_root, &blobs_root, &trees_root, &commits_root, &branches_root,
+            &tags_root, &tag_objects_root, &packs_root, &remotes_root, &branch_logs_root,
+            &ops_root,
         ];
 
         /*************************
@@ -48,8 +73,10 @@ impl Repo {
         let index_file = repo_path.join(INDEX_FILE);
         let head_file = repo_path.join(HEAD_FILE);
         let config_file = repo_path.join(CONFIG_FILE);
+        let stash_file = repo_path.join(STASH_FILE);
+        let head_log_file = repo_path.join(HEAD_LOG_FILE);
         let component_files = [
-            &index_file, &head_file, &config_file,
+            &index_file, &head_file, &config_file, &stash_file, &head_log_file,
         ];
 
         for component_dir in component_dirs {
@@ -61,11 +88,16 @@ impl Repo {
         }
 
         let ignore_file = repo_path.join(IGNORE_FILE);
+        let lock_file = repo_path.join(LOCK_FILE);
+        let rebase_file = repo_path.join(REBASE_FILE);
+        let checkout_file = repo_path.join(CHECKOUT_FILE);
         Ok(Self {
             repo_path, dit_root, blobs_root,
             trees_root, index_file, commits_root,
-            branches_root, head_file, ignore_file,
-            config_file,
+            branches_root, tags_root, tag_objects_root, packs_root,
+            remotes_root, branch_logs_root, ops_root, head_file, ignore_file, config_file,
+            stash_file, head_log_file, lock_file, rebase_file, checkout_file,
+            lock_state: Mutex::new(LockState::default()),
         })
     }
 
@@ -125,6 +157,36 @@ impl Repo {
         &self.branches_root
     }
 
+    /// Returns the [`TAGS_ROOT`] path
+    pub fn tags(&self) -> &Path {
+        &self.tags_root
+    }
+
+    /// Returns the [`TAG_OBJECTS_ROOT`] path
+    pub fn tag_objects(&self) -> &Path {
+        &self.tag_objects_root
+    }
+
+    /// Returns the [`PACKS_ROOT`] path
+    pub fn packs(&self) -> &Path {
+        &self.packs_root
+    }
+
+    /// Returns the [`REMOTES_ROOT`] path
+    pub fn remotes(&self) -> &Path {
+        &self.remotes_root
+    }
+
+    /// Returns the [`BRANCH_LOGS_ROOT`] path
+    pub fn branch_logs(&self) -> &Path {
+        &self.branch_logs_root
+    }
+
+    /// Returns the [`OPS_ROOT`] path
+    pub fn ops(&self) -> &Path {
+        &self.ops_root
+    }
+
     /// Returns the [`HEAD_FILE`] path
     pub fn head_file(&self) -> &Path {
         &self.head_file
@@ -140,6 +202,31 @@ impl Repo {
         &self.config_file
     }
 
+    /// Returns the [`STASH_FILE`] path
+    pub fn stash_file(&self) -> &Path {
+        &self.stash_file
+    }
+
+    /// Returns the [`HEAD_LOG_FILE`] path
+    pub fn head_log_file(&self) -> &Path {
+        &self.head_log_file
+    }
+
+    /// Returns the [`LOCK_FILE`] path
+    pub fn lock_file(&self) -> &Path {
+        &self.lock_file
+    }
+
+    /// Returns the [`REBASE_FILE`] path
+    pub fn rebase_file(&self) -> &Path {
+        &self.rebase_file
+    }
+
+    /// Returns the [`CHECKOUT_FILE`] path
+    pub fn checkout_file(&self) -> &Path {
+        &self.checkout_file
+    }
+
     /// Returns the absolute path of a given path.
     /// 1. If the given path is relative, it will be considered relative to project path
     /// 2. If the given file is absolute, nothing will change
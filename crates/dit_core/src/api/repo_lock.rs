@@ -0,0 +1,135 @@
+use crate::errors::{DitResult, FsError, LockError};
+use crate::helpers::{path_to_string, LOCK_RETRY_MILLIS, LOCK_WAIT_MILLIS};
+use crate::Repo;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use std::{env, process};
+
+/// Holds the exclusive lock of a repository until dropped. Only one process at a time
+/// can change the repository
+///
+/// The lock is an advisory lock of the operating system on the [`LOCK_FILE`], which is
+/// released when the file is closed, so a process which no longer runs (e.g. a killed one)
+/// never holds it. The lock file itself is never removed, since another process might be
+/// waiting for the lock on it
+///
+/// The lock is reentrant: while a [`RepoLock`] of a [`Repo`] is alive, locking
+/// the same [`Repo`] again succeeds immediately, and the lock is released
+/// when the last of them is dropped
+///
+/// [`LOCK_FILE`]: crate::dit_component_paths::LOCK_FILE
+pub struct RepoLock {
+    repo: Arc<Repo>,
+    is_outermost: bool,
+}
+
+/// Represents the lock of a repository held by this process
+#[derive(Default)]
+pub(crate) struct LockState {
+    /// The number of the live [`RepoLock`]s of the repository
+    depth: usize,
+
+    /// The locked [`LOCK_FILE`], while the depth is not zero
+    ///
+    /// [`LOCK_FILE`]: crate::dit_component_paths::LOCK_FILE
+    file: Option<File>,
+}
+
+/// Constructor
+impl Repo {
+    /// Locks the repository, waiting for [`LOCK_WAIT_MILLIS`] if another process holds the lock
+    pub fn lock(self: &Arc<Self>) -> DitResult<RepoLock> {
+        let mut state = self.lock_state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let is_outermost = state.depth == 0;
+        if is_outermost {
+            state.file = Some(Self::lock_file_exclusively(self.lock_file())?);
+        }
+        state.depth += 1;
+
+        Ok(RepoLock { repo: self.clone(), is_outermost })
+    }
+}
+
+/// Getters
+impl RepoLock {
+    /// Returns whether the lock was taken by this lock and not by an enclosing one,
+    /// in which case the repository might have been changed before it was locked
+    pub fn is_outermost(&self) -> bool {
+        self.is_outermost
+    }
+}
+
+impl Drop for RepoLock {
+    fn drop(&mut self) {
+        let mut state = self.repo.lock_state.lock().unwrap_or_else(|e| e.into_inner());
+        state.depth -= 1;
+        if state.depth == 0 {
+            // Closing the file releases the lock
+            state.file = None;
+        }
+    }
+}
+
+
+/// Private
+impl Repo {
+    /// Opens the lock file, creating it if needed, and locks it exclusively.
+    /// The pid and the host name of this process are written into it, so that
+    /// the other processes can tell who holds the lock
+    fn lock_file_exclusively(path: &Path) -> DitResult<File> {
+        let deadline = Instant::now() + Duration::from_millis(LOCK_WAIT_MILLIS);
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|_| FsError::FileCreateError(path_to_string(path)))?;
+
+        loop {
+            match file.try_lock() {
+                Ok(()) => break,
+
+                Err(TryLockError::WouldBlock) => {
+                    if Instant::now() >= deadline {
+                        let owner = fs::read_to_string(path).unwrap_or_default();
+                        let mut owner = owner.lines();
+                        return Err(LockError::RepositoryLocked {
+                            pid: owner.next().unwrap_or("unknown").to_string(),
+                            host: owner.next().unwrap_or("unknown").to_string(),
+                        }.into());
+                    }
+
+                    thread::sleep(Duration::from_millis(LOCK_RETRY_MILLIS));
+                }
+
+                Err(TryLockError::Error(_)) => return Err(FsError::FileCreateError(path_to_string(path)).into()),
+            }
+        }
+
+        let host = host_name().unwrap_or_else(|| String::from("unknown"));
+        let owner = format!("{}\n{host}", process::id());
+        if file.set_len(0).and_then(|_| file.write_all(owner.as_bytes())).is_err() {
+            return Err(FsError::FileWriteError(path_to_string(path)).into());
+        }
+
+        Ok(file)
+    }
+}
+
+/// Returns the name of this host, shown to the other processes waiting for the lock
+/// (the repository might be on a share mounted by several hosts)
+fn host_name() -> Option<String> {
+    fs::read_to_string("/proc/sys/kernel/hostname")
+        .ok()
+        .or_else(|| env::var("HOSTNAME").ok())
+        .or_else(|| env::var("COMPUTERNAME").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
@@ -0,0 +1,53 @@
+use crate::Dit;
+use crate::api_models::signing::SignatureStatus;
+use crate::managers::commit::CommitMgr;
+use crate::errors::DitResult;
+use std::path::Path;
+
+/// Signing
+impl Dit {
+    /// Generates a new Ed25519 key pair for signing commits. The private key is written
+    /// to `path` and the public key next to it with the `.pub` extension. Returns the public key
+    pub fn generate_signing_key<P: AsRef<Path>>(path: P) -> DitResult<String> {
+        CommitMgr::generate_signing_key(path.as_ref())
+    }
+
+    /// Verifies the signature of the commit the revision resolves to
+    pub fn verify_commit<S: AsRef<str>>(&self, revision: S) -> DitResult<SignatureStatus> {
+        let hash = self.resolve_revision(revision)?;
+        let commit = self.commit_mgr().borrow().get_commit(hash)?;
+
+        CommitMgr::verify_commit_signature(&commit, &self.config_mgr()?.borrow())
+    }
+}
+
+
+/// Private
+impl Dit {
+    /// Fails if the branch being worked on (the current one or the one being rebased)
+    /// requires signed commits but no signing key is configured
+    pub(super) fn require_signing_key(&self) -> DitResult<()> {
+        let branch = match self.rebase_mgr()?.borrow().state() {
+            Some(state) => Some(state.branch.clone()),
+            None => self.get_branch()?,
+        };
+
+        CommitMgr::require_signing_key(branch.as_deref(), &self.config_mgr()?.borrow())
+    }
+
+    /// Fails if the current branch requires signed commits and moving it from HEAD
+    /// to `new` would bring in commits which aren't correctly signed with a trusted key
+    pub(super) fn verify_incoming_commits(&self, new: &str) -> DitResult<()> {
+        let Some(branch) = self.get_branch()? else {
+            return Ok(());
+        };
+        let head = self.get_head_commit()?;
+
+        self.commit_mgr().borrow().verify_branch_update(
+            &branch,
+            head.as_deref(),
+            new,
+            &self.config_mgr()?.borrow(),
+        )
+    }
+}
@@ -6,28 +6,43 @@ use std::path::Path;
 impl Dit {
     /// Adds files in their current state to the index
     pub fn add_files(&mut self, paths: impl IntoIterator<Item = impl AsRef<Path>>) -> DitResult<()> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+
         self.index_mgr()?.borrow_mut().add_files(
             paths,
             &self.blob_mgr().borrow(),
-        )
+        )?;
+
+        self.finish_operation(start, None, "add", false)
     }
 
     /// Unstages files
     pub fn unstage_files(&mut self, paths: impl IntoIterator<Item = impl AsRef<Path>>) -> DitResult<()> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+
         self.index_mgr()?.borrow_mut().unstage_files(
             paths,
             &self.tree_mgr().borrow(),
             &self.commit_mgr().borrow(),
             &self.branch_mgr()?.borrow(),
-        )
+        )?;
+
+        self.finish_operation(start, None, "unstage", false)
     }
 
     /// Clears the index
     pub fn clear_stage(&mut self) -> DitResult<()> {
+        let _lock = self.lock()?;
+        let start = self.start_operation()?;
+
         self.index_mgr()?.borrow_mut().unstage_all(
             &self.tree_mgr().borrow(),
             &self.commit_mgr().borrow(),
             &self.branch_mgr()?.borrow(),
-        )
+        )?;
+
+        self.finish_operation(start, None, "clear", false)
     }
 }
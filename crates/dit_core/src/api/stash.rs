@@ -0,0 +1,63 @@
+use crate::Dit;
+use crate::api_models::stash::{StashApplyResult, StashEntry};
+use crate::errors::DitResult;
+
+/// Manipulate the stash
+impl Dit {
+    /// Stashes the staged and unstaged changes of the tracked files and reverts
+    /// them to the current commit. Returns the hash of the stash commit
+    pub fn stash_push(&mut self, message: Option<String>) -> DitResult<String> {
+        let _lock = self.lock()?;
+        self.stash_mgr()?.borrow_mut().push_stash(
+            message,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &mut self.commit_mgr().borrow_mut(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &self.branch_mgr()?.borrow(),
+            &self.config_mgr()?.borrow(),
+        )
+    }
+
+    /// Applies the given stash entry (0 is the most recent) and removes it from the stash
+    /// if it was applied without conflicts
+    pub fn stash_pop(&mut self, index: usize) -> DitResult<StashApplyResult> {
+        let _lock = self.lock()?;
+        self.stash_mgr()?.borrow_mut().pop_stash(
+            index,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &self.branch_mgr()?.borrow(),
+        )
+    }
+
+    /// Applies the given stash entry (0 is the most recent) and keeps it in the stash
+    pub fn stash_apply(&mut self, index: usize) -> DitResult<StashApplyResult> {
+        let _lock = self.lock()?;
+        self.stash_mgr()?.borrow().apply_stash(
+            index,
+            &self.blob_mgr().borrow(),
+            &self.tree_mgr().borrow(),
+            &self.commit_mgr().borrow(),
+            &mut self.index_mgr()?.borrow_mut(),
+            &self.branch_mgr()?.borrow(),
+        )
+    }
+
+    /// Removes the given stash entry (0 is the most recent) without applying it
+    pub fn stash_drop(&mut self, index: usize) -> DitResult<String> {
+        let _lock = self.lock()?;
+        self.stash_mgr()?.borrow_mut().drop_stash(index)
+    }
+}
+
+
+/// Getters
+impl Dit {
+    /// Returns the stash entries, the most recent first
+    pub fn stash_list(&self) -> DitResult<Vec<StashEntry>> {
+        self.stash_mgr()?.borrow().list_stash(&self.commit_mgr().borrow())
+    }
+}
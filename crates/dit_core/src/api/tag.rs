@@ -0,0 +1,53 @@
+use crate::Dit;
+use crate::api_models::tag::TagInfo;
+use crate::errors::DitResult;
+
+/// Manipulate tags
+impl Dit {
+    /// Creates a tag pointing to the given revision (the current commit by default). \
+    /// If a message is given, an annotated tag is created with the given tagger
+    /// (the configured user by default)
+    pub fn create_tag<S: AsRef<str>>(
+        &mut self,
+        name: S,
+        revision: Option<String>,
+        message: Option<String>,
+        tagger: Option<String>,
+    ) -> DitResult<()> {
+        let _lock = self.lock()?;
+        let commit = self.resolve_revision(revision.as_deref().unwrap_or("HEAD"))?;
+        let mut tag_mgr = self.tag_mgr().borrow_mut();
+
+        match message {
+            None => tag_mgr.create_tag(name, commit),
+            Some(message) => {
+                let tagger = match tagger {
+                    Some(tagger) => tagger,
+                    None => self.config_mgr()?.borrow().require_user()?,
+                };
+                tag_mgr.create_annotated_tag(name, commit, tagger, message)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Deletes a given tag
+    pub fn delete_tag<S: AsRef<str>>(&mut self, name: S) -> DitResult<()> {
+        let _lock = self.lock()?;
+        self.tag_mgr().borrow_mut().delete_tag(name)
+    }
+}
+
+
+/// Getters
+impl Dit {
+    /// Returns all the tags sorted by name
+    pub fn list_tags(&self) -> DitResult<Vec<TagInfo>> {
+        self.tag_mgr().borrow().list_tags()
+    }
+
+    /// Returns a given tag, or `None` if it doesn't exist
+    pub fn get_tag<S: AsRef<str>>(&self, name: S) -> DitResult<Option<TagInfo>> {
+        self.tag_mgr().borrow().get_tag(name)
+    }
+}
@@ -23,6 +23,10 @@ pub enum BranchError {
     #[error("Cannot merge to branch '{0}' because the head is in a detached head state.")]
     CannotMergeToDetachedHead(String),
 
-    #[error("Merging branches which are not ancestors is not supported yet")]
-    MergeNotSupported, // todo
+    #[error("Cannot merge the branch '{0}' because there are staged changes.
+    Commit the changes or clear the index before merging.")]
+    CannotMergeBranches(String),
+
+    #[error("A merge is already in progress. Resolve the conflicts and commit the merge first.")]
+    MergeInProgress,
 }
@@ -0,0 +1,41 @@
+use crate::helpers::path_to_string;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Errors related to updating the working tree to a different commit
+#[derive(Error, Debug)]
+pub enum CheckoutError {
+    #[error("{}", describe_clobbered(untracked, modified))]
+    WouldClobber {
+        /// Untracked files which would be overwritten by the checked out files
+        untracked: Vec<PathBuf>,
+
+        /// Tracked files whose local modifications would be overwritten or removed
+        modified: Vec<PathBuf>,
+    },
+
+    #[error("The working tree update '{0}' was interrupted. \
+    Run `dit recover --continue` to finish it or `dit recover --rollback` to undo it.")]
+    Interrupted(String),
+
+    #[error("There is no interrupted working tree update to recover")]
+    NothingToRecover,
+}
+
+fn describe_clobbered(untracked: &[PathBuf], modified: &[PathBuf]) -> String {
+    let join = |paths: &[PathBuf]| paths.iter()
+        .map(|p| path_to_string(p))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut message = String::from("The checkout would lose uncommitted work.");
+    if !untracked.is_empty() {
+        message.push_str(&format!(" Untracked files which would be overwritten: {}.", join(untracked)));
+    }
+    if !modified.is_empty() {
+        message.push_str(&format!(" Local changes which would be lost: {}.", join(modified)));
+    }
+    message.push_str(" Commit or stash them, or use --force to overwrite them");
+
+    message
+}
@@ -11,4 +11,42 @@ pub enum CommitError {
 
     #[error("The commit '{0}' is unreachable from the commit '{1}'. Use hard reset instead.")]
     UnreachableCommitError(String, String),
+
+    #[error("Cannot commit because of unmerged files: {0}. Resolve the conflicts and stage the files first.")]
+    UnresolvedConflicts(String),
+
+    #[error("Cannot apply the commit '{0}' because there are local changes. Commit or stash them first.")]
+    LocalChanges(String),
+
+    #[error("Cannot apply the commit '{0}' while a merge is in progress. \
+    Resolve the conflicts and commit first.")]
+    MergeInProgress(String),
+
+    #[error("Applying the commit '{0}' results in no changes")]
+    EmptyChange(String),
+
+    #[error("There is no commit to amend")]
+    NothingToAmend,
+
+    #[error("Cannot amend the commit while a merge is in progress. Commit the merge first.")]
+    AmendDuringMerge,
+
+    #[error("Aborting the commit due to an empty commit message")]
+    EmptyMessage,
+
+    #[error("Invalid commit message: {0}")]
+    InvalidMessage(String),
+
+    #[error("No editor is configured. Set 'core.editor' or the VISUAL or EDITOR environment variable, \
+    or pass the message with -m.")]
+    NoEditor,
+
+    #[error("The editor '{0}' failed. Aborting the commit.")]
+    EditorFailed(String),
+
+    #[error("Invalid author '{0}'. Expected \"Name <email>\" with a valid email address.")]
+    InvalidAuthor(String),
+
+    #[error("Unsupported commit format version {0}")]
+    UnsupportedVersion(u32),
 }
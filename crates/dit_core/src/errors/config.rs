@@ -1,3 +1,4 @@
+use crate::models::{USER_EMAIL_CONFIG, USER_NAME_CONFIG};
 use thiserror::Error;
 
 /// General filesystem related errors
@@ -11,4 +12,10 @@ pub enum ConfigError {
 
     #[error("Configuration required but not found: '{0}'")]
     ConfigNotFound(String),
+
+    #[error("User identity required but not found: set '{}' or '{}'", USER_NAME_CONFIG, USER_EMAIL_CONFIG)]
+    UserIdentityNotFound,
+
+    #[error("Invalid email address: '{0}'")]
+    InvalidEmail(String),
 }
@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Errors related to the garbage collection
+#[derive(Error, Debug)]
+pub enum GcError {
+    #[error("The {0} '{1}' is referenced but missing. Refusing to collect garbage in a corrupted repository.")]
+    MissingObject(String, String),
+}
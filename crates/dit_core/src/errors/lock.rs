@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Errors related to the repository lock
+#[derive(Error, Debug)]
+pub enum LockError {
+    #[error("The repository is locked by another dit process (pid {pid} on '{host}')")]
+    RepositoryLocked { pid: String, host: String },
+}
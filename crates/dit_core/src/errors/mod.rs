@@ -9,6 +9,19 @@ mod project;
 mod fs;
 mod other;
 mod config;
+mod revision;
+mod tag;
+mod stash;
+mod gc;
+mod pack;
+mod remote;
+mod lock;
+mod reflog;
+mod oplog;
+mod rebase;
+mod signing;
+mod checkout;
+mod object;
 
 pub use self::{
     blob::BlobError,
@@ -20,6 +33,19 @@ pub use self::{
     fs::FsError,
     other::OtherError,
     config::ConfigError,
+    revision::RevisionError,
+    tag::TagError,
+    stash::StashError,
+    gc::GcError,
+    pack::PackError,
+    remote::RemoteError,
+    lock::LockError,
+    reflog::ReflogError,
+    oplog::OpLogError,
+    rebase::RebaseError,
+    signing::SigningError,
+    checkout::CheckoutError,
+    object::ObjectError,
 };
 
 #[derive(Error, Debug)]
@@ -51,6 +77,45 @@ pub enum DitCoreError {
     #[error("config error: {0}")]
     ConfigError(#[from] ConfigError),
 
+    #[error("revision error: {0}")]
+    RevisionError(#[from] RevisionError),
+
+    #[error("tag error: {0}")]
+    TagError(#[from] TagError),
+
+    #[error("stash error: {0}")]
+    StashError(#[from] StashError),
+
+    #[error("gc error: {0}")]
+    GcError(#[from] GcError),
+
+    #[error("pack error: {0}")]
+    PackError(#[from] PackError),
+
+    #[error("remote error: {0}")]
+    RemoteError(#[from] RemoteError),
+
+    #[error("lock error: {0}")]
+    LockError(#[from] LockError),
+
+    #[error("reflog error: {0}")]
+    ReflogError(#[from] ReflogError),
+
+    #[error("operation log error: {0}")]
+    OpLogError(#[from] OpLogError),
+
+    #[error("rebase error: {0}")]
+    RebaseError(#[from] RebaseError),
+
+    #[error("signing error: {0}")]
+    SigningError(#[from] SigningError),
+
+    #[error("checkout error: {0}")]
+    CheckoutError(#[from] CheckoutError),
+
+    #[error("object error: {0}")]
+    ObjectError(#[from] ObjectError),
+
     #[error("serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
 
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// Errors related to the encoding of the objects
+#[derive(Error, Debug)]
+pub enum ObjectError {
+    #[error("The {0} is malformed: {1}")]
+    Malformed(String, String),
+
+    #[error("The {0} is not in the canonical encoding")]
+    NonCanonical(String),
+}
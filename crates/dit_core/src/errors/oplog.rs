@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// Errors related to the operation log
+#[derive(Error, Debug)]
+pub enum OpLogError {
+    #[error("There is no operation to undo")]
+    NothingToUndo,
+
+    #[error("Cannot undo '{0}': HEAD or the branches it changed have been moved since by another command")]
+    RefsChanged(String),
+
+    #[error("Cannot undo '{0}' because it would overwrite the local changes. Commit or stash them first")]
+    LocalChanges(String),
+}
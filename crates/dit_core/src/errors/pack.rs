@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Errors related to pack files
+#[derive(Error, Debug)]
+pub enum PackError {
+    #[error("The pack '{0}' is corrupted: {1}")]
+    CorruptPack(String, String),
+}
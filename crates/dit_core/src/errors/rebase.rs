@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// Errors related to rebasing
+#[derive(Error, Debug)]
+pub enum RebaseError {
+    #[error("There is no rebase in progress")]
+    NoRebaseInProgress,
+
+    #[error("A rebase of the branch '{0}' is in progress. Continue, skip or abort it first.")]
+    RebaseInProgress(String),
+
+    #[error("Cannot rebase in the detached head state. Switch to a branch first.")]
+    DetachedHead,
+
+    #[error("Cannot rebase because there are local changes. Commit or stash them first.")]
+    LocalChanges,
+
+    #[error("Cannot rebase while a merge is in progress. Resolve the conflicts and commit the merge first.")]
+    MergeInProgress,
+
+    #[error("Cannot continue the rebase because of unmerged files: {0}. \
+    Resolve the conflicts and stage the files first.")]
+    UnresolvedConflicts(String),
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// Errors related to the reflog
+#[derive(Error, Debug)]
+pub enum ReflogError {
+    #[error("'{0}' is neither HEAD nor a valid branch name")]
+    InvalidRefName(String),
+
+    #[error("The reflog of '{0}' is corrupted at line {1}")]
+    CorruptReflog(String, usize),
+}
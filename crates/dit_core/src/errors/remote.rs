@@ -0,0 +1,58 @@
+use thiserror::Error;
+
+/// Errors related to remotes, fetching and pushing
+#[derive(Error, Debug)]
+pub enum RemoteError {
+    #[error("Remote '{0}' already exists")]
+    RemoteAlreadyExists(String),
+
+    #[error("Remote '{0}' doesn't exist")]
+    RemoteDoesNotExist(String),
+
+    #[error("Invalid remote name: '{0}'")]
+    InvalidRemoteName(String),
+
+    #[error("'{0}' is not a dit repository")]
+    NotARepository(String),
+
+    #[error("Cannot clone into '{0}' because it's not an empty directory")]
+    CloneTargetNotEmpty(String),
+
+    #[error("Cannot push the current branch because the head is in a detached head state")]
+    DetachedHead,
+
+    #[error("Branch '{0}' has no commits to push")]
+    NothingToPush(String),
+
+    #[error("Cannot push to the branch '{0}' because the remote branch contains commits \
+    which are not in the local branch. Fetch and merge the remote branch first or force the push.")]
+    NonFastForward(String),
+
+    #[error("The remote branch '{0}' was updated during the push. Fetch and try again.")]
+    RemoteBranchChanged(String),
+
+    #[error("Cannot push to the branch '{0}' because it's checked out in the remote repository. \
+    Push to a repository created with `dit init --bare` instead.")]
+    BranchCheckedOut(String),
+
+    #[error("The commit '{0}' was not received")]
+    MissingCommit(String),
+
+    #[error("The {0} '{1}' referenced by the pushed commits was not received")]
+    MissingObject(String, String),
+
+    #[error("The received {0} '{1}' doesn't match its hash")]
+    CorruptObject(String, String),
+
+    #[error("Transferring {0} objects is not supported")]
+    UnsupportedObject(String),
+
+    #[error("Invalid remote url: '{0}'")]
+    InvalidUrl(String),
+
+    #[error("Protocol error: {0}")]
+    ProtocolError(String),
+
+    #[error("The server responded with an error: {0}")]
+    ServerError(String),
+}
@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// Errors related to resolving revisions
+#[derive(Error, Debug)]
+pub enum RevisionError {
+    #[error("Invalid revision syntax: '{0}'")]
+    InvalidRevision(String),
+
+    #[error("Unknown revision: '{0}'")]
+    UnknownRevision(String),
+
+    #[error("Ambiguous revision '{0}', it matches multiple commits: {1}")]
+    AmbiguousRevision(String, String),
+
+    #[error("The commit '{0}' doesn't have the parent number {1}")]
+    NoSuchParent(String, usize),
+
+    #[error("The reflog of '{0}' has only {1} entries")]
+    NoSuchReflogEntry(String, usize),
+}
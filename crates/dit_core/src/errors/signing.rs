@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// Errors related to signing and verifying commits
+#[derive(Error, Debug)]
+pub enum SigningError {
+    #[error("The file '{0}' is not a valid signing key")]
+    InvalidKeyFile(String),
+
+    #[error("The key file '{0}' already exists")]
+    KeyFileExists(String),
+
+    #[error("Failed to generate a random key")]
+    KeyGenerationError,
+
+    #[error("The branch '{0}' requires signed commits. Set 'user.signing_key' first.")]
+    SigningKeyRequired(String),
+
+    #[error("The commit '{0}' has no valid signature by a trusted key, which the branch '{1}' requires")]
+    UnverifiedCommit(String, String),
+}
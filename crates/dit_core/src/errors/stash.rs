@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+/// Errors related to the stash
+#[derive(Error, Debug)]
+pub enum StashError {
+    #[error("There are no local changes to stash")]
+    NoLocalChanges,
+
+    #[error("Cannot stash the changes before the initial commit")]
+    NoInitialCommit,
+
+    #[error("The stash entry stash@{{{0}}} doesn't exist")]
+    StashDoesNotExist(usize),
+
+    #[error("Cannot apply the stash because there are local changes. \
+    Commit or stash the changes first.")]
+    LocalChanges,
+
+    #[error("Cannot stash or apply the changes while a merge is in progress. \
+    Resolve the conflicts and commit the merge first.")]
+    MergeInProgress,
+}
@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// Errors related to tags
+#[derive(Error, Debug)]
+pub enum TagError {
+    #[error("Tag '{0}' already exists")]
+    TagAlreadyExists(String),
+
+    #[error("Tag '{0}' doesn't exist")]
+    TagDoesNotExist(String),
+
+    #[error("Invalid tag name: '{0}'")]
+    InvalidTagName(String),
+
+    #[error("Tag '{0}' doesn't point to a commit or a tag object")]
+    InvalidTagRef(String),
+
+    #[error("Unsupported tag object format version {0}")]
+    UnsupportedVersion(u32),
+}
@@ -8,4 +8,13 @@ pub enum TreeError {
 
     #[error("Failed to deserialize the tree with hash '{0}'")]
     DeserializationError(String),
+
+    #[error("Unsupported tree format version {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("The path '{0}' is not valid UTF-8")]
+    NonUtf8Path(String),
+
+    #[error("The tree contains an invalid entry name '{0}'")]
+    InvalidEntryName(String),
 }
@@ -1,4 +1,4 @@
-use crate::helpers::{DitHasher, ZSTD_BUFFER_SIZE, ZSTD_COMPRESSION_LEVEL};
+use crate::helpers::{DitHasher, HashingWriter, ZSTD_BUFFER_SIZE, ZSTD_COMPRESSION_LEVEL};
 use crate::errors::DitResult;
 use std::io::{self, BufReader, BufWriter, Read, Write};
 use zstd::stream::{write::Encoder, read::Decoder};
@@ -54,3 +54,33 @@ pub fn decompress_file(src: &Path, dest: &Path) -> DitResult<()> {
     Ok(())
 }
 
+
+/// Decompresses a file using ZSTD algorithm and returns the content
+pub fn decompress_file_to_bytes(src: &Path) -> DitResult<Vec<u8>> {
+    let reader = BufReader::with_capacity(ZSTD_BUFFER_SIZE, File::open(src)?);
+    let mut decoder = Decoder::new(reader)?;
+    let mut content = Vec::new();
+    decoder.read_to_end(&mut content)?;
+    Ok(content)
+}
+
+/// Decompresses a file using ZSTD algorithm and calculates the hash of the content
+pub fn hash_compressed_file(src: &Path) -> DitResult<String> {
+    let reader = BufReader::with_capacity(ZSTD_BUFFER_SIZE, File::open(src)?);
+    let mut decoder = Decoder::new(reader)?;
+    let mut writer = HashingWriter::new(io::sink());
+    io::copy(&mut decoder, &mut writer)?;
+    Ok(writer.finalize_string())
+}
+
+/// Compresses the given content using the ZSTD algorithm and writes it to a file
+pub fn compress_bytes(content: &[u8], dest: &Path) -> DitResult<()> {
+    let writer = BufWriter::with_capacity(ZSTD_BUFFER_SIZE, File::create(dest)?);
+    let mut encoder = Encoder::new(writer, ZSTD_COMPRESSION_LEVEL as i32)?;
+    encoder.write_all(content)?;
+
+    let mut writer = encoder.finish()?;
+    writer.flush()?;
+
+    Ok(())
+}
@@ -1,3 +1,26 @@
 ﻿pub const HASHING_BUFFER_SIZE: usize = 1024 * 1024;
 pub const ZSTD_BUFFER_SIZE: usize = 1024 * 1024;
 pub const ZSTD_COMPRESSION_LEVEL: u8 = 3;
+pub const DIFF_CONTEXT_LINES: usize = 3;
+pub const DEFAULT_GC_GRACE_PERIOD_SECS: u64 = 60 * 60;
+pub const TEMP_FILE_PREFIX: &str = ".temp_";
+pub const PACK_MAGIC: &[u8] = b"DITPACK1";
+pub const OBJECT_MAGIC: &[u8] = b"DITOBJ";
+pub const PACK_FILE_EXTENSION: &str = "pack";
+pub const PACK_INDEX_EXTENSION: &str = "idx";
+pub const PACK_DICTIONARY_MAX_SIZE: usize = 112 * 1024;
+pub const PACK_DICTIONARY_MAX_SAMPLES: usize = 1024;
+pub const PACK_DICTIONARY_MAX_SAMPLE_SIZE: u64 = 128 * 1024;
+pub const DEFAULT_BRANCH_NAME: &str = "main";
+pub const DEFAULT_REMOTE_NAME: &str = "origin";
+pub const NETWORK_URL_SCHEME: &str = "dit://";
+pub const DEFAULT_SERVE_PORT: u16 = 9419;
+pub const SERVE_MAX_CONNECTIONS: usize = 16;
+pub const SERVE_TIMEOUT_SECS: u64 = 60;
+pub const PROTOCOL_VERSION: u32 = 4;
+pub const PROTOCOL_MAX_FRAME_SIZE: u32 = 4 * 1024 * 1024;
+pub const PROTOCOL_MAX_PAYLOAD_SIZE: usize = 4 * 1024 * 1024;
+pub const PROTOCOL_OBJECT_CHUNK_SIZE: usize = 1024 * 1024;
+pub const OP_LOG_MAX_ENTRIES: usize = 100;
+pub const LOCK_WAIT_MILLIS: u64 = 3000;
+pub const LOCK_RETRY_MILLIS: u64 = 50;
@@ -28,3 +28,16 @@ pub fn create_file_all(path: &Path) -> DitResult<()> {
 
     Ok(())
 }
+
+
+/// Removes the parent directories of a removed file which became empty,
+/// up to (but excluding) the given root directory
+pub fn remove_empty_parents(path: &Path, root: &Path) {
+    let mut parent = path.parent();
+    while let Some(dir) = parent {
+        if dir == root || !dir.starts_with(root) || fs::remove_dir(dir).is_err() {
+            break;
+        }
+        parent = dir.parent();
+    }
+}
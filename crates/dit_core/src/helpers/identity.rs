@@ -0,0 +1,44 @@
+/// Splits an identity of the form `Name <email>` into the name and the email address.
+/// A string without an email address is treated as a name only
+pub fn parse_identity(identity: &str) -> (String, String) {
+    let identity = identity.trim();
+
+    if let Some(rest) = identity.strip_suffix('>')
+        && let Some(start) = rest.rfind('<')
+    {
+        let name = rest[..start].trim().to_string();
+        let email = rest[start + 1..].trim().to_string();
+        return (name, email);
+    }
+
+    (identity.to_string(), String::new())
+}
+
+
+/// Formats a name and an email address as `Name <email>`, leaving out the missing parts
+pub fn format_identity(name: &str, email: &str) -> String {
+    match (name.is_empty(), email.is_empty()) {
+        (_, true) => name.to_string(),
+        (true, false) => format!("<{email}>"),
+        (false, false) => format!("{name} <{email}>"),
+    }
+}
+
+
+/// Checks whether the email address has the form `local@domain.tld`: a single `@`,
+/// a nonempty local part and a domain made of nonempty labels separated by dots,
+/// without whitespace or angle brackets
+pub fn is_valid_email(email: &str) -> bool {
+    if email.chars().any(|c| c.is_whitespace() || c == '<' || c == '>') {
+        return false;
+    }
+
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty())
+}
@@ -16,3 +16,8 @@ pub fn read_to_string(path: &Path) -> DitResult<String> {
 
     Ok(s)
 }
+
+/// Reads a file using [`fs::read`] and maps the error to [`FsError`]
+pub fn read_bytes(path: &Path) -> DitResult<Vec<u8>> {
+    fs::read(path).map_err(|_| FsError::FileReadError(path_to_string(path)).into())
+}
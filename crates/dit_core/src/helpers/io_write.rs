@@ -1 +1,36 @@
-﻿
\ No newline at end of file
+use crate::errors::{DitResult, FsError};
+use crate::helpers::{create_temp_file, path_to_string, TEMP_FILE_PREFIX};
+use std::io::Write;
+use std::path::Path;
+use std::fs;
+
+
+/// Writes the content to a temp file in the destination directory and renames it
+/// over the destination, so that the readers see either the old or the new content
+/// and a crash never leaves a truncated file behind
+pub fn write_atomic<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, content: C) -> DitResult<()> {
+    let path = path.as_ref();
+    let dir = path.parent().unwrap_or(Path::new(""));
+
+    let (mut file, temp_path) = create_temp_file(dir)?;
+    let written = file.write_all(content.as_ref()).and_then(|_| file.sync_all());
+    drop(file);
+
+    if written.is_err() {
+        let _ = fs::remove_file(&temp_path);
+        return Err(FsError::FileWriteError(path_to_string(path)).into());
+    }
+
+    if fs::rename(&temp_path, path).is_err() {
+        let _ = fs::remove_file(&temp_path);
+        return Err(FsError::FileRenameError(path_to_string(&temp_path), path_to_string(path)).into());
+    }
+
+    Ok(())
+}
+
+/// Checks whether the file name is the name of a temp file created by [`create_temp_file`],
+/// e.g. a leftover of an interrupted [`write_atomic`]
+pub fn is_temp_file_name<S: AsRef<str>>(name: S) -> bool {
+    name.as_ref().starts_with(TEMP_FILE_PREFIX)
+}
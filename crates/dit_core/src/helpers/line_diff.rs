@@ -0,0 +1,201 @@
+use std::ops::Range;
+
+/// Represents a single step of a line diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    /// The line `old` of the old sequence is equal to the line `new` of the new sequence
+    Equal { old: usize, new: usize },
+
+    /// The line `old` of the old sequence was deleted
+    Delete { old: usize },
+
+    /// The line `new` of the new sequence was inserted
+    Insert { new: usize },
+}
+
+
+/// Splits the content into lines, keeping the line terminators.
+/// The last line may not end with a newline
+pub fn split_lines(content: &[u8]) -> Vec<&[u8]> {
+    content.split_inclusive(|b| *b == b'\n').collect()
+}
+
+
+/// Checks whether the content looks like binary data (contains a NUL byte
+/// near the beginning), in which case it should not be diffed line by line
+pub fn is_binary(content: &[u8]) -> bool {
+    content.iter().take(8000).any(|b| *b == 0)
+}
+
+
+/// Computes the shortest edit script between two sequences using the linear space variant
+/// of the Myers algorithm: the sequences are split where an optimal edit path crosses their
+/// middle (the middle snake) and both halves are diffed recursively. The memory used is
+/// proportional to the length of the sequences, not to the square of the edit distance
+pub fn diff_lines<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    let mut ops = Vec::with_capacity(old.len().max(new.len()));
+    diff_ranges(old, new, 0..old.len(), 0..new.len(), &mut ops);
+    ops
+}
+
+/// Appends the edit script between `old[olds]` and `new[news]` to the operations
+fn diff_ranges<T: PartialEq>(
+    old: &[T],
+    new: &[T],
+    mut olds: Range<usize>,
+    mut news: Range<usize>,
+    ops: &mut Vec<DiffOp>,
+) {
+    while !olds.is_empty() && !news.is_empty() && old[olds.start] == new[news.start] {
+        ops.push(DiffOp::Equal { old: olds.start, new: news.start });
+        olds.start += 1;
+        news.start += 1;
+    }
+
+    let mut suffix = 0;
+    while !olds.is_empty() && !news.is_empty() && old[olds.end - 1] == new[news.end - 1] {
+        olds.end -= 1;
+        news.end -= 1;
+        suffix += 1;
+    }
+
+    if olds.is_empty() {
+        ops.extend(news.clone().map(|new| DiffOp::Insert { new }));
+    } else if news.is_empty() {
+        ops.extend(olds.clone().map(|old| DiffOp::Delete { old }));
+    } else {
+        match middle_snake(&old[olds.clone()], &new[news.clone()]) {
+            Some((x, y)) => {
+                diff_ranges(old, new, olds.start..olds.start + x, news.start..news.start + y, ops);
+                diff_ranges(old, new, olds.start + x..olds.end, news.start + y..news.end, ops);
+            }
+            None => {
+                ops.extend(olds.clone().map(|old| DiffOp::Delete { old }));
+                ops.extend(news.clone().map(|new| DiffOp::Insert { new }));
+            }
+        }
+    }
+
+    for i in 0..suffix {
+        ops.push(DiffOp::Equal { old: olds.end + i, new: news.end + i });
+    }
+}
+
+/// Finds a point where an optimal edit path between the sequences crosses their middle,
+/// by searching forward from the start and backward from the end until the searches meet.
+/// Returns `None` if the sequences have nothing in common
+fn middle_snake<T: PartialEq>(old: &[T], new: &[T]) -> Option<(usize, usize)> {
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+    let max_d = (n + m + 1) / 2;
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+
+    // `forward[k + max_d]` holds the furthest x reached from the start on the diagonal
+    // k = x - y, `backward[k + max_d]` the furthest x reached from the end (both -1 if none)
+    let len = 2 * max_d + 2;
+    let mut forward = vec![-1isize; len as usize];
+    let mut backward = vec![-1isize; len as usize];
+    forward[max_d as usize + 1] = 0;
+    backward[max_d as usize + 1] = 0;
+
+    // The diagonals which left the edit graph are skipped
+    let (mut forward_start, mut forward_end) = (0, 0);
+    let (mut backward_start, mut backward_end) = (0, 0);
+
+    for d in 0..max_d {
+        let mut k = -d + forward_start;
+        while k <= d - forward_end {
+            let idx = (k + max_d) as usize;
+            let mut x = if k == -d || (k != d && forward[idx - 1] < forward[idx + 1]) {
+                forward[idx + 1]
+            } else {
+                forward[idx - 1] + 1
+            };
+            let mut y = x - k;
+
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[idx] = x;
+
+            if x > n {
+                forward_end += 2;
+            } else if y > m {
+                forward_start += 2;
+            } else if odd {
+                let back_idx = max_d + delta - k;
+                if (0..len).contains(&back_idx)
+                    && backward[back_idx as usize] != -1
+                    && x >= n - backward[back_idx as usize]
+                {
+                    return Some((x as usize, y as usize));
+                }
+            }
+            k += 2;
+        }
+
+        let mut k = -d + backward_start;
+        while k <= d - backward_end {
+            let idx = (k + max_d) as usize;
+            let mut x = if k == -d || (k != d && backward[idx - 1] < backward[idx + 1]) {
+                backward[idx + 1]
+            } else {
+                backward[idx - 1] + 1
+            };
+            let mut y = x - k;
+
+            while x < n && y < m && old[(n - x - 1) as usize] == new[(m - y - 1) as usize] {
+                x += 1;
+                y += 1;
+            }
+            backward[idx] = x;
+
+            if x > n {
+                backward_end += 2;
+            } else if y > m {
+                backward_start += 2;
+            } else if !odd {
+                let fwd_idx = max_d + delta - k;
+                if (0..len).contains(&fwd_idx) && forward[fwd_idx as usize] != -1 {
+                    let fwd_x = forward[fwd_idx as usize];
+                    let fwd_y = max_d + fwd_x - fwd_idx;
+                    if fwd_x >= n - x {
+                        return Some((fwd_x as usize, fwd_y as usize));
+                    }
+                }
+            }
+            k += 2;
+        }
+    }
+
+    None
+}
+
+
+/// Groups the changes of an edit script into hunks, each surrounded by up to `context`
+/// unchanged lines. Changes separated by no more than `2 * context` unchanged lines
+/// are placed in the same hunk.
+///
+/// Returns the ranges of the hunks in the edit script
+pub fn group_hunks(ops: &[DiffOp], context: usize) -> Vec<Range<usize>> {
+    let changes: Vec<usize> = ops.iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, DiffOp::Equal { .. }))
+        .map(|(idx, _)| idx)
+        .collect();
+
+    let mut hunks: Vec<Range<usize>> = Vec::new();
+    for idx in changes {
+        let start = idx.saturating_sub(context);
+        let end = (idx + context + 1).min(ops.len());
+
+        match hunks.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => hunks.push(start..end),
+        }
+    }
+
+    hunks
+}
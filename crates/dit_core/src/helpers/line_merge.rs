@@ -0,0 +1,121 @@
+use crate::helpers::{diff_lines, split_lines, DiffOp};
+
+/// Represents the result of a line-based three-way merge
+#[derive(Debug, Clone, Default)]
+pub struct LineMerge {
+    /// Represents the merged content (with conflict markers for the conflicting hunks)
+    pub content: Vec<u8>,
+
+    /// Represents the number of conflicting hunks
+    pub conflicts: usize,
+}
+
+impl LineMerge {
+    /// Checks whether the merge was resolved without any conflicts
+    pub fn is_clean(&self) -> bool {
+        self.conflicts == 0
+    }
+}
+
+
+/// Performs a line-based three-way merge (diff3) of two versions of a file given their
+/// common base version. The conflicting hunks are surrounded with
+/// `<<<<<<<`/`=======`/`>>>>>>>` markers labeled with the given labels
+pub fn merge_lines(
+    base: &[u8],
+    ours: &[u8],
+    theirs: &[u8],
+    our_label: &str,
+    their_label: &str,
+) -> LineMerge {
+    let base = split_lines(base);
+    let ours = split_lines(ours);
+    let theirs = split_lines(theirs);
+
+    let ours_match = match_base_lines(&base, &ours);
+    let theirs_match = match_base_lines(&base, &theirs);
+
+    let mut merge = LineMerge::default();
+    let (mut i, mut j, mut k) = (0, 0, 0);
+
+    loop {
+        // Copy the lines which are unchanged on both sides
+        let mut stable = 0;
+        while i + stable < base.len()
+            && ours_match[i + stable] == Some(j + stable)
+            && theirs_match[i + stable] == Some(k + stable)
+        {
+            stable += 1;
+        }
+        if stable > 0 {
+            push_lines(&mut merge.content, &base[i..i + stable]);
+            i += stable;
+            j += stable;
+            k += stable;
+            continue;
+        }
+
+        // Find the next base line which is kept by both sides
+        let (next_i, next_j, next_k) = (i..base.len())
+            .find_map(|x| Some((x, ours_match[x]?, theirs_match[x]?)))
+            .unwrap_or((base.len(), ours.len(), theirs.len()));
+
+        if (next_i, next_j, next_k) == (i, j, k) {
+            break;
+        }
+
+        let base_hunk = &base[i..next_i];
+        let our_hunk = &ours[j..next_j];
+        let their_hunk = &theirs[k..next_k];
+
+        if our_hunk == base_hunk {
+            push_lines(&mut merge.content, their_hunk);
+        } else if their_hunk == base_hunk || our_hunk == their_hunk {
+            push_lines(&mut merge.content, our_hunk);
+        } else {
+            merge.conflicts += 1;
+            push_marker(&mut merge.content, "<<<<<<<", our_label);
+            push_lines(&mut merge.content, our_hunk);
+            push_marker(&mut merge.content, "=======", "");
+            push_lines(&mut merge.content, their_hunk);
+            push_marker(&mut merge.content, ">>>>>>>", their_label);
+        }
+
+        (i, j, k) = (next_i, next_j, next_k);
+    }
+
+    merge
+}
+
+
+/// For each base line, returns the index of the matching line in the other version
+/// (or `None` if the line was removed or changed)
+fn match_base_lines(base: &[&[u8]], other: &[&[u8]]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; base.len()];
+    for op in diff_lines(base, other) {
+        if let DiffOp::Equal { old, new } = op {
+            matches[old] = Some(new);
+        }
+    }
+    matches
+}
+
+/// Appends the lines to the content
+fn push_lines(content: &mut Vec<u8>, lines: &[&[u8]]) {
+    for line in lines {
+        content.extend_from_slice(line);
+    }
+}
+
+/// Appends a conflict marker on its own line
+fn push_marker(content: &mut Vec<u8>, marker: &str, label: &str) {
+    if content.last().is_some_and(|b| *b != b'\n') {
+        content.push(b'\n');
+    }
+    content.extend_from_slice(marker.as_bytes());
+    if !label.is_empty() {
+        content.push(b' ');
+        content.extend_from_slice(label.as_bytes());
+    }
+    content.push(b'\n');
+}
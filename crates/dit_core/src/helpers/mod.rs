@@ -8,6 +8,10 @@ pub mod hashing;
 pub mod compression;
 pub mod glob;
 pub mod serialization;
+pub mod line_diff;
+pub mod line_merge;
+pub mod identity;
+pub mod object_encoding;
 
 pub use io_read::*;
 pub use io_write::*;
@@ -19,3 +23,7 @@ pub use hashing::*;
 pub use compression::*;
 pub use glob::*;
 pub use serialization::*;
+pub use line_diff::*;
+pub use line_merge::*;
+pub use identity::*;
+pub use object_encoding::*;
@@ -0,0 +1,174 @@
+//! Canonical encoding of the objects
+//!
+//! Trees and commits are stored in a binary encoding which has exactly one form for every
+//! object, and the id of an object is the hash of its stored bytes. So the id can always be
+//! recalculated from the stored object, e.g. by `dit fsck` or when the object is received
+//! from another repository.
+//!
+//! An encoded object starts with [`OBJECT_MAGIC`], a byte telling the kind of the object and
+//! the version of its format (`u32`). Its fields follow:
+//! * integers are little-endian and have a fixed width
+//! * strings are UTF-8 and are prefixed with their length in bytes (`u64`)
+//! * lists are prefixed with the number of their items (`u64`)
+//! * optional values are prefixed with `0` (absent) or `1` (present)
+//!
+//! Blobs are stored as their raw content, so that the id of a file in the working tree is
+//! the hash of the file itself. The kind of an object is known from every reference to it,
+//! so a blob is never taken for another kind of object.
+//!
+//! The objects stored before the encoding was introduced are JSON documents, which never
+//! start with [`OBJECT_MAGIC`]. They keep their original ids.
+
+use crate::api_models::object::ObjectKind;
+use crate::helpers::OBJECT_MAGIC;
+use crate::errors::{DitCoreError, DitResult, ObjectError};
+
+/// Encodes the fields of an object
+pub struct ObjectEncoder {
+    bytes: Vec<u8>,
+}
+
+impl ObjectEncoder {
+    /// Starts encoding an object of the given kind in the given version of its format
+    pub fn new(kind: ObjectKind, version: u32) -> Self {
+        let mut encoder = Self { bytes: OBJECT_MAGIC.to_vec() };
+        encoder.u8(kind_tag(kind));
+        encoder.u32(version);
+        encoder
+    }
+
+    pub fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    pub fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn i32(&mut self, value: i32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn str(&mut self, value: &str) {
+        self.u64(value.len() as u64);
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+
+    /// Returns the encoded object
+    pub fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+
+/// Decodes the fields of an object, failing if the object is truncated
+pub struct ObjectDecoder<'a> {
+    kind: ObjectKind,
+    bytes: &'a [u8],
+}
+
+impl<'a> ObjectDecoder<'a> {
+    /// Starts decoding an object of the given kind. Returns the decoder
+    /// and the version of the format of the object
+    pub fn new(kind: ObjectKind, bytes: &'a [u8]) -> DitResult<(Self, u32)> {
+        let mut decoder = Self { kind, bytes };
+
+        if decoder.take(OBJECT_MAGIC.len())? != OBJECT_MAGIC {
+            return Err(decoder.malformed("the encoding is unknown"));
+        }
+        if decoder.u8()? != kind_tag(kind) {
+            return Err(decoder.malformed(format!("it's not a {kind}")));
+        }
+        let version = decoder.u32()?;
+
+        Ok((decoder, version))
+    }
+
+    pub fn u8(&mut self) -> DitResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn u32(&mut self) -> DitResult<u32> {
+        Ok(u32::from_le_bytes(self.take_array()?))
+    }
+
+    pub fn u64(&mut self) -> DitResult<u64> {
+        Ok(u64::from_le_bytes(self.take_array()?))
+    }
+
+    pub fn i32(&mut self) -> DitResult<i32> {
+        Ok(i32::from_le_bytes(self.take_array()?))
+    }
+
+    pub fn str(&mut self) -> DitResult<String> {
+        let len = self.u64()?;
+        let bytes = match usize::try_from(len) {
+            Ok(len) => self.take(len)?,
+            Err(_) => return Err(self.malformed("it's truncated")),
+        };
+
+        String::from_utf8(bytes.to_vec()).map_err(|_| self.malformed("a string is not valid UTF-8"))
+    }
+
+    /// Decodes the presence flag of an optional value
+    pub fn flag(&mut self) -> DitResult<bool> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            flag => Err(self.malformed(format!("unknown flag {flag}"))),
+        }
+    }
+
+    /// Finishes decoding, failing if there are bytes left after the object
+    pub fn finish(self) -> DitResult<()> {
+        match self.bytes.is_empty() {
+            true => Ok(()),
+            false => Err(self.malformed("there is data after its end")),
+        }
+    }
+
+    /// Returns the error telling that the object is malformed for the given reason
+    pub fn malformed<S: Into<String>>(&self, reason: S) -> DitCoreError {
+        ObjectError::Malformed(self.kind.to_string(), reason.into()).into()
+    }
+}
+
+
+/// Private
+impl<'a> ObjectDecoder<'a> {
+    fn take(&mut self, len: usize) -> DitResult<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(self.malformed("it's truncated"));
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> DitResult<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+}
+
+
+/// Checks whether the stored object is in the canonical encoding (and not a JSON document)
+pub fn is_encoded_object(bytes: &[u8]) -> bool {
+    bytes.starts_with(OBJECT_MAGIC)
+}
+
+/// Returns the byte identifying the kind of an encoded object
+fn kind_tag(kind: ObjectKind) -> u8 {
+    match kind {
+        ObjectKind::Blob => 1,
+        ObjectKind::Tree => 2,
+        ObjectKind::Commit => 3,
+        ObjectKind::TagObject => 4,
+    }
+}
@@ -1,8 +1,7 @@
-use crate::helpers::read_to_string;
+use crate::helpers::{read_to_string, write_atomic};
 use crate::errors::DitResult;
 use serde::{Serialize, de::DeserializeOwned};
 use std::path::Path;
-use std::fs;
 
 /// Implements methods for serializing structures to files and
 /// deserializing from files
@@ -14,7 +13,7 @@ pub trait DitModel: Sized {
 impl<T> DitModel for T where T: Serialize + DeserializeOwned  {
     fn serialize_to(&self, path: &Path) -> DitResult<()> {
         let serialized = serde_json::to_string_pretty(self)?;
-        fs::write(path, serialized)?;
+        write_atomic(path, serialized)?;
         Ok(())
     }
 
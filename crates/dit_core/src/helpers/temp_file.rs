@@ -1,5 +1,5 @@
 use crate::errors::{DitResult, FsError, OtherError};
-use crate::helpers::path_to_string;
+use crate::helpers::{path_to_string, TEMP_FILE_PREFIX};
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::fs::{File, OpenOptions};
 use std::path::{Path, PathBuf};
@@ -20,7 +20,7 @@ pub fn create_temp_file<P: AsRef<Path>>(dest_dir: P)
             .map_err(|_| OtherError::TimeWentBackwardsError)?
             .as_nanos();
 
-        let filename = format!("temp_{pid}_{nanos}_{attempts}");
+        let filename = format!("{TEMP_FILE_PREFIX}{pid}_{nanos}_{attempts}");
         let path = dest_dir.join(filename);
 
         let file = OpenOptions::new()
@@ -5,6 +5,6 @@ mod models;
 mod api;
 
 use managers::*;
-pub use api::{Dit, Repo};
+pub use api::{Dit, Repo, RepoLock};
 pub use api::api_models;
 pub use api::dit_component_paths;
@@ -1,10 +1,12 @@
 use crate::managers::blob::BlobMgr;
 use crate::helpers::{
-    compress_file, compress_file_hashed,
-    create_temp_file, decompress_file,
-    remove_file_if_exists,
+    compress_bytes, compress_file, compress_file_hashed,
+    create_temp_file, decompress_file, decompress_file_to_bytes,
+    remove_file_if_exists, DitHasher,
 };
 use crate::errors::DitResult;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::fs;
 
@@ -25,17 +27,54 @@ impl BlobMgr {
         compress_file(source, &dest)
     }
 
+    /// Creates a blob from the given content. Returns its hash
+    pub fn create_blob_from_bytes(&self, content: &[u8]) -> DitResult<String> {
+        let mut hasher = DitHasher::new();
+        hasher.update(content);
+        let hash = hasher.finalize_string();
+
+        let (_, temp_file_path) = create_temp_file(self.repo.blobs())?;
+        compress_bytes(content, &temp_file_path)?;
+        fs::rename(&temp_file_path, self.get_blob_path(hash.clone()))?;
+        Ok(hash)
+    }
+
+    /// Reads and returns the (decompressed) content of a blob
+    pub fn read_blob(&self, hash: String) -> DitResult<Vec<u8>> {
+        let path = self.get_blob_path(hash.clone());
+        if !path.is_file()
+            && let Some(content) = self.packs.read_object(&hash)?
+        {
+            return Ok(content);
+        }
+
+        decompress_file_to_bytes(&path)
+    }
+
     /// Recovers the blob to the target file
     pub fn recover_blob(&self, hash: String, target: &Path) -> DitResult<()> {
-        decompress_file(&self.get_blob_path(hash), target)
+        let path = self.get_blob_path(hash.clone());
+        if !path.is_file() && self.packs.contains(&hash)? {
+            let mut writer = BufWriter::new(File::create(target)?);
+            self.packs.read_object_to(&hash, &mut writer)?;
+            writer.flush()?;
+            return Ok(());
+        }
+
+        decompress_file(&path, target)
+    }
+
+    /// Checks whether the blob exists (either loose or packed)
+    pub fn has_blob(&self, hash: &str) -> DitResult<bool> {
+        Ok(self.get_blob_path(hash.to_string()).is_file() || self.packs.contains(hash)?)
     }
 
-    /// Removes a blob
+    /// Removes a loose blob
     pub fn remove_blob(&self, hash: String) -> DitResult<()> {
         remove_file_if_exists(&self.get_blob_path(hash))
     }
 
-    /// Returns the path of a blob given its hash
+    /// Returns the path of a loose blob given its hash
     pub fn get_blob_path(&self, hash: String) -> PathBuf {
         self.repo.blobs().join(hash)
     }
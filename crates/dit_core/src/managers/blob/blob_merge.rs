@@ -0,0 +1,34 @@
+use crate::managers::blob::BlobMgr;
+use crate::models::ConflictEntry;
+use crate::helpers::{is_binary, merge_lines, LineMerge};
+use crate::errors::DitResult;
+
+impl BlobMgr {
+    /// Performs a line-based three-way merge of the versions of a conflicting file.
+    ///
+    /// Returns `None` if the file can't be merged line by line (it's missing on
+    /// one of the sides or the content is binary)
+    pub fn merge_blobs(
+        &self,
+        conflict: &ConflictEntry,
+        our_label: &str,
+        their_label: &str,
+    ) -> DitResult<Option<LineMerge>> {
+        let (Some(ours), Some(theirs)) = (&conflict.ours, &conflict.theirs) else {
+            return Ok(None);
+        };
+
+        let base = match &conflict.base {
+            Some(base) => self.read_blob(base.clone())?,
+            None => Vec::new(),
+        };
+        let ours = self.read_blob(ours.clone())?;
+        let theirs = self.read_blob(theirs.clone())?;
+
+        if is_binary(&base) || is_binary(&ours) || is_binary(&theirs) {
+            return Ok(None);
+        }
+
+        Ok(Some(merge_lines(&base, &ours, &theirs, our_label, their_label)))
+    }
+}
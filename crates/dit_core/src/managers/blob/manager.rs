@@ -26,6 +26,7 @@
 //! or other files with identical content. This way, we avoid unnecessary copying.
 
 use crate::Repo;
+use crate::managers::pack::PackStore;
 use std::sync::Arc;
 
 /// Manages the blobs in our Dit version control system \
@@ -33,11 +34,14 @@ use std::sync::Arc;
 pub struct BlobMgr {
     /// Represents the blobs directory, [`BLOBS_ROOT`]
     pub(super) repo: Arc<Repo>,
+
+    /// Represents the packed blobs, which are read if there is no loose blob
+    pub(super) packs: PackStore,
 }
 
 /// Constructors
 impl BlobMgr {
     pub fn from(project: Arc<Repo>) -> Self {
-        Self { repo: project }
+        Self { packs: PackStore::from(project.clone()), repo: project }
     }
 }
@@ -1,4 +1,5 @@
 mod manager;
 mod blob_manage;
+mod blob_merge;
 
 pub use manager::BlobMgr;
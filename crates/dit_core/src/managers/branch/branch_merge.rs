@@ -1,57 +1,142 @@
+use crate::managers::blob::BlobMgr;
 use crate::managers::branch::BranchMgr;
 use crate::managers::commit::CommitMgr;
+use crate::managers::config::ConfigMgr;
+use crate::managers::index::IndexMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::checkout::CheckoutMode;
+use crate::api_models::merge::MergeResult;
+use crate::models::PendingMerge;
 use crate::errors::{BranchError, DitResult};
 
 /// Public
 impl BranchMgr {
-    /// Merges two branches
-    pub fn merge_to<S: AsRef<str>>(
+    /// Merges the given commit into the current branch. The name is used to refer to
+    /// the merged commit in the merge message and the conflict markers.
+    ///
+    /// The untracked files and the local modifications which the merge would overwrite
+    /// are handled according to the `mode`, before anything is written
+    #[allow(clippy::too_many_arguments)]
+    pub fn merge_into_current<S: AsRef<str>>(
         &mut self,
-        merge_to: S,
-        commit_mgr: &CommitMgr,
-    ) -> DitResult<()> {
-        let merge_to = merge_to.as_ref();
-        let merge_from = self.curr_branch.as_ref().cloned()
-            .ok_or_else(|| BranchError::CannotMergeToDetachedHead(merge_to.to_string()))?;
-        self.merge_branches(merge_from, merge_to, commit_mgr)
+        merge_from: S,
+        their_head: String,
+        mode: CheckoutMode,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &mut CommitMgr,
+        index_mgr: &mut IndexMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<MergeResult> {
+        let merge_from = merge_from.as_ref();
+        let merge_to = self.curr_branch.as_ref().cloned()
+            .ok_or_else(|| BranchError::CannotMergeToDetachedHead(merge_from.to_string()))?;
+
+        if index_mgr.pending_merge().is_some() {
+            return Err(BranchError::MergeInProgress.into());
+        }
+
+        if index_mgr.are_tracked_changes(tree_mgr, commit_mgr, self)? {
+            return Err(BranchError::CannotMergeBranches(merge_from.to_string()).into());
+        }
+
+        self.merge_branches(
+            merge_from, merge_to, their_head, mode,
+            blob_mgr, tree_mgr, commit_mgr, index_mgr, config_mgr
+        )
     }
 }
 
 
 /// Private
 impl BranchMgr {
-    /// Tries to merge to branches
+    /// Tries to merge `from` (whose head is `their_head`) into the current branch `to`
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn merge_branches<S1, S2>(
         &mut self,
         from: S1,
         to: S2,
-        commit_mgr: &CommitMgr,
-    ) -> DitResult<()>
+        their_head: String,
+        mode: CheckoutMode,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &mut CommitMgr,
+        index_mgr: &mut IndexMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<MergeResult>
     where S1: Into<String>, S2: Into<String> {
         let from = from.into();
         let to = to.into();
 
+        let our_head = self.get_head_commit().cloned();
+
         // A -> B -> C -> D -> E -> F
-        //      ^ BRANCH1           ^ BRANCH2
+        //      ^ FROM              ^ TO
 
-        // Case 1: Merge BRANCH1 into BRANCH2
-        // do nothing, BRANCH2 is already up to date
-        if commit_mgr.is_ancestor(&from, &to)? {
-            Ok(())
+        // Case 1: FROM is already included in TO, do nothing
+        if let Some(our_head) = &our_head
+            && commit_mgr.is_ancestor(&their_head, our_head)?
+        {
+            return Ok(MergeResult::UpToDate);
         }
-        // Case 2: Merge BRANCH2 into BRANCH1
-        // In this case, simply move the BRANCH1 pointer to point to BRANCH2 head
+
+        // Case 2: TO is an ancestor of FROM (or has no commits at all)
+        // In this case, simply move the TO pointer to point to FROM head
         // A -> B -> C -> D -> E -> F
-        //                          ^ BRANCH1, BRANCH2
-        else if commit_mgr.is_ancestor(&to, &from)? {
-            let merge_from_commit = self.get_branch_head(&from)?
-                .unwrap_or_else(String::new);
-            self.set_branch_head(&to, merge_from_commit)?;
-            Ok(())
-        }
+        //                          ^ FROM, TO
+        let our_head = match our_head {
+            Some(our_head) if !commit_mgr.is_ancestor(&our_head, &their_head)? => our_head,
+            _ => {
+                let their_tree = commit_mgr.get_commit_tree(&their_head, tree_mgr)?;
+                let head = self.get_head_tree(tree_mgr, commit_mgr)?;
+                index_mgr.plan_checkout(head, their_tree.clone(), false, mode, blob_mgr)?;
+
+                index_mgr.checkout_index(their_tree, blob_mgr)?;
+                self.set_head_commit(&their_head)?;
+                return Ok(MergeResult::FastForward(their_head));
+            }
+        };
 
-        else {
-            Err(BranchError::MergeNotSupported.into())  // todo
+        // Case 3: the histories diverged
+        //           D -> E
+        //         /      ^ FROM
+        // A -> B -> C
+        //           ^ TO
+        // Merge the trees relative to the merge base (B) and create a merge commit
+        let base_tree = match commit_mgr.find_merge_base(&our_head, &their_head)? {
+            Some(base) => Some(commit_mgr.get_commit(base)?.tree),
+            None => None,
+        };
+        let our_tree = commit_mgr.get_commit(&our_head)?.tree;
+        let their_tree = commit_mgr.get_commit(&their_head)?.tree;
+
+        let merge = tree_mgr.merge_trees(base_tree, Some(our_tree), Some(their_tree))?;
+
+        // Nothing is written if the merged or the unmerged files would overwrite untracked
+        // files or local modifications
+        let head = self.get_head_tree(tree_mgr, commit_mgr)?;
+        let unmerged = index_mgr.apply_tree_merge(merge, head, mode, &to, &from, blob_mgr)?;
+
+        // The merge is concluded by a commit once the conflicts are resolved
+        if !unmerged.is_empty() {
+            index_mgr.start_merge(PendingMerge { head: their_head, branch: from })?;
+            return Ok(MergeResult::Conflicted(unmerged));
         }
+
+        let author = CommitMgr::user_signature_now(config_mgr)?;
+        let tree_hash = tree_mgr.create_tree(index_mgr.index().clone())?;
+
+        let commit_hash = commit_mgr.create_commit_from_tree(
+            format!("Merge branch '{from}' into '{to}'"),
+            author.clone(),
+            author,
+            tree_hash,
+            vec![our_head, their_head],
+            config_mgr,
+        )?;
+
+        self.set_head_commit(&commit_hash)?;
+
+        Ok(MergeResult::Merged(commit_hash))
     }
-}
\ No newline at end of file
+}
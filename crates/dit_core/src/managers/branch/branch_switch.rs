@@ -3,12 +3,11 @@ use crate::managers::tree::TreeMgr;
 use crate::managers::commit::CommitMgr;
 use crate::managers::index::IndexMgr;
 use crate::managers::branch::BranchMgr;
-use crate::managers::ignore::IgnoreMgr;
+use crate::api_models::checkout::CheckoutMode;
 use crate::errors::{BranchError, DitResult};
-use crate::helpers::{create_file_all, read_to_string};
-use std::fs;
-use std::collections::BTreeMap;
-use rayon::prelude::*;
+use crate::managers::checkout::CheckoutMgr;
+use crate::models::{CheckoutJournal, CheckoutStep, Index};
+use crate::helpers::{read_to_string, write_atomic};
 
 /// Public
 impl BranchMgr {
@@ -20,7 +19,7 @@ impl BranchMgr {
         -> DitResult<()> {
         let name = name.as_ref();
 
-        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        if !Self::is_valid_branch_name(name) {
             return Err(BranchError::InvalidBranchName(name.to_string()).into())
         }
 
@@ -31,11 +30,11 @@ impl BranchMgr {
 
         match &self.curr_commit {
             None => {
-                fs::write(&path, "")?;
+                write_atomic(&path, "")?;
             }
 
             Some(curr_commit) => {
-                fs::write(&path, curr_commit)?;
+                write_atomic(&path, curr_commit)?;
             }
         }
 
@@ -43,16 +42,22 @@ impl BranchMgr {
     }
 
 
-    /// Switches to a different branch
+    /// Switches to a different branch. Only the files which differ between the current
+    /// and the target commit are written or removed, the untracked files are left alone.
+    /// The untracked files and local modifications in the way are handled according to the `mode`.
+    /// The update is journaled under the given description, see [`CheckoutMgr`]
+    #[allow(clippy::too_many_arguments)]
     pub fn switch_branch<S: AsRef<str>>(
         &mut self,
         name: S,
         is_hard: bool,
+        mode: CheckoutMode,
+        description: String,
         blob_mgr: &BlobMgr,
         tree_mgr: &TreeMgr,
         commit_mgr: &CommitMgr,
         index_mgr: &mut IndexMgr,
-        ignore_mgr: &IgnoreMgr,
+        checkout_mgr: &mut CheckoutMgr,
     ) -> DitResult<()> {
         let name = name.as_ref();
         let (exists, path) = self.find_branch(name);
@@ -69,27 +74,31 @@ impl BranchMgr {
 
         // Get the commit tree
         let target_commit_hash = read_to_string(&path)?;
-        let files = if target_commit_hash.is_empty() {
-            BTreeMap::new()
+        let (target_commit, tree, index) = if target_commit_hash.is_empty() {
+            (None, None, Index::default())
         } else {
-            let target_commit = commit_mgr.get_commit(&target_commit_hash)?;
-            tree_mgr.get_tree(target_commit.tree)?.index.files
+            let tree = commit_mgr.get_commit(&target_commit_hash)?.tree;
+            let index = tree_mgr.get_tree_index(&tree)?;
+            (Some(target_commit_hash), Some(tree), index)
         };
 
-        // Remove the current project root
-        ignore_mgr.clear_dir(self.repo.repo_path())?;
-
-        // Create the files in the commit
-        files.into_par_iter()
-            .try_for_each(|(rel_path, entry)| -> DitResult<()> {
-                create_file_all(&rel_path)?;
-                let src = blob_mgr.get_blob_path(entry.hash);
-                fs::copy(&src, &rel_path)?;
-                Ok(())
-            })?;
-
-        // Set heads to the branch
-        self.set_head(name, target_commit_hash)
+        // Update the files which differ from the current commit, then set heads to the branch
+        let head = self.get_head_tree(tree_mgr, commit_mgr)?;
+        let (files, index) = index_mgr.plan_checkout(head, index, is_hard, mode, blob_mgr)?;
+
+        let journal = CheckoutJournal {
+            description,
+            step: CheckoutStep::Files,
+            tree,
+            orig_branch: self.curr_branch.clone(),
+            orig_head: self.curr_commit.clone(),
+            branch: Some(name.to_string()),
+            head: target_commit,
+            orig_index: index_mgr.index().clone(),
+            index,
+            files,
+        };
+        checkout_mgr.checkout(journal, blob_mgr, index_mgr, self)
     }
 }
 
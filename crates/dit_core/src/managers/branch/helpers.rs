@@ -1,9 +1,10 @@
-use crate::errors::DitResult;
-use crate::helpers::{read_to_string};
+use crate::errors::{BranchError, DitResult};
+use crate::helpers::{is_temp_file_name, read_to_string, remove_file_if_exists, write_atomic};
 use crate::managers::branch::BranchMgr;
 use crate::managers::commit::CommitMgr;
 use crate::managers::tree::TreeMgr;
-use crate::models::Tree;
+use crate::models::Index;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::fs;
 
@@ -46,16 +47,16 @@ impl BranchMgr {
         let head_file = self.repo.head_file();
 
         if let Some(curr_branch) = &self.curr_branch {
-            fs::write(head_file, curr_branch)?;
+            write_atomic(head_file, curr_branch)?;
             let branch_file = self.repo.branches().join(curr_branch);
             match &self.curr_commit {
-                Some(curr_commit) => fs::write(&branch_file, curr_commit)?,
-                None => fs::write(&branch_file, "")?,
+                Some(curr_commit) => write_atomic(&branch_file, curr_commit)?,
+                None => write_atomic(&branch_file, "")?,
             }
         } else {
             match &self.curr_commit {
-                Some(head) => fs::write(head_file, format!(":{head}"))?,
-                None => fs::write(head_file, "")?,
+                Some(head) => write_atomic(head_file, format!(":{head}"))?,
+                None => write_atomic(head_file, "")?,
             }
         }
 
@@ -66,18 +67,6 @@ impl BranchMgr {
 
 /// Branch head operations
 impl BranchMgr {
-    /// Sets the current head branch and head commit to new values
-    pub(super) fn set_head<S1, S2>(&mut self, branch: S1, commit: S2)
-        -> DitResult<()>
-    where
-        S1: AsRef<str>,
-        S2: AsRef<str>,
-    {
-        self.set_current_branch(branch.as_ref())?;
-        self.set_head_commit(commit.as_ref())?;
-        self.store()
-    }
-    
     /// Sets the current (head) branch to a new value
     pub fn set_current_branch<S: Into<String>>(&mut self, branch: S) -> DitResult<()> {
         self.curr_branch = Some(branch.into());
@@ -102,13 +91,55 @@ impl BranchMgr {
         let branch_file = self.repo.branches().join(branch);
 
         if branch_file.is_file() {
-            fs::write(&branch_file, commit)?;
+            write_atomic(&branch_file, commit)?;
         }
         Ok(())
     }
 
+    /// Points a branch to a given commit, creating the branch if it doesn't exist.
+    /// The current branch is not changed
+    pub fn update_branch_head(&mut self, branch: &str, commit: &str) -> DitResult<()> {
+        if !Self::is_valid_branch_name(branch) {
+            return Err(BranchError::InvalidBranchName(branch.to_string()).into());
+        }
+
+        if self.curr_branch.as_deref() == Some(branch) {
+            self.curr_commit = Some(commit.to_string());
+        }
+
+        let (_, path) = self.find_branch(branch);
+        write_atomic(&path, commit)?;
+        Ok(())
+    }
+
+    /// Restores HEAD and the given branches, e.g. to undo an operation. The branches are
+    /// mapped to the content of their files (the head commit, or an empty string for a branch
+    /// without commits), or to `None` to remove them
+    pub fn restore_refs(
+        &mut self,
+        branch: Option<&str>,
+        commit: Option<&str>,
+        branches: &BTreeMap<String, Option<String>>,
+    ) -> DitResult<()> {
+        for (name, content) in branches {
+            if !Self::is_valid_branch_name(name) {
+                return Err(BranchError::InvalidBranchName(name.clone()).into());
+            }
+
+            let (_, path) = self.find_branch(name);
+            match content {
+                Some(content) => write_atomic(&path, content)?,
+                None => remove_file_if_exists(&path)?,
+            }
+        }
+
+        self.curr_branch = branch.map(str::to_string);
+        self.curr_commit = commit.map(str::to_string);
+        self.store()
+    }
+
     /// Returns the head commit of a given branch
-    pub(super) fn get_branch_head<S: AsRef<str>>(&self, name: S) -> DitResult<Option<String>> {
+    pub fn get_branch_head<S: AsRef<str>>(&self, name: S) -> DitResult<Option<String>> {
         let (exists, path) = self.find_branch(name);
 
         if exists {
@@ -133,11 +164,19 @@ impl BranchMgr {
         self.store()
     }
 
+    /// Detaches HEAD from the current branch and points it to a given commit.
+    /// The branch itself is not changed
+    pub fn detach_head<S: Into<String>>(&mut self, commit: S) -> DitResult<()> {
+        self.curr_branch = None;
+        self.curr_commit = Some(commit.into());
+        self.store()
+    }
+
     /// Returns the hash of the current commit
     pub fn get_head_commit(&self) -> Option<&String> { self.curr_commit.as_ref() }
 
-    /// Return the tree of the current commit
-    pub fn get_head_tree(&self, tree_mgr: &TreeMgr, commit_mgr: &CommitMgr) -> DitResult<Option<Tree>> {
+    /// Returns the files of the tree of the current commit
+    pub fn get_head_tree(&self, tree_mgr: &TreeMgr, commit_mgr: &CommitMgr) -> DitResult<Option<Index>> {
         let head_commit = self.get_head_commit();
 
         match head_commit {
@@ -153,6 +192,27 @@ impl BranchMgr {
 
 /// Branch getters
 impl BranchMgr {
+    /// Checks whether the name can be used as a branch name
+    pub fn is_valid_branch_name<S: AsRef<str>>(name: S) -> bool {
+        let name = name.as_ref();
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    /// Returns the names of all the branches sorted by name
+    pub fn list_branches(&self) -> DitResult<Vec<String>> {
+        let mut branches = Vec::new();
+        for entry in fs::read_dir(self.repo.branches())? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type()?.is_file() && !is_temp_file_name(&name) {
+                branches.push(name);
+            }
+        }
+        branches.sort();
+
+        Ok(branches)
+    }
+
     /// Returns a bool indicating whether the branch exists or not and
     /// the path to that branch file
     pub(super) fn find_branch<S: AsRef<str>>(&self, name: S) -> (bool, PathBuf) {
@@ -0,0 +1,145 @@
+use crate::managers::checkout::CheckoutMgr;
+use crate::managers::blob::BlobMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::index::IndexMgr;
+use crate::models::{CheckoutFile, CheckoutJournal, CheckoutStep, FileFingerprint};
+use crate::helpers::{create_file_all, remove_empty_parents, remove_file_if_exists};
+use crate::errors::{CheckoutError, DitResult};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use rayon::prelude::*;
+
+/// Public
+impl CheckoutMgr {
+    /// Records the journal of a working tree update and applies it: writes and removes
+    /// the files, then updates the index and HEAD. If the update is interrupted,
+    /// the journal is left behind to continue or roll it back
+    pub fn checkout(
+        &mut self,
+        journal: CheckoutJournal,
+        blob_mgr: &BlobMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+    ) -> DitResult<()> {
+        if let Some(interrupted) = &self.journal {
+            return Err(CheckoutError::Interrupted(interrupted.description.clone()).into());
+        }
+
+        self.journal = Some(journal);
+        self.store()?;
+
+        self.continue_checkout(blob_mgr, index_mgr, branch_mgr)?;
+        Ok(())
+    }
+
+    /// Continues the interrupted working tree update from the step it has reached.
+    /// The files are written again, so a partially written one is fixed too.
+    /// Returns the journal of the update
+    pub fn continue_checkout(
+        &mut self,
+        blob_mgr: &BlobMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+    ) -> DitResult<CheckoutJournal> {
+        let mut journal = self.require_journal()?.clone();
+
+        if journal.step == CheckoutStep::Files {
+            let fingerprints = self.write_files(&journal.files, blob_mgr)?;
+            for (rel_path, fp) in fingerprints {
+                if let Some(entry) = journal.index.files.get_mut(&rel_path) {
+                    entry.fp = fp;
+                }
+            }
+
+            journal.step = CheckoutStep::Refs;
+            self.journal = Some(journal.clone());
+            self.store()?;
+        }
+
+        index_mgr.set_index(journal.index.clone())?;
+        branch_mgr.restore_refs(journal.branch.as_deref(), journal.head.as_deref(), &BTreeMap::new())?;
+
+        self.journal = None;
+        self.store()?;
+
+        Ok(journal)
+    }
+
+    /// Rolls back the interrupted working tree update: restores the files it changed,
+    /// the index and HEAD as they were before it. Returns the journal of the update
+    pub fn rollback_checkout(
+        &mut self,
+        blob_mgr: &BlobMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+    ) -> DitResult<CheckoutJournal> {
+        let journal = self.require_journal()?.clone();
+
+        let files = journal.files
+            .iter()
+            .map(|file| CheckoutFile {
+                path: file.path.clone(),
+                before: file.after.clone(),
+                after: file.before.clone(),
+                local: None,
+            })
+            .collect::<Vec<_>>();
+        let fingerprints = self.write_files(&files, blob_mgr)?;
+
+        // The restored files which match the index don't need to be hashed again
+        let mut index = journal.orig_index.clone();
+        for file in &files {
+            if let Some(entry) = index.files.get_mut(&file.path)
+                && file.after.as_ref() == Some(&entry.hash)
+                && let Some(fp) = fingerprints.get(&file.path)
+            {
+                entry.fp = fp.clone();
+            }
+        }
+
+        index_mgr.set_index(index)?;
+        branch_mgr.restore_refs(journal.orig_branch.as_deref(), journal.orig_head.as_deref(), &BTreeMap::new())?;
+
+        self.journal = None;
+        self.store()?;
+
+        Ok(journal)
+    }
+}
+
+
+/// Private
+impl CheckoutMgr {
+    /// Removes the files without a version after the update (and the directories left empty),
+    /// then writes the others. Returns the fingerprints of the written files, taken before
+    /// the merged local modifications are written over them
+    fn write_files(
+        &self,
+        files: &[CheckoutFile],
+        blob_mgr: &BlobMgr,
+    ) -> DitResult<BTreeMap<PathBuf, FileFingerprint>> {
+        let repo_path = self.repo.repo_path();
+        for file in files.iter().filter(|file| file.after.is_none()) {
+            let abs_path = self.repo.abs_path_from_repo(&file.path, true)?;
+            remove_file_if_exists(&abs_path)?;
+            remove_empty_parents(&abs_path, repo_path);
+        }
+
+        files
+            .par_iter()
+            .filter_map(|file| file.after.as_ref().map(|after| (file, after)))
+            .map(|(file, after)| -> DitResult<(PathBuf, FileFingerprint)> {
+                let abs_path = self.repo.abs_path_from_repo(&file.path, true)?;
+                create_file_all(&abs_path)?;
+                blob_mgr.recover_blob(after.clone(), &abs_path)?;
+                let fp = FileFingerprint::from(&abs_path)?;
+
+                if let Some(local) = &file.local {
+                    blob_mgr.recover_blob(local.clone(), &abs_path)?;
+                }
+
+                Ok((file.path.clone(), fp))
+            })
+            .collect()
+    }
+}
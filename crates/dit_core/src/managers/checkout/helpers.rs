@@ -0,0 +1,40 @@
+use crate::managers::checkout::CheckoutMgr;
+use crate::models::CheckoutJournal;
+use crate::helpers::{remove_file_if_exists, DitModel};
+use crate::errors::{CheckoutError, DitResult};
+
+/// Manage the checkout file
+impl CheckoutMgr {
+    /// Loads the interrupted working tree update (if any) from the checkout file
+    pub(super) fn load(&mut self) -> DitResult<()> {
+        let path = self.repo.checkout_file();
+        self.journal = match path.is_file() {
+            true => Some(CheckoutJournal::deserialize_from(path)?),
+            false => None,
+        };
+        Ok(())
+    }
+
+    /// Updates the checkout file based on the current journal. The file is removed
+    /// when there is no working tree update in progress
+    pub(super) fn store(&self) -> DitResult<()> {
+        match &self.journal {
+            Some(journal) => journal.serialize_to(self.repo.checkout_file()),
+            None => remove_file_if_exists(self.repo.checkout_file()),
+        }
+    }
+}
+
+
+/// Getters
+impl CheckoutMgr {
+    /// Returns the journal of the interrupted working tree update
+    pub fn journal(&self) -> Option<&CheckoutJournal> {
+        self.journal.as_ref()
+    }
+
+    /// Returns the journal of the interrupted working tree update, or an error if there is none
+    pub fn require_journal(&self) -> DitResult<&CheckoutJournal> {
+        self.journal.as_ref().ok_or_else(|| CheckoutError::NothingToRecover.into())
+    }
+}
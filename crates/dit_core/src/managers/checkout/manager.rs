@@ -0,0 +1,32 @@
+//! This module manages the working tree updates in the Dit version control system
+//!
+//! A branch switch or a hard reset writes and removes many files, then updates the index
+//! and HEAD. Before any file is changed, the planned update is recorded in `.dit/checkout`
+//! (the target tree, the files to change with their previous versions, the index and HEAD
+//! before and after it, and the step it has reached). If the update is interrupted (e.g. the
+//! process is killed or the disk is full), the journal stays behind and the repository
+//! can't be changed until the update is continued or rolled back.
+
+use crate::Repo;
+use crate::errors::DitResult;
+use crate::models::CheckoutJournal;
+use std::sync::Arc;
+
+/// Manages the working tree updates. See [`crate::checkout`] for more info
+pub struct CheckoutMgr {
+    pub(super) repo: Arc<Repo>,
+
+    pub(super) journal: Option<CheckoutJournal>,
+}
+
+/// Constructors
+impl CheckoutMgr {
+    pub fn from(repo: Arc<Repo>) -> DitResult<Self> {
+        let mut mgr = Self {
+            repo,
+            journal: None,
+        };
+        Self::load(&mut mgr)?;
+        Ok(mgr)
+    }
+}
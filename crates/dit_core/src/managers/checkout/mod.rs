@@ -0,0 +1,5 @@
+mod manager;
+mod helpers;
+mod checkout_apply;
+
+pub use manager::CheckoutMgr;
@@ -3,65 +3,160 @@ use crate::managers::branch::BranchMgr;
 use crate::managers::commit::CommitMgr;
 use crate::managers::index::IndexMgr;
 use crate::managers::tree::TreeMgr;
-use crate::models::Commit;
-use crate::helpers::DitHasher;
-use crate::errors::{DitResult, OtherError};
-use std::time::SystemTime;
+use crate::models::{Commit, Signature, COMMIT_FORMAT_VERSION};
+use crate::helpers::{path_to_string, DitHasher};
+use crate::errors::{CommitError, DitResult};
 
 /// Public
 impl CommitMgr {
-    /// Commits the changes given the commit author and the message
+    /// Commits the changes given the commit author and the message.
+    /// The message is checked against the configured rules
     pub fn create_commit<S1: Into<String>, S2: Into<String>>(
         &mut self,
         message: S1,
         author: Option<S2>,
         tree_mgr: &TreeMgr,
-        index_mgr: &IndexMgr,
+        index_mgr: &mut IndexMgr,
         branch_mgr: &mut BranchMgr,
         config_mgr: &ConfigMgr,
     ) -> DitResult<()> {
-        let parent = branch_mgr.get_head_commit().cloned();
+        let message = message.into();
+        Self::validate_message(&message, config_mgr)?;
+
+        if index_mgr.has_conflicts() {
+            let conflicts = index_mgr.index().conflicts.keys()
+                .map(|p| path_to_string(p))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(CommitError::UnresolvedConflicts(conflicts).into());
+        }
+
+        // A merge which was stopped by conflicts is concluded by this commit
+        let mut parents: Vec<String> = branch_mgr.get_head_commit().cloned().into_iter().collect();
+        if let Some(merge) = index_mgr.pending_merge() {
+            parents.push(merge.head.clone());
+        }
         let author = match author {
-            Some(author) => author.into(),
-            None => config_mgr.require_user()?,
+            Some(author) => Self::signature_now(&author.into())?,
+            None => Self::user_signature_now(config_mgr)?,
         };
-        let message = message.into();
+        let committer = Self::committer_now(&author, config_mgr)?;
         let index = index_mgr.index().clone();
 
         let tree_hash = tree_mgr.create_tree(index)?;
 
-        let timestamp = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .map_err(|_| OtherError::TimeWentBackwardsError)?
-            .as_secs();
+        let commit_hash = self.create_commit_from_tree(message, author, committer, tree_hash, parents, config_mgr)?;
 
-        let mut hasher = DitHasher::new();
-        hasher.update(author.as_bytes());
-        hasher.update(message.as_bytes());
-        hasher.update(&timestamp.to_le_bytes());
-        hasher.update(tree_hash.as_bytes());
-        hasher.update(&parent.clone()
-            .map(|s| s.into_bytes())
-            .unwrap_or_else(|| vec![0]), );
-        let commit_hash = hasher.finalize_string();
-
-        let parents = if let Some(parent) = parent {
-            vec![parent]
-        } else {
-            vec![]
+        if index_mgr.pending_merge().is_some() {
+            index_mgr.finish_merge()?;
+        }
+
+        branch_mgr.set_head_commit(commit_hash)
+    }
+
+    /// Replaces the HEAD commit with a new commit which has the same parents and the
+    /// current index as its tree. The message and the author of the HEAD commit are kept
+    /// unless new ones are given. A new message is checked against the configured rules
+    pub fn amend_commit<S1: Into<String>, S2: Into<String>>(
+        &mut self,
+        message: Option<S1>,
+        author: Option<S2>,
+        tree_mgr: &TreeMgr,
+        index_mgr: &IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<()> {
+        let message = message.map(Into::into);
+        if let Some(message) = &message {
+            Self::validate_message(message, config_mgr)?;
+        }
+
+        if index_mgr.has_conflicts() {
+            let conflicts = index_mgr.index().conflicts.keys()
+                .map(|p| path_to_string(p))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(CommitError::UnresolvedConflicts(conflicts).into());
+        }
+
+        if index_mgr.pending_merge().is_some() {
+            return Err(CommitError::AmendDuringMerge.into());
+        }
+
+        let head = branch_mgr.get_head_commit().ok_or(CommitError::NothingToAmend)?;
+        let head = self.get_commit(head)?;
+
+        let message = message.unwrap_or(head.message);
+        let author = match author {
+            Some(author) => Self::signature_now(&author.into())?,
+            None => head.author,
         };
+        let committer = Self::committer_now(&author, config_mgr)?;
+        let tree_hash = tree_mgr.create_tree(index_mgr.index().clone())?;
+
+        let commit_hash = self.create_commit_from_tree(message, author, committer, tree_hash, head.parents, config_mgr)?;
+
+        branch_mgr.set_head_commit(commit_hash)
+    }
 
-        let commit = Commit {
+    /// Creates a commit pointing to an already existing tree with the given parents
+    /// and returns its hash. The commit is signed if a signing key is configured.
+    /// The head is not moved
+    pub fn create_commit_from_tree<S: Into<String>>(
+        &mut self,
+        message: S,
+        author: Signature,
+        committer: Signature,
+        tree_hash: String,
+        parents: Vec<String>,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<String> {
+        let mut commit = Commit {
+            version: COMMIT_FORMAT_VERSION,
             author,
-            message,
-            timestamp,
+            committer,
+            message: message.into(),
             tree: tree_hash,
             parents,
-            hash: commit_hash.clone(),
+            signature: None,
+            legacy_author: None,
+            hash: String::new(),
         };
+        if let Some(key) = Self::configured_signing_key(config_mgr)? {
+            Self::sign_commit(&mut commit, &key);
+        }
+        commit.hash = Self::hash_commit(&commit);
 
         self.write_commit(&commit)?;
 
-        branch_mgr.set_head_commit(commit_hash)
+        Ok(commit.hash)
+    }
+
+    /// Calculates the hash of a commit given its content: the hash of its canonical encoding.
+    /// A legacy commit is hashed the way it was when it was created
+    pub fn hash_commit(commit: &Commit) -> String {
+        let mut hasher = DitHasher::new();
+
+        let Some(author) = &commit.legacy_author else {
+            hasher.update(&Self::encode_commit(commit));
+            return hasher.finalize_string();
+        };
+
+        hasher.update(author.as_bytes());
+        hasher.update(commit.message.as_bytes());
+        hasher.update(&commit.author.timestamp.to_le_bytes());
+        hasher.update(commit.tree.as_bytes());
+        if commit.parents.is_empty() {
+            hasher.update(&[0]);
+        }
+        for parent in &commit.parents {
+            hasher.update(parent.as_bytes());
+        }
+        hasher.finalize_string()
+    }
+
+    /// Calculates the hash the commit has without its signature, which is what the signature signs
+    pub fn signed_hash(commit: &Commit) -> String {
+        Self::hash_commit(&Commit { signature: None, ..commit.clone() })
     }
 }
@@ -0,0 +1,108 @@
+use crate::managers::commit::CommitMgr;
+use crate::api_models::object::ObjectKind;
+use crate::models::{Commit, CommitSignature, Signature, COMMIT_FORMAT_VERSION};
+use crate::helpers::{is_encoded_object, ObjectDecoder, ObjectEncoder};
+use crate::errors::{CommitError, DitResult, ObjectError};
+
+/// Public
+impl CommitMgr {
+    /// Encodes a commit in the canonical encoding: the author and the committer, the message,
+    /// the tree, the parents and the signature (if the commit is signed)
+    pub fn encode_commit(commit: &Commit) -> Vec<u8> {
+        let mut encoder = ObjectEncoder::new(ObjectKind::Commit, commit.version);
+
+        for signature in [&commit.author, &commit.committer] {
+            encoder.str(&signature.name);
+            encoder.str(&signature.email);
+            encoder.u64(signature.timestamp);
+            encoder.i32(signature.offset);
+        }
+        encoder.str(&commit.message);
+        encoder.str(&commit.tree);
+
+        encoder.u64(commit.parents.len() as u64);
+        for parent in &commit.parents {
+            encoder.str(parent);
+        }
+
+        match &commit.signature {
+            Some(signed) => {
+                encoder.u8(1);
+                encoder.str(&signed.public_key);
+                encoder.str(&signed.signature);
+            }
+            None => encoder.u8(0),
+        }
+
+        encoder.finish()
+    }
+
+    /// Decodes a stored commit, either in the canonical encoding or as a legacy
+    /// JSON document. A commit which isn't in the canonical form is rejected
+    pub fn decode_commit(bytes: &[u8]) -> DitResult<Commit> {
+        if !is_encoded_object(bytes) {
+            return Ok(serde_json::from_slice(bytes)?);
+        }
+
+        let (mut decoder, version) = ObjectDecoder::new(ObjectKind::Commit, bytes)?;
+        if version != COMMIT_FORMAT_VERSION {
+            return Err(CommitError::UnsupportedVersion(version).into());
+        }
+
+        let author = Self::decode_signature(&mut decoder)?;
+        let committer = Self::decode_signature(&mut decoder)?;
+        let message = decoder.str()?;
+        let tree = decoder.str()?;
+
+        let mut parents = Vec::new();
+        for _ in 0..decoder.u64()? {
+            parents.push(decoder.str()?);
+        }
+
+        let signature = match decoder.flag()? {
+            true => Some(CommitSignature { public_key: decoder.str()?, signature: decoder.str()? }),
+            false => None,
+        };
+        decoder.finish()?;
+
+        let commit = Commit {
+            version,
+            author,
+            committer,
+            message,
+            tree,
+            parents,
+            signature,
+            legacy_author: None,
+            hash: String::new(),
+        };
+        if Self::encode_commit(&commit) != bytes {
+            return Err(ObjectError::NonCanonical(ObjectKind::Commit.to_string()).into());
+        }
+
+        Ok(commit)
+    }
+}
+
+
+/// Private
+impl CommitMgr {
+    /// Decodes the author or the committer of a commit
+    fn decode_signature(decoder: &mut ObjectDecoder) -> DitResult<Signature> {
+        Ok(Signature {
+            name: decoder.str()?,
+            email: decoder.str()?,
+            timestamp: decoder.u64()?,
+            offset: decoder.i32()?,
+        })
+    }
+
+    /// Returns the bytes a commit is stored as. A legacy commit
+    /// is stored as JSON, like it was when it was created
+    pub(super) fn stored_bytes(commit: &Commit) -> DitResult<Vec<u8>> {
+        match commit.legacy_author {
+            Some(_) => Ok(serde_json::to_vec_pretty(commit)?),
+            None => Ok(Self::encode_commit(commit)),
+        }
+    }
+}
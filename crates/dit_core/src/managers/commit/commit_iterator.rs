@@ -1,4 +1,5 @@
 use crate::managers::commit::CommitMgr;
+use crate::errors::DitResult;
 use std::collections::{HashSet, VecDeque};
 
 /// Iterates through ancestors of a commit using breadth-first search
@@ -13,18 +14,33 @@ impl<'a> CommitBfsIterator<'a> {
         start_commit: S,
         commit_mgr: &'a CommitMgr
     ) -> Self
+    {
+        Self::from_many([start_commit], commit_mgr)
+    }
+
+    /// Iterates through the ancestors of several commits at once. Every commit
+    /// is yielded only once, even if it's reachable from multiple start commits
+    pub fn from_many<I>(
+        start_commits: I,
+        commit_mgr: &'a CommitMgr
+    ) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
     {
         Self {
             visited: HashSet::new(),
-            queue: VecDeque::from([start_commit.into()]),
+            queue: start_commits.into_iter().map(Into::into).collect(),
             commit_mgr,
         }
     }
 }
 
 impl<'a> Iterator for CommitBfsIterator<'a> {
-    type Item = String;
+    type Item = DitResult<String>;
 
+    /// Yields the next commit, or the error if a commit can't be read.
+    /// Nothing is yielded after an error
     fn next(&mut self) -> Option<Self::Item> {
         while let Some(commit_hash) = self.queue.pop_front() {
             if self.visited.contains(&commit_hash) {
@@ -32,11 +48,13 @@ impl<'a> Iterator for CommitBfsIterator<'a> {
             }
             self.visited.insert(commit_hash.clone());
 
-            let commit = self.commit_mgr.get_commit(&commit_hash);
-            if commit.is_err() {
-                return None;
-            }
-            let commit = commit.unwrap();
+            let commit = match self.commit_mgr.get_commit(&commit_hash) {
+                Ok(commit) => commit,
+                Err(e) => {
+                    self.queue.clear();
+                    return Some(Err(e));
+                }
+            };
 
             for parent in &commit.parents {
                 if !self.visited.contains(parent) {
@@ -44,7 +62,7 @@ impl<'a> Iterator for CommitBfsIterator<'a> {
                 }
             }
 
-            return Some(commit_hash)
+            return Some(Ok(commit_hash))
         }
 
         None
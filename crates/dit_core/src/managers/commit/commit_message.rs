@@ -0,0 +1,93 @@
+use crate::managers::commit::CommitMgr;
+use crate::managers::config::ConfigMgr;
+use crate::api_models::status::{ChangeType, Status};
+use crate::helpers::path_to_string;
+use crate::errors::{CommitError, DitResult};
+
+/// Represents the prefix of the lines of an edited commit message which are ignored
+const COMMENT_PREFIX: char = '#';
+
+/// Public
+impl CommitMgr {
+    /// Checks the commit message against the configured rules (the maximum subject length
+    /// and the blank second line). An empty message is always rejected
+    pub fn validate_message(message: &str, config_mgr: &ConfigMgr) -> DitResult<()> {
+        let mut lines = message.lines();
+        let subject = lines.next().unwrap_or_default();
+        if subject.trim().is_empty() {
+            return Err(CommitError::EmptyMessage.into());
+        }
+
+        if let Some(max_length) = config_mgr.get_max_subject_length()
+            && subject.chars().count() > max_length
+        {
+            return Err(CommitError::InvalidMessage(
+                format!("the subject line is longer than {max_length} characters")
+            ).into());
+        }
+
+        if config_mgr.get_blank_second_line().unwrap_or(false)
+            && lines.next().is_some_and(|line| !line.trim().is_empty())
+        {
+            return Err(CommitError::InvalidMessage(
+                "the second line must be blank".to_string()
+            ).into());
+        }
+
+        Ok(())
+    }
+
+    /// Builds the initial content of the file in which the commit message is edited:
+    /// an empty line followed by comments describing the changes to be committed
+    pub fn message_template(branch: Option<&str>, status: &Status) -> String {
+        let mut lines = vec![
+            "Please enter the commit message for your changes. Lines starting".to_string(),
+            format!("with '{COMMENT_PREFIX}' will be ignored, and an empty message aborts the commit."),
+            String::new(),
+        ];
+
+        match branch {
+            Some(branch) => lines.push(format!("On branch {branch}")),
+            None => lines.push("HEAD detached".to_string()),
+        }
+
+        if status.has_any_tracked() {
+            lines.push("Changes to be committed:".to_string());
+            let changes = [
+                (ChangeType::New, "new file"),
+                (ChangeType::Modified, "modified"),
+                (ChangeType::Deleted, "deleted"),
+            ];
+            for (change_type, label) in changes {
+                for path in status.get_tracked(change_type) {
+                    lines.push(format!("\t{label}: {}", path_to_string(path)));
+                }
+            }
+        } else {
+            lines.push("No changes to be committed".to_string());
+        }
+
+        // The message is written on the first line, above the comments
+        let mut template = String::from("\n");
+        for line in lines {
+            template.push(COMMENT_PREFIX);
+            if !line.is_empty() {
+                template.push(' ');
+                template.push_str(&line);
+            }
+            template.push('\n');
+        }
+
+        template
+    }
+
+    /// Removes the comment lines and the surrounding blank lines from an edited commit message
+    pub fn strip_message(raw: &str) -> String {
+        let lines = raw.lines()
+            .filter(|line| !line.starts_with(COMMENT_PREFIX))
+            .map(str::trim_end)
+            .collect::<Vec<_>>();
+
+        lines.join("\n").trim_matches('\n').to_string()
+    }
+}
@@ -0,0 +1,154 @@
+use crate::managers::blob::BlobMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::config::ConfigMgr;
+use crate::managers::index::IndexMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::checkout::CheckoutMode;
+use crate::api_models::pick::PickResult;
+use crate::models::Signature;
+use crate::errors::{CommitError, DitResult};
+
+/// Public
+impl CommitMgr {
+    /// Applies the change the given commit introduced relative to its first parent
+    /// on top of HEAD and commits it with the original message and author, the configured
+    /// user being the committer.
+    /// `revision` is the revision the commit was resolved from, used to refer to it
+    /// in the conflict markers
+    #[allow(clippy::too_many_arguments)]
+    pub fn cherry_pick<S: AsRef<str>>(
+        &mut self,
+        revision: S,
+        hash: String,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<PickResult> {
+        let commit = self.get_commit(&hash)?;
+        let parent_tree = match commit.parents.first() {
+            Some(parent) => Some(self.get_commit(parent)?.tree),
+            None => None,
+        };
+
+        let message = format!("{}\n\n(cherry picked from commit {hash})", commit.message.trim_end());
+
+        self.apply_change(
+            revision.as_ref(),
+            parent_tree,
+            Some(commit.tree),
+            message,
+            commit.author,
+            blob_mgr,
+            tree_mgr,
+            index_mgr,
+            branch_mgr,
+            config_mgr,
+        )
+    }
+
+    /// Applies the inverse of the change the given commit introduced relative to
+    /// its first parent on top of HEAD and commits it as the configured user.
+    /// `revision` is the revision the commit was resolved from, used to refer to it
+    /// in the conflict markers
+    #[allow(clippy::too_many_arguments)]
+    pub fn revert<S: AsRef<str>>(
+        &mut self,
+        revision: S,
+        hash: String,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<PickResult> {
+        let commit = self.get_commit(&hash)?;
+        let parent_tree = match commit.parents.first() {
+            Some(parent) => Some(self.get_commit(parent)?.tree),
+            None => None,
+        };
+
+        let subject = commit.message.lines().next().unwrap_or_default();
+        let message = format!("Revert \"{subject}\"\n\nThis reverts commit {hash}.");
+        let author = Self::user_signature_now(config_mgr)?;
+
+        self.apply_change(
+            revision.as_ref(),
+            Some(commit.tree),
+            parent_tree,
+            message,
+            author,
+            blob_mgr,
+            tree_mgr,
+            index_mgr,
+            branch_mgr,
+            config_mgr,
+        )
+    }
+}
+
+
+/// Private
+impl CommitMgr {
+    /// Applies the change from the `base` tree to the `target` tree on top of HEAD.
+    /// If it applies cleanly, the result is committed, otherwise the conflicts are left
+    /// in the index and the working tree
+    #[allow(clippy::too_many_arguments)]
+    fn apply_change(
+        &mut self,
+        revision: &str,
+        base: Option<String>,
+        target: Option<String>,
+        message: String,
+        author: Signature,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<PickResult> {
+        if index_mgr.pending_merge().is_some() || index_mgr.has_conflicts() {
+            return Err(CommitError::MergeInProgress(revision.to_string()).into());
+        }
+
+        if index_mgr.are_tracked_changes(tree_mgr, self, branch_mgr)?
+            || index_mgr.are_unstaged_changes()?
+        {
+            return Err(CommitError::LocalChanges(revision.to_string()).into());
+        }
+
+        let head = branch_mgr.get_head_commit().cloned();
+        let head_tree = match &head {
+            Some(head) => Some(self.get_commit(head)?.tree),
+            None => None,
+        };
+
+        let merge = tree_mgr.merge_trees(base, head_tree.clone(), target)?;
+
+        let unchanged = match &head_tree {
+            Some(head_tree) => tree_mgr.matches_index(head_tree, &merge.index)?,
+            None => merge.index.files.is_empty(),
+        };
+        if merge.conflicts.is_empty() && unchanged {
+            return Err(CommitError::EmptyChange(revision.to_string()).into());
+        }
+
+        let our_label = branch_mgr.get_current_branch().cloned().unwrap_or_else(|| "HEAD".to_string());
+        let head_index = branch_mgr.get_head_tree(tree_mgr, self)?;
+        let unmerged = index_mgr.apply_tree_merge(
+            merge, head_index, CheckoutMode::Safe, &our_label, revision, blob_mgr
+        )?;
+        if !unmerged.is_empty() {
+            return Ok(PickResult::Conflicted { paths: unmerged, message });
+        }
+
+        let committer = Self::committer_now(&author, config_mgr)?;
+        let tree_hash = tree_mgr.create_tree(index_mgr.index().clone())?;
+        let commit_hash = self.create_commit_from_tree(message, author, committer, tree_hash, head.into_iter().collect(), config_mgr)?;
+        branch_mgr.set_head_commit(&commit_hash)?;
+
+        Ok(PickResult::Committed(commit_hash))
+    }
+}
@@ -2,9 +2,11 @@ use crate::managers::blob::BlobMgr;
 use crate::managers::tree::TreeMgr;
 use crate::managers::commit::CommitMgr;
 use crate::managers::branch::BranchMgr;
-use crate::helpers::remove_file_if_exists;
+use crate::managers::checkout::CheckoutMgr;
+use crate::models::{CheckoutJournal, CheckoutStep};
+use crate::api_models::checkout::CheckoutMode;
 use crate::errors::DitResult;
-use crate::managers::ignore::IgnoreMgr;
+use crate::managers::index::IndexMgr;
 
 impl CommitMgr {
     /// Performs a soft reset to a specific commit. Only changes the head
@@ -35,27 +37,41 @@ impl CommitMgr {
         Ok(())
     }
 
-    /// Performs a mixed reset to a specific commit. Hard reset means that the files
-    /// not included in that commit tree will be deleted.
+    /// Performs a hard reset to a specific commit. Hard reset means that the tracked files
+    /// not included in that commit tree will be deleted, and the index and the tracked files
+    /// will match the tree. Only the files which differ are written, the untracked files are left alone.
+    /// The untracked files and local modifications in the way are handled according to the `mode`.
+    /// The update is journaled under the given description, see [`CheckoutMgr`]
+    #[allow(clippy::too_many_arguments)]
     pub fn hard_reset<S: AsRef<str>>(
         &mut self,
         commit: S,
+        mode: CheckoutMode,
+        description: String,
         blob_mgr: &mut BlobMgr,
         tree_mgr: &mut TreeMgr,
         branch_mgr: &mut BranchMgr,
-        ignore_mgr: &mut IgnoreMgr
+        index_mgr: &mut IndexMgr,
+        checkout_mgr: &mut CheckoutMgr,
     ) -> DitResult<()> {
         let commit = self.get_commit(commit.as_ref())?;
+        let target = tree_mgr.get_tree_index(&commit.tree)?;
 
-        // Clear the project directory to recover the target commit tree
-        ignore_mgr.walk_dir_files(self.repo.repo_path(), |p| {
-            remove_file_if_exists(&p)
-        })?;
+        let head = branch_mgr.get_head_tree(tree_mgr, self)?;
+        let (files, index) = index_mgr.plan_checkout(head, target, true, mode, blob_mgr)?;
 
-        tree_mgr.recover_tree(commit.tree, blob_mgr)?;
-
-        branch_mgr.set_head_commit(commit.hash)?;
-
-        Ok(())
+        let journal = CheckoutJournal {
+            description,
+            step: CheckoutStep::Files,
+            tree: Some(commit.tree),
+            orig_branch: branch_mgr.get_current_branch().cloned(),
+            orig_head: branch_mgr.get_head_commit().cloned(),
+            branch: branch_mgr.get_current_branch().cloned(),
+            head: Some(commit.hash),
+            orig_index: index_mgr.index().clone(),
+            index,
+            files,
+        };
+        checkout_mgr.checkout(journal, blob_mgr, index_mgr, branch_mgr)
     }
 }
@@ -0,0 +1,172 @@
+use crate::managers::commit::CommitMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::tag::TagMgr;
+use crate::managers::remote::RemoteMgr;
+use crate::managers::reflog::ReflogMgr;
+use crate::api_models::object::ObjectKind;
+use crate::errors::{DitResult, RevisionError};
+use std::fs;
+
+/// Name of the revision which refers to the current commit
+const HEAD_REVISION: &str = "HEAD";
+
+/// Public
+impl CommitMgr {
+    /// Resolves a revision to the full hash of a commit.
+    ///
+    /// A revision starts with one of the following:
+    /// - `HEAD` - the current commit
+    /// - a tag name - the commit the tag points to
+    /// - a branch name - the head commit of the branch
+    /// - `<remote>/<branch>` - the head commit of the remote branch as of the last fetch or push
+    /// - `HEAD@{N}`, `<branch>@{N}` - the commit HEAD or the branch pointed to N movements ago,
+    ///   according to the reflog (`@{0}` is the current commit)
+    /// - a full commit hash or its unique prefix
+    ///
+    /// followed by any number of suffixes:
+    /// - `~N` - the N-th generation ancestor following the first parents (`~` is `~1`)
+    /// - `^N` - the N-th parent (`^` is `^1`, `^0` is the commit itself)
+    ///
+    /// For example, `HEAD~3`, `main^2`, `a1b2c3~2^`, `HEAD@{1}~2`
+    pub fn resolve_revision<S: AsRef<str>>(
+        &self,
+        revision: S,
+        branch_mgr: &BranchMgr,
+        tag_mgr: &TagMgr,
+        remote_mgr: &RemoteMgr,
+        reflog_mgr: &ReflogMgr,
+    ) -> DitResult<String> {
+        let revision = revision.as_ref();
+        let suffix_start = revision.find(['~', '^']).unwrap_or(revision.len());
+        let (base, mut suffixes) = revision.split_at(suffix_start);
+
+        let mut commit = self.resolve_revision_base(base, branch_mgr, tag_mgr, remote_mgr, reflog_mgr)?;
+
+        while let Some(operator) = suffixes.chars().next() {
+            suffixes = &suffixes[1..];
+            let digits_end = suffixes.find(|c: char| !c.is_ascii_digit()).unwrap_or(suffixes.len());
+            let (digits, rest) = suffixes.split_at(digits_end);
+            suffixes = rest;
+
+            let n = if digits.is_empty() {
+                1
+            } else {
+                digits.parse::<usize>()
+                    .map_err(|_| RevisionError::InvalidRevision(revision.to_string()))?
+            };
+
+            match operator {
+                '~' => {
+                    for _ in 0..n {
+                        commit = self.get_parents(&commit)?
+                            .into_iter()
+                            .next()
+                            .ok_or_else(|| RevisionError::NoSuchParent(commit.clone(), 1))?;
+                    }
+                }
+                '^' if n > 0 => {
+                    commit = self.get_parents(&commit)?
+                        .into_iter()
+                        .nth(n - 1)
+                        .ok_or_else(|| RevisionError::NoSuchParent(commit.clone(), n))?;
+                }
+                '^' => {}
+                _ => return Err(RevisionError::InvalidRevision(revision.to_string()).into()),
+            }
+        }
+
+        Ok(commit)
+    }
+}
+
+
+/// Private
+impl CommitMgr {
+    /// Resolves the part of a revision preceding the suffixes
+    fn resolve_revision_base(
+        &self,
+        base: &str,
+        branch_mgr: &BranchMgr,
+        tag_mgr: &TagMgr,
+        remote_mgr: &RemoteMgr,
+        reflog_mgr: &ReflogMgr,
+    ) -> DitResult<String> {
+        if base.is_empty() {
+            return Err(RevisionError::InvalidRevision(base.to_string()).into());
+        }
+
+        if let Some((name, n)) = base.strip_suffix('}').and_then(|base| base.rsplit_once("@{")) {
+            let n = n.parse::<usize>()
+                .map_err(|_| RevisionError::InvalidRevision(base.to_string()))?;
+
+            let entries = reflog_mgr.read_log(name)?;
+            if let Some(entry) = entries.iter().rev().nth(n) {
+                return Ok(entry.new.clone());
+            }
+
+            // A reference which has never moved is only known by its current value
+            let commit = self.resolve_revision_base(name, branch_mgr, tag_mgr, remote_mgr, reflog_mgr)?;
+            return if n == 0 {
+                Ok(commit)
+            } else {
+                Err(RevisionError::NoSuchReflogEntry(name.to_string(), entries.len()).into())
+            };
+        }
+
+        if base == HEAD_REVISION {
+            return branch_mgr.get_head_commit()
+                .cloned()
+                .ok_or_else(|| RevisionError::UnknownRevision(base.to_string()).into());
+        }
+
+        if TagMgr::is_valid_tag_name(base)
+            && let Some(commit) = tag_mgr.get_tag_commit(base)?
+        {
+            return Ok(commit);
+        }
+
+        if BranchMgr::is_valid_branch_name(base)
+            && let Some(commit) = branch_mgr.get_branch_head(base)?
+        {
+            return Ok(commit);
+        }
+
+        if let Some((remote, branch)) = base.split_once('/')
+            && let Some(commit) = remote_mgr.get_tracking_head(remote, branch)?
+        {
+            return Ok(commit);
+        }
+
+        if !base.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(RevisionError::UnknownRevision(base.to_string()).into());
+        }
+
+        let prefix = base.to_ascii_lowercase();
+        let mut candidates = Vec::new();
+        for entry in fs::read_dir(self.repo.commits())? {
+            let name = entry?.file_name().to_string_lossy().to_string();
+            if name.starts_with(&prefix) {
+                candidates.push(name);
+            }
+        }
+        for hash in self.packs.hashes(ObjectKind::Commit)? {
+            if hash.starts_with(&prefix) {
+                candidates.push(hash.clone());
+            }
+        }
+        candidates.sort();
+        candidates.dedup();
+
+        match candidates.len() {
+            0 => Err(RevisionError::UnknownRevision(base.to_string()).into()),
+            1 => Ok(candidates.remove(0)),
+            _ => {
+                let candidates = candidates.iter()
+                    .map(|c| &c[..c.len().min(12)])
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Err(RevisionError::AmbiguousRevision(base.to_string(), candidates).into())
+            }
+        }
+    }
+}
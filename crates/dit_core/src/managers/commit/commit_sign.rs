@@ -0,0 +1,162 @@
+use crate::managers::commit::{CommitBfsIterator, CommitMgr};
+use crate::managers::config::ConfigMgr;
+use crate::api_models::signing::SignatureStatus;
+use crate::models::{Commit, CommitSignature};
+use crate::helpers::path_to_string;
+use crate::errors::{DitResult, FsError, SigningError};
+use ed25519_dalek::{Signature as Ed25519Signature, Signer, SigningKey, VerifyingKey};
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Public
+impl CommitMgr {
+    /// Generates a new Ed25519 key pair. The private key is written to `path` and the
+    /// public key to `path` with the `.pub` extension appended, both hex-encoded.
+    /// Returns the public key
+    pub fn generate_signing_key(path: &Path) -> DitResult<String> {
+        let public_path = Self::public_key_path(path);
+        for path in [path, public_path.as_path()] {
+            if path.exists() {
+                return Err(SigningError::KeyFileExists(path_to_string(path)).into());
+            }
+        }
+
+        let mut seed = [0u8; 32];
+        getrandom::fill(&mut seed).map_err(|_| SigningError::KeyGenerationError)?;
+        let key = SigningKey::from_bytes(&seed);
+        let public_key = hex::encode(key.verifying_key().to_bytes());
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options.open(path)
+            .map_err(|_| FsError::FileCreateError(path_to_string(path)))?;
+        writeln!(file, "{}", hex::encode(seed))
+            .map_err(|_| FsError::FileWriteError(path_to_string(path)))?;
+        fs::write(&public_path, format!("{public_key}\n"))
+            .map_err(|_| FsError::FileWriteError(path_to_string(&public_path)))?;
+
+        Ok(public_key)
+    }
+
+    /// Reads the private key from a file written by [`CommitMgr::generate_signing_key`]
+    pub fn load_signing_key(path: &Path) -> DitResult<SigningKey> {
+        let content = fs::read_to_string(path)
+            .map_err(|_| FsError::FileReadError(path_to_string(path)))?;
+
+        let seed: [u8; 32] = hex::decode(content.trim()).ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| SigningError::InvalidKeyFile(path_to_string(path)))?;
+
+        Ok(SigningKey::from_bytes(&seed))
+    }
+
+    /// Returns the configured signing key, if there is one
+    pub fn configured_signing_key(config_mgr: &ConfigMgr) -> DitResult<Option<SigningKey>> {
+        config_mgr.get_signing_key()
+            .map(|path| Self::load_signing_key(Path::new(&path)))
+            .transpose()
+    }
+
+    /// Signs the commit with the given key. The signature is made of the hash the commit has
+    /// without it (see [`CommitMgr::signed_hash`]), so the commit must be hashed after it's signed
+    pub fn sign_commit(commit: &mut Commit, key: &SigningKey) {
+        let signature = key.sign(Self::signed_hash(commit).as_bytes());
+
+        commit.signature = Some(CommitSignature {
+            public_key: hex::encode(key.verifying_key().to_bytes()),
+            signature: hex::encode(signature.to_bytes()),
+        });
+    }
+
+    /// Verifies the signature of the commit. A correct signature is trusted if it's made
+    /// with the key of the configured user or one of the `commit.trusted_keys`
+    pub fn verify_commit_signature(commit: &Commit, config_mgr: &ConfigMgr) -> DitResult<SignatureStatus> {
+        let Some(signed) = &commit.signature else {
+            return Ok(SignatureStatus::Unsigned);
+        };
+
+        let public_key = hex::decode(&signed.public_key).ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .and_then(|bytes: [u8; 32]| VerifyingKey::from_bytes(&bytes).ok());
+        let signature = hex::decode(&signed.signature).ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(|bytes: [u8; 64]| Ed25519Signature::from_bytes(&bytes));
+
+        let valid = match (public_key, signature) {
+            (Some(public_key), Some(signature)) =>
+                public_key.verify_strict(Self::signed_hash(commit).as_bytes(), &signature).is_ok(),
+            _ => false,
+        };
+        if !valid {
+            return Ok(SignatureStatus::Bad(signed.public_key.clone()));
+        }
+
+        let own_key = Self::configured_signing_key(config_mgr)?
+            .map(|key| hex::encode(key.verifying_key().to_bytes()));
+        let trusted = own_key.as_ref() == Some(&signed.public_key)
+            || config_mgr.get_trusted_keys().contains(&signed.public_key);
+
+        match trusted {
+            true => Ok(SignatureStatus::Good(signed.public_key.clone())),
+            false => Ok(SignatureStatus::Untrusted(signed.public_key.clone())),
+        }
+    }
+
+    /// Fails if the branch requires signed commits but no signing key is configured,
+    /// so that the commits created on it would be unsigned
+    pub fn require_signing_key(branch: Option<&str>, config_mgr: &ConfigMgr) -> DitResult<()> {
+        if let Some(branch) = branch
+            && config_mgr.requires_signed_commits(branch)
+            && config_mgr.get_signing_key().is_none()
+        {
+            return Err(SigningError::SigningKeyRequired(branch.to_string()).into());
+        }
+
+        Ok(())
+    }
+
+    /// Fails if the branch requires signed commits and any of the commits reachable from
+    /// `new` but not from `old` is not correctly signed with a trusted key
+    pub fn verify_branch_update(
+        &self,
+        branch: &str,
+        old: Option<&str>,
+        new: &str,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<()> {
+        if !config_mgr.requires_signed_commits(branch) {
+            return Ok(());
+        }
+
+        let known: HashSet<String> = CommitBfsIterator::from_many(old, self).collect::<DitResult<_>>()?;
+        for hash in CommitBfsIterator::new(new, self) {
+            let hash = hash?;
+            if known.contains(&hash) {
+                continue;
+            }
+
+            let commit = self.get_commit(&hash)?;
+            if !Self::verify_commit_signature(&commit, config_mgr)?.is_good() {
+                return Err(SigningError::UnverifiedCommit(hash, branch.to_string()).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Private
+impl CommitMgr {
+    /// Returns the path of the public key which belongs to the private key at the given path
+    fn public_key_path(path: &Path) -> PathBuf {
+        let mut public_path = path.as_os_str().to_owned();
+        public_path.push(".pub");
+        PathBuf::from(public_path)
+    }
+}
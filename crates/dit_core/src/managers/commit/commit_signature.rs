@@ -0,0 +1,53 @@
+use crate::managers::commit::CommitMgr;
+use crate::managers::config::ConfigMgr;
+use crate::models::Signature;
+use crate::helpers::{is_valid_email, parse_identity};
+use crate::errors::{CommitError, DitResult, OtherError};
+use chrono::Local;
+use std::time::SystemTime;
+
+/// Public
+impl CommitMgr {
+    /// Creates a signature of the given identity (`Name <email>`) with the current time
+    /// and the offset of the local timezone. Fails if the email address is invalid
+    pub fn signature_now(identity: &str) -> DitResult<Signature> {
+        let (name, email) = parse_identity(identity);
+        if (name.is_empty() && email.is_empty()) || (!email.is_empty() && !is_valid_email(&email)) {
+            return Err(CommitError::InvalidAuthor(identity.to_string()).into());
+        }
+
+        let (timestamp, offset) = Self::now()?;
+        Ok(Signature { name, email, timestamp, offset })
+    }
+
+    /// Creates a signature of the configured user with the current time
+    pub fn user_signature_now(config_mgr: &ConfigMgr) -> DitResult<Signature> {
+        Self::signature_now(&config_mgr.require_user()?)
+    }
+
+    /// Creates the signature of the committer of a commit written by `author`: the configured
+    /// user, or the author if no user is configured. It always has the current time
+    pub fn committer_now(author: &Signature, config_mgr: &ConfigMgr) -> DitResult<Signature> {
+        if config_mgr.get_user_name().is_some() || config_mgr.get_user_email().is_some() {
+            return Self::user_signature_now(config_mgr);
+        }
+
+        let (timestamp, offset) = Self::now()?;
+        Ok(Signature { timestamp, offset, ..author.clone() })
+    }
+}
+
+
+/// Private
+impl CommitMgr {
+    /// Returns the current Unix timestamp and the offset of the local timezone in minutes
+    fn now() -> DitResult<(u64, i32)> {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|_| OtherError::TimeWentBackwardsError)?
+            .as_secs();
+        let offset = Local::now().offset().local_minus_utc() / 60;
+
+        Ok((timestamp, offset))
+    }
+}
@@ -1,53 +1,65 @@
 use crate::managers::commit::commit_iterator::CommitBfsIterator;
 use crate::managers::commit::CommitMgr;
 use crate::managers::tree::TreeMgr;
-use crate::models::{Commit, Tree};
+use crate::models::{Commit, Index};
 use crate::errors::DitResult;
-use crate::helpers::DitModel;
+use crate::helpers::{read_bytes, write_atomic};
+use std::collections::HashSet;
 
 /// Load/write to the commits directory
 impl CommitMgr {
     /// Writes the given commit to the commits directory
-    pub(super) fn write_commit(&self, commit: &Commit) -> DitResult<()> {
+    pub fn write_commit(&self, commit: &Commit) -> DitResult<()> {
         let path = self.repo.commits().join(&commit.hash);
 
-        commit.serialize_to(&path)
+        write_atomic(path, Self::stored_bytes(commit)?)
     }
 
     /// Reads and returns a commit given the commit's hash
     pub fn get_commit<S: Into<String>>(&self, hash: S) -> DitResult<Commit> {
         let hash = hash.into();
-        let path = self.repo.commits().join(&hash);
-
-        let mut commit = Commit::deserialize_from(&path)?;
-
+        let mut commit = Self::decode_commit(&self.read_commit_bytes(&hash)?)?;
 
         commit.hash = hash;
 
         Ok(commit)
     }
+
+    /// Reads the commit (either loose or packed) as it's stored, see [`CommitMgr::decode_commit`]
+    pub fn read_commit_bytes(&self, hash: &str) -> DitResult<Vec<u8>> {
+        let path = self.repo.commits().join(hash);
+        if !path.is_file()
+            && let Some(content) = self.packs.read_object(hash)?
+        {
+            return Ok(content);
+        }
+
+        read_bytes(&path)
+    }
+
+    /// Checks whether the commit exists (either loose or packed)
+    pub fn has_commit(&self, hash: &str) -> DitResult<bool> {
+        Ok(self.repo.commits().join(hash).is_file() || self.packs.contains(hash)?)
+    }
 }
 
 
 /// Getters
 impl CommitMgr {
-    /// Returns the tree of a commit by commit hash
+    /// Returns the files of the tree of a commit by commit hash
     pub fn get_commit_tree<S: Into<String>>(
         &self,
         hash: S,
         tree_mgr: &TreeMgr
-    ) -> DitResult<Tree> {
+    ) -> DitResult<Index> {
         let commit = self.get_commit(hash)?;
 
-        tree_mgr.get_tree(commit.tree)
+        tree_mgr.get_tree_index(&commit.tree)
     }
 
     /// Returns the parent commit hash(es) of a given commit
     pub fn get_parents<S: Into<String>>(&self, hash: S) -> DitResult<Vec<String>> {
-        let hash = hash.into();
-        let path = self.repo.commits().join(&hash);
-        let commit = Commit::deserialize_from(&path)?;
-        Ok(commit.parents)
+        Ok(self.get_commit(hash)?.parents)
     }
 
 
@@ -62,11 +74,91 @@ impl CommitMgr {
 
         let commit_iterator = CommitBfsIterator::new(child, self);
         for commit in commit_iterator {
-            if commit == ancestor {
+            if commit? == ancestor {
                 return Ok(true);
             }
         }
 
         Ok(false)
     }
+
+
+    /// Finds the best common ancestor (the merge base) of two commits.
+    ///
+    /// Returns `None` if the commits have no common history
+    pub fn find_merge_base<S1, S2>(&self, first: S1, second: S2) -> DitResult<Option<String>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let first_ancestors: HashSet<String> = CommitBfsIterator::new(first, self).collect::<DitResult<_>>()?;
+
+        // Common ancestors in the order of their distance from the second commit
+        let mut common = Vec::new();
+        for commit in CommitBfsIterator::new(second, self) {
+            let commit = commit?;
+            if first_ancestors.contains(&commit) {
+                common.push(commit);
+            }
+        }
+
+        // Every ancestor of a common ancestor is itself common, so the best candidates
+        // are the ones which are not reachable from the parents of other common ancestors
+        let mut parents = Vec::new();
+        for commit in &common {
+            parents.extend(self.get_parents(commit)?);
+        }
+        let dominated: HashSet<String> = CommitBfsIterator::from_many(parents, self).collect::<DitResult<_>>()?;
+
+        Ok(common.into_iter().find(|c| !dominated.contains(c)))
+    }
+
+    /// Returns the commits reachable from `head` but not from `upstream`, parents before
+    /// their children. Merge commits are left out, so that the result can be replayed
+    /// one by one on top of another commit
+    pub fn list_commits_to_replay<S1, S2>(&self, head: S1, upstream: S2) -> DitResult<Vec<String>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let head = head.into();
+        let upstream: HashSet<String> = CommitBfsIterator::new(upstream, self).collect::<DitResult<_>>()?;
+        let mut own = HashSet::new();
+        for commit in CommitBfsIterator::new(head.clone(), self) {
+            let commit = commit?;
+            if !upstream.contains(&commit) {
+                own.insert(commit);
+            }
+        }
+
+        // Depth-first post-order walk, so that every commit comes after its parents
+        let mut ordered = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![(head, false)];
+        while let Some((hash, expanded)) = stack.pop() {
+            if !own.contains(&hash) {
+                continue;
+            }
+
+            let commit = self.get_commit(&hash)?;
+            if expanded {
+                if commit.parents.len() <= 1 {
+                    ordered.push(hash);
+                }
+                continue;
+            }
+            if !visited.insert(hash.clone()) {
+                continue;
+            }
+
+            stack.push((hash, true));
+            for parent in commit.parents.into_iter().rev() {
+                if !visited.contains(&parent) {
+                    stack.push((parent, false));
+                }
+            }
+        }
+
+        Ok(ordered)
+    }
 }
@@ -6,16 +6,20 @@
 //! commit, the commit message, etc.
 
 use crate::Repo;
+use crate::managers::pack::PackStore;
 use std::sync::Arc;
 
 /// Manages the commits in our Dit version control system
 pub struct CommitMgr {
     pub(super) repo: Arc<Repo>,
+
+    /// Represents the packed commits, which are read if there is no loose commit
+    pub(super) packs: PackStore,
 }
 
 /// Constructors
 impl CommitMgr {
     pub fn from(repo: Arc<Repo>) -> Self {
-        Self { repo }
+        Self { packs: PackStore::from(repo.clone()), repo }
     }
 }
@@ -3,5 +3,12 @@ mod commit_reset;
 mod commit_create;
 mod helpers;
 mod commit_iterator;
+mod commit_resolve;
+mod commit_pick;
+mod commit_message;
+mod commit_signature;
+mod commit_sign;
+mod commit_encoding;
 
 pub use manager::CommitMgr;
+pub use commit_iterator::CommitBfsIterator;
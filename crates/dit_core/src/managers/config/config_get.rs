@@ -1,6 +1,7 @@
-use crate::errors::{ConfigError, DitResult};
+use crate::errors::{ConfigError, DitResult, RemoteError};
+use std::collections::BTreeMap;
 use crate::managers::config::ConfigMgr;
-use crate::models::USER_NAME_CONFIG;
+use crate::helpers::is_valid_email;
 
 impl ConfigMgr {
     pub fn get_user(&self) -> Option<String> {
@@ -15,9 +16,53 @@ impl ConfigMgr {
         self.config.user_email.clone()
     }
 
+    pub fn get_editor_config(&self) -> Option<String> {
+        self.config.core_editor.clone()
+    }
+
+    pub fn get_max_subject_length(&self) -> Option<usize> {
+        self.config.commit_max_subject_length
+    }
+
+    pub fn get_blank_second_line(&self) -> Option<bool> {
+        self.config.commit_blank_second_line
+    }
+
+    pub fn get_signing_key(&self) -> Option<String> {
+        self.config.user_signing_key.clone()
+    }
+
+    pub fn get_trusted_keys(&self) -> &[String] {
+        &self.config.commit_trusted_keys
+    }
+
+    pub fn get_signed_branches(&self) -> &[String] {
+        &self.config.commit_signed_branches
+    }
+
+    /// Checks whether the branch may only receive signed commits
+    pub fn requires_signed_commits(&self, branch: &str) -> bool {
+        self.config.commit_signed_branches.iter().any(|b| b == branch)
+    }
+
+    /// Returns the editor command: the configured one, otherwise the one from
+    /// the `VISUAL` or the `EDITOR` environment variable
+    pub fn get_editor(&self) -> Option<String> {
+        self.get_editor_config()
+            .or_else(|| std::env::var("VISUAL").ok())
+            .or_else(|| std::env::var("EDITOR").ok())
+            .filter(|editor| !editor.trim().is_empty())
+    }
+
     /// If at least one of the username and user email is set, returns the formatted version.
-    /// Otherwise, returns an error
+    /// Otherwise, or if the email address is invalid, returns an error
     pub fn require_user(&self) -> DitResult<String> {
+        if let Some(email) = &self.config.user_email
+            && !is_valid_email(email)
+        {
+            return Err(ConfigError::InvalidEmail(email.clone()).into());
+        }
+
         match &self.config.user_name {
             Some(name) => match &self.config.user_email {
                 Some(email) => Ok(format!("{name} <{email}>")),
@@ -26,9 +71,21 @@ impl ConfigMgr {
 
             None => match &self.config.user_email {
                 Some(email) => Ok(format!("<{email}>")),
-                None => Err(ConfigError::ConfigNotFound(USER_NAME_CONFIG.to_string()).into())
+                None => Err(ConfigError::UserIdentityNotFound.into())
             }
         }
     }
-}
 
+    /// Returns the url of the remote, or an error if there is no such remote
+    pub fn require_remote_url(&self, name: &str) -> DitResult<&str> {
+        self.config.remotes
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| RemoteError::RemoteDoesNotExist(name.to_string()).into())
+    }
+
+    /// Returns the remote names mapped to their urls
+    pub fn list_remotes(&self) -> &BTreeMap<String, String> {
+        &self.config.remotes
+    }
+}
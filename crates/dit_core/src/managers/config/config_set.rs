@@ -1,4 +1,5 @@
-use crate::errors::DitResult;
+use crate::errors::{ConfigError, DitResult, RemoteError};
+use crate::helpers::is_valid_email;
 use crate::managers::config::ConfigMgr;
 
 impl ConfigMgr {
@@ -8,7 +9,58 @@ impl ConfigMgr {
     }
 
     pub fn set_user_email(&mut self, user_email: String) -> DitResult<()> {
+        if !is_valid_email(&user_email) {
+            return Err(ConfigError::InvalidEmail(user_email).into());
+        }
+
         self.config.user_email = Some(user_email);
         self.store()
     }
+
+    pub fn set_editor(&mut self, editor: String) -> DitResult<()> {
+        self.config.core_editor = Some(editor);
+        self.store()
+    }
+
+    pub fn set_max_subject_length(&mut self, length: usize) -> DitResult<()> {
+        self.config.commit_max_subject_length = Some(length);
+        self.store()
+    }
+
+    pub fn set_blank_second_line(&mut self, required: bool) -> DitResult<()> {
+        self.config.commit_blank_second_line = Some(required);
+        self.store()
+    }
+
+    pub fn set_signing_key(&mut self, path: String) -> DitResult<()> {
+        self.config.user_signing_key = Some(path);
+        self.store()
+    }
+
+    pub fn set_trusted_keys(&mut self, keys: Vec<String>) -> DitResult<()> {
+        self.config.commit_trusted_keys = keys;
+        self.store()
+    }
+
+    pub fn set_signed_branches(&mut self, branches: Vec<String>) -> DitResult<()> {
+        self.config.commit_signed_branches = branches;
+        self.store()
+    }
+
+    pub fn add_remote(&mut self, name: String, url: String) -> DitResult<()> {
+        if self.config.remotes.contains_key(&name) {
+            return Err(RemoteError::RemoteAlreadyExists(name).into());
+        }
+
+        self.config.remotes.insert(name, url);
+        self.store()
+    }
+
+    pub fn remove_remote(&mut self, name: &str) -> DitResult<()> {
+        if self.config.remotes.remove(name).is_none() {
+            return Err(RemoteError::RemoteDoesNotExist(name.to_string()).into());
+        }
+
+        self.store()
+    }
 }
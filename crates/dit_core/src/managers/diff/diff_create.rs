@@ -0,0 +1,153 @@
+use crate::managers::diff::DiffMgr;
+use crate::managers::blob::BlobMgr;
+use crate::managers::index::IndexMgr;
+use crate::api_models::diff::{Diff, DiffContent, DiffHunk, DiffLine, FileDiff, FileDiffKind};
+use crate::models::{Change, Index, ModifiedFile};
+use crate::helpers::{diff_lines, group_hunks, is_binary, split_lines, DiffOp, DIFF_CONTEXT_LINES};
+use crate::errors::DitResult;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::fs;
+
+/// Public
+impl DiffMgr {
+    /// Computes the differences between two indexes (e.g. the trees of two commits)
+    pub fn diff_indexes(&self, old: &Index, new: &Index, blob_mgr: &BlobMgr) -> DitResult<Diff> {
+        let paths: BTreeSet<&PathBuf> = old.files.keys()
+            .chain(new.files.keys())
+            .collect();
+
+        let mut diff = Diff::default();
+
+        for rel_path in paths {
+            let in_old = old.files.get(rel_path);
+            let in_new = new.files.get(rel_path);
+
+            if let (Some(in_old), Some(in_new)) = (in_old, in_new)
+                && in_old.hash == in_new.hash
+            {
+                continue;
+            }
+
+            let old_content = in_old
+                .map(|e| blob_mgr.read_blob(e.hash.clone()))
+                .transpose()?;
+            let new_content = in_new
+                .map(|e| blob_mgr.read_blob(e.hash.clone()))
+                .transpose()?;
+
+            diff.files.push(Self::diff_file(rel_path, old_content, new_content));
+        }
+
+        Ok(diff)
+    }
+
+    /// Computes the differences between the index and the working tree.
+    /// Only the tracked files are compared
+    pub fn diff_working_tree(&self, index_mgr: &IndexMgr, blob_mgr: &BlobMgr) -> DitResult<Diff> {
+        let mut diff = Diff::default();
+
+        for (rel_path, entry) in &index_mgr.index().files {
+            let new_content = match index_mgr.get_untracked_change(rel_path)? {
+                Change::Modified(ModifiedFile { old_hash, hash, .. }) if old_hash != hash => {
+                    let abs_path = self.repo.abs_path_from_repo(rel_path, true)?;
+                    Some(fs::read(abs_path)?)
+                }
+                Change::Deleted(_) => None,
+                _ => continue,
+            };
+            let old_content = blob_mgr.read_blob(entry.hash.clone())?;
+
+            diff.files.push(Self::diff_file(rel_path, Some(old_content), new_content));
+        }
+
+        Ok(diff)
+    }
+}
+
+
+/// Private
+impl DiffMgr {
+    /// Compares two versions of a file. `None` means that the file doesn't exist
+    fn diff_file(rel_path: &Path, old: Option<Vec<u8>>, new: Option<Vec<u8>>) -> FileDiff {
+        let kind = match (&old, &new) {
+            (None, _) => FileDiffKind::Added,
+            (_, None) => FileDiffKind::Deleted,
+            _ => FileDiffKind::Modified,
+        };
+        let old = old.unwrap_or_default();
+        let new = new.unwrap_or_default();
+
+        let content = if is_binary(&old) || is_binary(&new) {
+            DiffContent::Binary
+        } else {
+            DiffContent::Text(Self::diff_text(&old, &new))
+        };
+
+        FileDiff { path: rel_path.to_path_buf(), kind, content }
+    }
+
+    /// Compares two versions of a text file line by line and groups the changes into hunks
+    fn diff_text(old: &[u8], new: &[u8]) -> Vec<DiffHunk> {
+        let old_lines = split_lines(old);
+        let new_lines = split_lines(new);
+        let ops = diff_lines(&old_lines, &new_lines);
+
+        group_hunks(&ops, DIFF_CONTEXT_LINES)
+            .into_iter()
+            .map(|range| {
+                let old_before = ops[..range.start].iter()
+                    .filter(|op| !matches!(op, DiffOp::Insert { .. }))
+                    .count();
+                let new_before = ops[..range.start].iter()
+                    .filter(|op| !matches!(op, DiffOp::Delete { .. }))
+                    .count();
+
+                let mut lines = Vec::new();
+                let (mut old_len, mut new_len) = (0, 0);
+                for op in &ops[range] {
+                    let line = match *op {
+                        DiffOp::Equal { old, .. } => {
+                            lines.push(DiffLine::Context(line_to_string(old_lines[old])));
+                            old_len += 1;
+                            new_len += 1;
+                            old_lines[old]
+                        }
+                        DiffOp::Delete { old } => {
+                            lines.push(DiffLine::Removed(line_to_string(old_lines[old])));
+                            old_len += 1;
+                            old_lines[old]
+                        }
+                        DiffOp::Insert { new } => {
+                            lines.push(DiffLine::Added(line_to_string(new_lines[new])));
+                            new_len += 1;
+                            new_lines[new]
+                        }
+                    };
+
+                    // Only the last line of a file can lack the terminator
+                    if !line.ends_with(b"\n") {
+                        lines.push(DiffLine::NoNewlineAtEnd);
+                    }
+                }
+
+                // An empty side of a hunk starts at the line preceding it
+                DiffHunk {
+                    old_start: if old_len == 0 { old_before } else { old_before + 1 },
+                    old_len,
+                    new_start: if new_len == 0 { new_before } else { new_before + 1 },
+                    new_len,
+                    lines,
+                }
+            })
+            .collect()
+    }
+}
+
+
+/// Converts a line to a string without the line feed. The carriage return of
+/// a CRLF line is kept, so that a change of the line endings shows in the diff
+fn line_to_string(line: &[u8]) -> String {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    String::from_utf8_lossy(line).to_string()
+}
@@ -0,0 +1,21 @@
+//! This module computes the differences between versions of the project files
+//!
+//! The contents of two versions of a file are compared line by line (using the
+//! Myers algorithm) and the changes are grouped into hunks, similar to the
+//! unified diff format. Versions can come from blobs (the index, the trees of
+//! commits) or directly from the working tree.
+
+use crate::Repo;
+use std::sync::Arc;
+
+/// Manages the computation of diffs in our Dit version control system
+pub struct DiffMgr {
+    pub(super) repo: Arc<Repo>,
+}
+
+/// Constructors
+impl DiffMgr {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { repo }
+    }
+}
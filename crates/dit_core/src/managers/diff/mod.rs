@@ -0,0 +1,4 @@
+mod manager;
+mod diff_create;
+
+pub use manager::DiffMgr;
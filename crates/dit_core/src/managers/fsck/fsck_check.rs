@@ -0,0 +1,101 @@
+use crate::managers::fsck::FsckMgr;
+use crate::api_models::fsck::{DanglingObject, FsckReport};
+use crate::api_models::object::ObjectKind;
+use crate::models::{CheckedObjects, ObjectRoots};
+use crate::errors::DitResult;
+use std::collections::HashSet;
+
+/// Public
+impl FsckMgr {
+    /// Checks the integrity of all the objects and references in the repository
+    pub fn check(&self) -> DitResult<FsckReport> {
+        let mut report = FsckReport::default();
+
+        let objects = self.check_objects(&mut report.problems)?;
+        let roots = self.check_refs(&objects, &mut report.problems)?;
+
+        report.checked = objects.blobs.len()
+            + objects.trees.len()
+            + objects.commits.len()
+            + objects.tag_objects.len();
+        report.dangling = Self::find_dangling(&objects, roots);
+
+        Ok(report)
+    }
+}
+
+
+/// Private
+impl FsckMgr {
+    /// Returns the objects which are unreachable from the roots and
+    /// not referenced by any other object
+    fn find_dangling(objects: &CheckedObjects, roots: ObjectRoots) -> Vec<DanglingObject> {
+        let mut reachable_blobs = roots.blobs;
+        let mut reachable_trees = HashSet::new();
+        let mut reachable_commits = HashSet::new();
+        let reachable_tags = roots.tag_objects;
+
+        let mut trees = Vec::new();
+        let mut queue: Vec<String> = roots.commits.into_iter().collect();
+        queue.extend(reachable_tags.iter()
+            .filter_map(|hash| objects.tag_objects.get(hash)?.as_ref())
+            .map(|tag| tag.commit.clone()));
+
+        while let Some(hash) = queue.pop() {
+            if !reachable_commits.insert(hash.clone()) {
+                continue;
+            }
+            let Some(Some(commit)) = objects.commits.get(&hash) else { continue };
+
+            trees.push(commit.tree.clone());
+            queue.extend(commit.parents.iter().cloned());
+        }
+
+        // The subtrees shared with the already walked trees are skipped
+        while let Some(hash) = trees.pop() {
+            if !reachable_trees.insert(hash.clone()) {
+                continue;
+            }
+            let Some(Some(tree)) = objects.trees.get(&hash) else { continue };
+
+            for entry in tree.entries.values() {
+                match entry.is_tree() {
+                    true => trees.push(entry.hash.clone()),
+                    false => { reachable_blobs.insert(entry.hash.clone()); }
+                }
+            }
+        }
+
+        // Objects referenced by other (unreachable) objects are not reported
+        let mut referenced = HashSet::new();
+        for commit in objects.commits.values().flatten() {
+            referenced.insert(commit.tree.as_str());
+            referenced.extend(commit.parents.iter().map(String::as_str));
+        }
+        for tree in objects.trees.values().flatten() {
+            referenced.extend(tree.entries.values().map(|e| e.hash.as_str()));
+        }
+        for tag in objects.tag_objects.values().flatten() {
+            referenced.insert(tag.commit.as_str());
+        }
+
+        let mut dangling = Vec::new();
+        let kinds = [
+            (ObjectKind::Commit, objects.commits.keys().collect::<Vec<_>>(), &reachable_commits),
+            (ObjectKind::TagObject, objects.tag_objects.keys().collect(), &reachable_tags),
+            (ObjectKind::Tree, objects.trees.keys().collect(), &reachable_trees),
+            (ObjectKind::Blob, objects.blobs.iter().collect(), &reachable_blobs),
+        ];
+
+        for (kind, mut hashes, reachable) in kinds {
+            hashes.sort();
+            for hash in hashes {
+                if !reachable.contains(hash) && !referenced.contains(hash.as_str()) {
+                    dangling.push(DanglingObject { kind, hash: hash.clone() });
+                }
+            }
+        }
+
+        dangling
+    }
+}
@@ -0,0 +1,240 @@
+use crate::managers::fsck::FsckMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::tag::TagMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::fsck::FsckProblem;
+use crate::api_models::object::ObjectKind;
+use crate::models::CheckedObjects;
+use crate::helpers::{hash_compressed_file, hash_file, is_temp_file_name, read_bytes, DitHasher};
+use crate::errors::DitResult;
+use std::collections::HashMap;
+use std::path::Path;
+use std::fs;
+
+impl FsckMgr {
+    /// Reads all the stored objects (both loose and packed) and verifies their hashes
+    /// and the references between them
+    pub(super) fn check_objects(&self, problems: &mut Vec<FsckProblem>) -> DitResult<CheckedObjects> {
+        let mut objects = CheckedObjects::default();
+        let packed = self.check_packs(problems);
+
+        let mut blobs = Vec::new();
+        for hash in Self::list_objects(self.repo.blobs())? {
+            let actual = hash_compressed_file(&self.repo.blobs().join(&hash));
+            blobs.push((hash, actual));
+        }
+        if packed {
+            for hash in self.packs.hashes(ObjectKind::Blob)? {
+                let actual = self.packs.read_object(hash).map(|content| {
+                    let mut hasher = DitHasher::new();
+                    hasher.update(&content.unwrap_or_default());
+                    hasher.finalize_string()
+                });
+                blobs.push((hash.clone(), actual));
+            }
+        }
+
+        for (hash, actual) in blobs {
+            match actual {
+                Ok(actual) => {
+                    Self::verify_hash(ObjectKind::Blob, &hash, actual, problems);
+                }
+                Err(e) => {
+                    problems.push(FsckProblem::Corrupt { kind: ObjectKind::Blob, hash: hash.clone(), reason: e.to_string() });
+                }
+            }
+            objects.blobs.insert(hash);
+        }
+
+        for (hash, tree) in self.load_objects(self.repo.trees(), ObjectKind::Tree, packed, TreeMgr::decode_tree)? {
+            let tree = Self::parse_object(ObjectKind::Tree, &hash, tree, problems)
+                .filter(|tree| {
+                    Self::verify_hash(ObjectKind::Tree, &hash, TreeMgr::hash_tree(tree), problems)
+                });
+            Self::insert_object(&mut objects.trees, hash, tree);
+        }
+
+        for (hash, commit) in self.load_objects(self.repo.commits(), ObjectKind::Commit, packed, CommitMgr::decode_commit)? {
+            let commit = Self::parse_object(ObjectKind::Commit, &hash, commit, problems)
+                .filter(|c| {
+                    let actual = CommitMgr::hash_commit(c);
+                    Self::verify_hash(ObjectKind::Commit, &hash, actual, problems)
+                });
+            Self::insert_object(&mut objects.commits, hash, commit);
+        }
+
+        for (hash, tag) in self.load_objects(self.repo.tag_objects(), ObjectKind::TagObject, packed, TagMgr::decode_tag)? {
+            let tag = Self::parse_object(ObjectKind::TagObject, &hash, tag, problems)
+                .filter(|t| {
+                    let actual = TagMgr::hash_tag(t);
+                    Self::verify_hash(ObjectKind::TagObject, &hash, actual, problems)
+                });
+            Self::insert_object(&mut objects.tag_objects, hash, tag);
+        }
+
+        Self::check_connectivity(&objects, problems);
+
+        Ok(objects)
+    }
+}
+
+
+/// Private
+impl FsckMgr {
+    /// Verifies that every tree entry references an existing blob or subtree, every commit
+    /// an existing tree and parents, and every tag object an existing commit
+    fn check_connectivity(objects: &CheckedObjects, problems: &mut Vec<FsckProblem>) {
+        for (hash, tree) in &objects.trees {
+            for (name, entry) in tree.iter().flat_map(|t| &t.entries) {
+                let (kind, exists) = match entry.is_tree() {
+                    true => (ObjectKind::Tree, objects.trees.contains_key(&entry.hash)),
+                    false => (ObjectKind::Blob, objects.blobs.contains(&entry.hash)),
+                };
+                if !exists {
+                    problems.push(FsckProblem::Missing {
+                        kind,
+                        hash: entry.hash.clone(),
+                        referenced_by: format!("tree {hash} ({name})"),
+                    });
+                }
+            }
+        }
+
+        for (hash, commit) in &objects.commits {
+            let Some(commit) = commit else { continue };
+
+            if !objects.trees.contains_key(&commit.tree) {
+                problems.push(FsckProblem::Missing {
+                    kind: ObjectKind::Tree,
+                    hash: commit.tree.clone(),
+                    referenced_by: format!("commit {hash}"),
+                });
+            }
+
+            for parent in &commit.parents {
+                if !objects.commits.contains_key(parent) {
+                    problems.push(FsckProblem::Missing {
+                        kind: ObjectKind::Commit,
+                        hash: parent.clone(),
+                        referenced_by: format!("commit {hash}"),
+                    });
+                }
+            }
+        }
+
+        for (hash, tag) in &objects.tag_objects {
+            if let Some(tag) = tag
+                && !objects.commits.contains_key(&tag.commit)
+            {
+                problems.push(FsckProblem::Missing {
+                    kind: ObjectKind::Commit,
+                    hash: tag.commit.clone(),
+                    referenced_by: format!("tag object {hash}"),
+                });
+            }
+        }
+    }
+
+    /// Returns the names of the objects in an object directory, skipping
+    /// the leftover temporary files
+    fn list_objects(dir: &Path) -> DitResult<Vec<String>> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type()?.is_file() && !is_temp_file_name(&name) {
+                names.push(name);
+            }
+        }
+        names.sort();
+
+        Ok(names)
+    }
+
+    /// Verifies that the pack files match their names (hashes).
+    /// Returns `false` if the pack indexes can't be read
+    fn check_packs(&self, problems: &mut Vec<FsckProblem>) -> bool {
+        let packs = match self.packs.packs() {
+            Ok(packs) => packs,
+            Err(e) => {
+                problems.push(FsckProblem::CorruptPack { name: String::from("index"), reason: e.to_string() });
+                return false;
+            }
+        };
+
+        for pack in packs {
+            match hash_file(&self.packs.pack_path(&pack.name)) {
+                Ok(actual) if actual == pack.name => {}
+                Ok(actual) => problems.push(FsckProblem::CorruptPack {
+                    name: pack.name.clone(),
+                    reason: format!("the content hashes to {actual}"),
+                }),
+                Err(e) => problems.push(FsckProblem::CorruptPack { name: pack.name.clone(), reason: e.to_string() }),
+            }
+        }
+
+        true
+    }
+
+    /// Reads the loose objects of an object directory and (optionally) the packed
+    /// objects of the given kind, and decodes them
+    fn load_objects<T>(
+        &self,
+        dir: &Path,
+        kind: ObjectKind,
+        packed: bool,
+        decode: impl Fn(&[u8]) -> DitResult<T>,
+    ) -> DitResult<Vec<(String, DitResult<T>)>> {
+        let mut loaded = Vec::new();
+
+        for hash in Self::list_objects(dir)? {
+            let object = read_bytes(&dir.join(&hash)).and_then(|content| decode(&content));
+            loaded.push((hash, object));
+        }
+
+        if packed {
+            for hash in self.packs.hashes(kind)? {
+                let object = self.packs.read_object(hash)
+                    .and_then(|content| decode(&content.unwrap_or_default()));
+                loaded.push((hash.clone(), object));
+            }
+        }
+
+        Ok(loaded)
+    }
+
+    /// Returns the parsed object, or records a problem if it couldn't be read
+    fn parse_object<T>(
+        kind: ObjectKind,
+        hash: &str,
+        object: DitResult<T>,
+        problems: &mut Vec<FsckProblem>,
+    ) -> Option<T> {
+        match object {
+            Ok(object) => Some(object),
+            Err(e) => {
+                problems.push(FsckProblem::Corrupt { kind, hash: hash.to_string(), reason: e.to_string() });
+                None
+            }
+        }
+    }
+
+    /// Records an object. If it's stored both loose and packed,
+    /// a valid copy takes precedence
+    fn insert_object<T>(objects: &mut HashMap<String, Option<T>>, hash: String, object: Option<T>) {
+        let slot = objects.entry(hash).or_default();
+        if slot.is_none() {
+            *slot = object;
+        }
+    }
+
+    /// Records a problem if the recalculated hash differs from the expected one
+    fn verify_hash(kind: ObjectKind, hash: &str, actual: String, problems: &mut Vec<FsckProblem>) -> bool {
+        if actual == hash {
+            return true;
+        }
+
+        problems.push(FsckProblem::HashMismatch { kind, hash: hash.to_string(), actual });
+        false
+    }
+}
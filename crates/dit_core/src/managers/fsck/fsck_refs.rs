@@ -0,0 +1,329 @@
+use crate::managers::fsck::FsckMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::tag::TagMgr;
+use crate::managers::remote::RemoteMgr;
+use crate::managers::reflog::ReflogMgr;
+use crate::managers::oplog::OpLogMgr;
+use crate::managers::rebase::RebaseMgr;
+use crate::api_models::fsck::FsckProblem;
+use crate::api_models::object::ObjectKind;
+use crate::models::{CheckedObjects, Index, ObjectRoots, Stash, TagRef};
+use crate::helpers::{is_temp_file_name, path_to_string, read_to_string, DitModelDefault};
+use crate::errors::DitResult;
+use std::fs;
+
+impl FsckMgr {
+    /// Validates HEAD, the branches, the remote-tracking branches, the tags, the index, the stash,
+    /// the reflog, the operation log and the rebase in progress.
+    /// Returns the objects they reference
+    pub(super) fn check_refs(
+        &self,
+        objects: &CheckedObjects,
+        problems: &mut Vec<FsckProblem>,
+    ) -> DitResult<ObjectRoots> {
+        let mut roots = ObjectRoots::default();
+
+        self.check_head(objects, &mut roots, problems)?;
+        self.check_branches(objects, &mut roots, problems)?;
+        self.check_remotes(objects, &mut roots, problems)?;
+        self.check_tags(objects, &mut roots, problems)?;
+        self.check_index(objects, &mut roots, problems);
+        self.check_stash(objects, &mut roots, problems);
+        self.check_reflogs(objects, &mut roots, problems)?;
+        self.check_oplog(objects, &mut roots, problems)?;
+        self.check_rebase(objects, &mut roots, problems);
+
+        Ok(roots)
+    }
+}
+
+
+/// Private
+impl FsckMgr {
+    fn check_head(
+        &self,
+        objects: &CheckedObjects,
+        roots: &mut ObjectRoots,
+        problems: &mut Vec<FsckProblem>,
+    ) -> DitResult<()> {
+        let head = read_to_string(self.repo.head_file())?;
+
+        if let Some(commit) = head.strip_prefix(':') {
+            Self::require_commit(objects, commit, "HEAD", roots, problems);
+        } else if !head.is_empty() {
+            if !BranchMgr::is_valid_branch_name(&head) {
+                problems.push(FsckProblem::InvalidRef {
+                    name: String::from("HEAD"),
+                    reason: format!("'{head}' is not a valid branch name"),
+                });
+            } else if !self.repo.branches().join(&head).is_file() {
+                problems.push(FsckProblem::InvalidRef {
+                    name: String::from("HEAD"),
+                    reason: format!("points to the branch '{head}' which doesn't exist"),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_branches(
+        &self,
+        objects: &CheckedObjects,
+        roots: &mut ObjectRoots,
+        problems: &mut Vec<FsckProblem>,
+    ) -> DitResult<()> {
+        for entry in fs::read_dir(self.repo.branches())? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if is_temp_file_name(&name) {
+                continue;
+            }
+            let ref_name = format!("branch '{name}'");
+
+            if !BranchMgr::is_valid_branch_name(&name) {
+                problems.push(FsckProblem::InvalidRef {
+                    name: ref_name,
+                    reason: String::from("not a valid branch name"),
+                });
+                continue;
+            }
+
+            let commit = read_to_string(&entry.path())?;
+            if !commit.is_empty() {
+                Self::require_commit(objects, &commit, &ref_name, roots, problems);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_remotes(
+        &self,
+        objects: &CheckedObjects,
+        roots: &mut ObjectRoots,
+        problems: &mut Vec<FsckProblem>,
+    ) -> DitResult<()> {
+        for remote in fs::read_dir(self.repo.remotes())? {
+            let remote = remote?;
+            let remote_name = remote.file_name().to_string_lossy().to_string();
+
+            if !remote.file_type()?.is_dir() || !RemoteMgr::is_valid_remote_name(&remote_name) {
+                problems.push(FsckProblem::InvalidRef {
+                    name: format!("remote '{remote_name}'"),
+                    reason: String::from("not a valid remote name"),
+                });
+                continue;
+            }
+
+            for entry in fs::read_dir(remote.path())? {
+                let entry = entry?;
+                let name = entry.file_name().to_string_lossy().to_string();
+                if is_temp_file_name(&name) {
+                    continue;
+                }
+                let ref_name = format!("remote-tracking branch '{remote_name}/{name}'");
+
+                if !BranchMgr::is_valid_branch_name(&name) {
+                    problems.push(FsckProblem::InvalidRef {
+                        name: ref_name,
+                        reason: String::from("not a valid branch name"),
+                    });
+                    continue;
+                }
+
+                let commit = read_to_string(&entry.path())?;
+                Self::require_commit(objects, &commit, &ref_name, roots, problems);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_tags(
+        &self,
+        objects: &CheckedObjects,
+        roots: &mut ObjectRoots,
+        problems: &mut Vec<FsckProblem>,
+    ) -> DitResult<()> {
+        for entry in fs::read_dir(self.repo.tags())? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if is_temp_file_name(&name) {
+                continue;
+            }
+            let ref_name = format!("tag '{name}'");
+
+            if !TagMgr::is_valid_tag_name(&name) {
+                problems.push(FsckProblem::InvalidRef {
+                    name: ref_name,
+                    reason: String::from("not a valid tag name"),
+                });
+                continue;
+            }
+
+            match TagRef::parse(&read_to_string(&entry.path())?) {
+                Some(TagRef::Commit(commit)) => {
+                    Self::require_commit(objects, &commit, &ref_name, roots, problems);
+                }
+                Some(TagRef::TagObject(hash)) => {
+                    if objects.tag_objects.contains_key(&hash) {
+                        roots.tag_objects.insert(hash);
+                    } else {
+                        problems.push(FsckProblem::Missing {
+                            kind: ObjectKind::TagObject,
+                            hash,
+                            referenced_by: ref_name,
+                        });
+                    }
+                }
+                None => problems.push(FsckProblem::InvalidRef {
+                    name: ref_name,
+                    reason: String::from("doesn't point to a commit or a tag object"),
+                }),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_index(&self, objects: &CheckedObjects, roots: &mut ObjectRoots, problems: &mut Vec<FsckProblem>) {
+        let index = match Index::deserialize_default_from(self.repo.index_file()) {
+            Ok(index) => index,
+            Err(e) => {
+                problems.push(FsckProblem::InvalidRef { name: String::from("index"), reason: e.to_string() });
+                return;
+            }
+        };
+
+        let conflicts = index.conflicts.iter().flat_map(|(rel_path, conflict)| {
+            [&conflict.base, &conflict.ours, &conflict.theirs]
+                .into_iter()
+                .flatten()
+                .map(move |hash| (rel_path, hash))
+        });
+
+        for (rel_path, hash) in index.files.iter().map(|(p, e)| (p, &e.hash)).chain(conflicts) {
+            if !objects.blobs.contains(hash) {
+                problems.push(FsckProblem::Missing {
+                    kind: ObjectKind::Blob,
+                    hash: hash.clone(),
+                    referenced_by: format!("index ({})", path_to_string(rel_path)),
+                });
+            }
+            roots.blobs.insert(hash.clone());
+        }
+
+        if let Some(merge) = &index.merge {
+            Self::require_commit(objects, &merge.head, "merge in progress", roots, problems);
+        }
+    }
+
+    fn check_stash(&self, objects: &CheckedObjects, roots: &mut ObjectRoots, problems: &mut Vec<FsckProblem>) {
+        match Stash::deserialize_default_from(self.repo.stash_file()) {
+            Ok(stash) => {
+                for (index, commit) in stash.entries.iter().enumerate() {
+                    Self::require_commit(objects, commit, &format!("stash@{{{index}}}"), roots, problems);
+                }
+            }
+            Err(e) => {
+                problems.push(FsckProblem::InvalidRef { name: String::from("stash"), reason: e.to_string() });
+            }
+        }
+    }
+
+    fn check_reflogs(
+        &self,
+        objects: &CheckedObjects,
+        roots: &mut ObjectRoots,
+        problems: &mut Vec<FsckProblem>,
+    ) -> DitResult<()> {
+        let reflog_mgr = ReflogMgr::from(self.repo.clone());
+
+        for name in reflog_mgr.list_logs()? {
+            let entries = match reflog_mgr.read_log(&name) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    problems.push(FsckProblem::InvalidRef { name: format!("reflog of '{name}'"), reason: e.to_string() });
+                    continue;
+                }
+            };
+
+            for (n, entry) in entries.iter().rev().enumerate() {
+                let ref_name = format!("{name}@{{{n}}}");
+                if let Some(old) = &entry.old {
+                    Self::require_commit(objects, old, &ref_name, roots, problems);
+                }
+                Self::require_commit(objects, &entry.new, &ref_name, roots, problems);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_oplog(
+        &self,
+        objects: &CheckedObjects,
+        roots: &mut ObjectRoots,
+        problems: &mut Vec<FsckProblem>,
+    ) -> DitResult<()> {
+        let operations = match OpLogMgr::from(self.repo.clone()).list_operations() {
+            Ok(operations) => operations,
+            Err(e) => {
+                problems.push(FsckProblem::InvalidRef { name: String::from("operation log"), reason: e.to_string() });
+                return Ok(());
+            }
+        };
+
+        for operation in &operations {
+            let ref_name = format!("operation {} ({})", operation.id, operation.description);
+            for commit in OpLogMgr::operation_commits(operation) {
+                Self::require_commit(objects, &commit, &ref_name, roots, problems);
+            }
+            for blob in OpLogMgr::operation_blobs(operation) {
+                if !objects.blobs.contains(&blob) {
+                    problems.push(FsckProblem::Missing {
+                        kind: ObjectKind::Blob,
+                        hash: blob.clone(),
+                        referenced_by: ref_name.clone(),
+                    });
+                }
+                roots.blobs.insert(blob);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_rebase(&self, objects: &CheckedObjects, roots: &mut ObjectRoots, problems: &mut Vec<FsckProblem>) {
+        match RebaseMgr::from(self.repo.clone()) {
+            Ok(rebase_mgr) => {
+                for commit in rebase_mgr.list_commits() {
+                    Self::require_commit(objects, &commit, "rebase in progress", roots, problems);
+                }
+            }
+            Err(e) => {
+                problems.push(FsckProblem::InvalidRef { name: String::from("rebase"), reason: e.to_string() });
+            }
+        }
+    }
+
+    /// Records the commit as a root, or a problem if it doesn't exist
+    fn require_commit(
+        objects: &CheckedObjects,
+        commit: &str,
+        referenced_by: &str,
+        roots: &mut ObjectRoots,
+        problems: &mut Vec<FsckProblem>,
+    ) {
+        if objects.commits.contains_key(commit) {
+            roots.commits.insert(commit.to_string());
+        } else {
+            problems.push(FsckProblem::Missing {
+                kind: ObjectKind::Commit,
+                hash: commit.to_string(),
+                referenced_by: referenced_by.to_string(),
+            });
+        }
+    }
+}
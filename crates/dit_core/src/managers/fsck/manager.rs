@@ -0,0 +1,25 @@
+//! This module checks the integrity of the Dit repository
+//!
+//! Every stored object (loose or packed) is read and its hash is recalculated from the content,
+//! every reference between the objects is followed, and HEAD, the branches, the
+//! remote-tracking branches, the tags, the index, the stash, the reflog, the operation log
+//! and the rebase in progress are validated. The objects which are not reachable from any
+//! reference are reported as dangling.
+
+use crate::Repo;
+use crate::managers::pack::PackStore;
+use std::sync::Arc;
+
+/// Checks the integrity of the repository. See [`crate::fsck`] for more info
+pub struct FsckMgr {
+    pub(super) repo: Arc<Repo>,
+
+    pub(super) packs: PackStore,
+}
+
+/// Constructors
+impl FsckMgr {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { packs: PackStore::from(repo.clone()), repo }
+    }
+}
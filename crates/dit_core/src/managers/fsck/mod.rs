@@ -0,0 +1,6 @@
+mod manager;
+mod fsck_check;
+mod fsck_objects;
+mod fsck_refs;
+
+pub use manager::FsckMgr;
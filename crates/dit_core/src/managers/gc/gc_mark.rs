@@ -0,0 +1,116 @@
+use crate::managers::gc::GcMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::commit::{CommitBfsIterator, CommitMgr};
+use crate::managers::index::IndexMgr;
+use crate::managers::stash::StashMgr;
+use crate::managers::tag::TagMgr;
+use crate::managers::remote::RemoteMgr;
+use crate::managers::reflog::ReflogMgr;
+use crate::managers::oplog::OpLogMgr;
+use crate::managers::rebase::RebaseMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::object::ObjectKind;
+use crate::models::ReachableObjects;
+use crate::errors::{DitResult, GcError};
+
+impl GcMgr {
+    /// Collects all the objects reachable from the branches, the remote-tracking branches,
+    /// the tags, the head, the index (including a merge in progress), the stash, the reflog,
+    /// the operation log and the rebase in progress
+    #[allow(clippy::too_many_arguments)]
+    pub fn mark(
+        &self,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+        index_mgr: &IndexMgr,
+        branch_mgr: &BranchMgr,
+        tag_mgr: &TagMgr,
+        stash_mgr: &StashMgr,
+        remote_mgr: &RemoteMgr,
+        reflog_mgr: &ReflogMgr,
+        oplog_mgr: &OpLogMgr,
+        rebase_mgr: &RebaseMgr,
+    ) -> DitResult<ReachableObjects> {
+        let mut reachable = ReachableObjects::default();
+        let mut roots = Vec::new();
+
+        for branch in branch_mgr.list_branches()? {
+            roots.extend(branch_mgr.get_branch_head(branch)?);
+        }
+        roots.extend(branch_mgr.get_head_commit().cloned());
+        roots.extend(remote_mgr.list_all_tracking_heads()?.into_values());
+
+        for tag in tag_mgr.list_tags()? {
+            if let Some(annotation) = tag.annotation {
+                reachable.tag_objects.insert(annotation.hash);
+            }
+            roots.push(tag.commit);
+        }
+
+        roots.extend(stash_mgr.get_stash_commits().iter().cloned());
+        roots.extend(reflog_mgr.list_logged_commits()?);
+        roots.extend(oplog_mgr.list_logged_commits()?);
+        reachable.blobs.extend(oplog_mgr.list_logged_blobs()?);
+        roots.extend(rebase_mgr.list_commits());
+
+        let index = index_mgr.index();
+        if let Some(merge) = &index.merge {
+            roots.push(merge.head.clone());
+        }
+        for entry in index.files.values() {
+            reachable.blobs.insert(entry.hash.clone());
+        }
+        for conflict in index.conflicts.values() {
+            for hash in [&conflict.base, &conflict.ours, &conflict.theirs].into_iter().flatten() {
+                reachable.blobs.insert(hash.clone());
+            }
+        }
+
+        for root in &roots {
+            Self::require_object(commit_mgr.has_commit(root)?, ObjectKind::Commit, root)?;
+        }
+
+        // Every parent is checked, so that a missing one is reported as a missing object
+        // instead of as the failure to read it
+        for commit_hash in CommitBfsIterator::from_many(roots, commit_mgr) {
+            let commit_hash = commit_hash?;
+            let commit = commit_mgr.get_commit(&commit_hash)?;
+            for parent in &commit.parents {
+                Self::require_object(commit_mgr.has_commit(parent)?, ObjectKind::Commit, parent)?;
+            }
+
+            // The subtrees shared with the already walked trees are skipped
+            let mut trees = vec![commit.tree];
+            while let Some(tree_hash) = trees.pop() {
+                if !reachable.trees.insert(tree_hash.clone()) {
+                    continue;
+                }
+
+                Self::require_object(tree_mgr.has_tree(&tree_hash)?, ObjectKind::Tree, &tree_hash)?;
+                for entry in tree_mgr.get_tree(tree_hash)?.entries.into_values() {
+                    match entry.is_tree() {
+                        true => trees.push(entry.hash),
+                        false => { reachable.blobs.insert(entry.hash); }
+                    }
+                }
+            }
+
+            reachable.commits.insert(commit_hash);
+        }
+
+        Ok(reachable)
+    }
+}
+
+
+/// Private
+impl GcMgr {
+    /// Fails if the object referenced by a reachable object doesn't exist
+    fn require_object(exists: bool, kind: ObjectKind, hash: &str) -> DitResult<()> {
+        if exists {
+            Ok(())
+        } else {
+            Err(GcError::MissingObject(kind.to_string(), hash.to_string()).into())
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::managers::gc::GcMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::index::IndexMgr;
+use crate::managers::stash::StashMgr;
+use crate::managers::tag::TagMgr;
+use crate::managers::remote::RemoteMgr;
+use crate::managers::reflog::ReflogMgr;
+use crate::managers::oplog::OpLogMgr;
+use crate::managers::rebase::RebaseMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::gc::{GcObject, GcReport};
+use crate::api_models::object::ObjectKind;
+use crate::models::ReachableObjects;
+use crate::helpers::{is_temp_file_name, remove_file_if_exists};
+use crate::errors::DitResult;
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use std::fs;
+
+/// Public
+impl GcMgr {
+    /// Removes the objects which are unreachable and older than the grace period.
+    /// In a dry run, only reports what would be removed
+    #[allow(clippy::too_many_arguments)]
+    pub fn collect_garbage(
+        &self,
+        grace_period: Duration,
+        dry_run: bool,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+        index_mgr: &IndexMgr,
+        branch_mgr: &BranchMgr,
+        tag_mgr: &TagMgr,
+        stash_mgr: &StashMgr,
+        remote_mgr: &RemoteMgr,
+        reflog_mgr: &ReflogMgr,
+        oplog_mgr: &OpLogMgr,
+        rebase_mgr: &RebaseMgr,
+    ) -> DitResult<GcReport> {
+        let ReachableObjects { blobs, trees, commits, tag_objects } = self.mark(
+            tree_mgr, commit_mgr, index_mgr, branch_mgr, tag_mgr, stash_mgr, remote_mgr, reflog_mgr,
+            oplog_mgr, rebase_mgr,
+        )?;
+
+        // Anything modified after this moment is kept
+        let cutoff = SystemTime::now()
+            .checked_sub(grace_period)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+
+        let mut report = GcReport { dry_run, ..Default::default() };
+
+        // Commits are swept first, so that an interrupted collection never leaves
+        // a commit whose tree or blobs were already removed
+        let sweeps = [
+            (ObjectKind::Commit, self.repo.commits(), &commits),
+            (ObjectKind::TagObject, self.repo.tag_objects(), &tag_objects),
+            (ObjectKind::Tree, self.repo.trees(), &trees),
+            (ObjectKind::Blob, self.repo.blobs(), &blobs),
+        ];
+
+        for (kind, dir, reachable) in sweeps {
+            self.sweep_dir(kind, dir, reachable, cutoff, &mut report)?;
+        }
+
+        Ok(report)
+    }
+}
+
+
+/// Private
+impl GcMgr {
+    /// Removes the unreachable files of an object directory which are older than the cutoff.
+    /// The temp files of the objects which are being written are skipped
+    fn sweep_dir(
+        &self,
+        kind: ObjectKind,
+        dir: &Path,
+        reachable: &HashSet<String>,
+        cutoff: SystemTime,
+        report: &mut GcReport,
+    ) -> DitResult<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let hash = entry.file_name().to_string_lossy().to_string();
+            if reachable.contains(&hash) || is_temp_file_name(&hash) {
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+
+            if metadata.modified()? > cutoff {
+                report.kept_recent += 1;
+                continue;
+            }
+
+            if !report.dry_run {
+                remove_file_if_exists(&entry.path())?;
+            }
+
+            report.removed.push(GcObject { kind, hash, size: metadata.len() });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+//! This module collects garbage in the Dit version control system
+//!
+//! Staging files, resetting and merging leave behind blobs, trees and commits
+//! which are no longer referenced. The garbage collection marks every object
+//! reachable from the branches, the remote-tracking branches, the tags, the head,
+//! the index (including a merge in progress), the stash, the reflog, the
+//! operation log and the rebase in progress, and removes the rest. \
+//! Objects younger than a grace period are kept, so that the objects which are
+//! being created at the moment are never removed. The temp files of the writes
+//! in progress aren't objects, so they are never removed either. \
+//! Only the loose objects are removed. Repacking writes the unreachable packed
+//! objects back as loose objects, so they are removed by the next collection.
+
+use crate::Repo;
+use std::sync::Arc;
+
+/// Removes the unreachable objects. See [`crate::gc`] for more info
+pub struct GcMgr {
+    pub(super) repo: Arc<Repo>,
+}
+
+/// Constructors
+impl GcMgr {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { repo }
+    }
+}
@@ -0,0 +1,5 @@
+mod manager;
+mod gc_mark;
+mod gc_sweep;
+
+pub use manager::GcMgr;
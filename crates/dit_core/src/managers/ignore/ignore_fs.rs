@@ -1,8 +1,7 @@
 ﻿use crate::managers::ignore::manager::{IgnoreMgr, DEFAULT_IGNORE_LIST};
-use crate::helpers::{expand_globs, get_cwd, path_to_string, remove_file_if_exists};
+use crate::helpers::{expand_globs, get_cwd, path_to_string};
 use crate::errors::DitResult;
 use std::path::{Path, PathBuf};
-use std::fs;
 use ignore::gitignore::Gitignore;
 use jwalk::WalkDir;
 
@@ -39,43 +38,6 @@ impl IgnoreMgr {
         Ok(())
     }
 
-    /// Clears a given directory (except the ignored files)
-    pub fn clear_dir<P: AsRef<Path>>(&self, root: P) -> DitResult<()> {
-        let ignore = self.ignore.clone();
-        let mut to_delete: Vec<(PathBuf, bool /* is_dir */)> = WalkDir::new(root)
-            .process_read_dir(move |_depth, _path, _state, children| {
-                children.retain(|child| match child {
-                    Ok(entry) => {
-                        if entry.file_type.is_dir() {
-                            Self::is_ignored_inner(&entry.path(), &ignore, true)
-                        } else {
-                            true
-                        }
-                    }
-                    Err(_) => true
-                })
-            })
-            .into_iter()
-            .filter_map(Result::ok)
-            .filter(|e| e.depth() != 0)
-            .map(|e| (e.path(), e.file_type().is_dir()))
-            .collect();
-
-        // Sort the deepest entries first so we remove files, then empty directories
-        to_delete.sort_by_key(|(p, _)| std::cmp::Reverse(p.components().count()));
-
-        for (path, is_dir) in to_delete {
-            if is_dir {
-                fs::remove_dir_all(&path)?;
-            } else {
-                remove_file_if_exists(&path)?;
-            }
-        }
-
-        Ok(())
-    }
-
-
     /// Expands the given glob patterns to file paths relative to the
     /// current working directory
     pub fn expand_globs_cwd<I>(&self, globs: I) -> DitResult<Vec<PathBuf>>
@@ -98,7 +60,9 @@ impl IgnoreMgr {
     /// Checks if a path is ignored given the [`Gitignore`]
     fn is_ignored_inner(rel_path: &Path, ignore: &Gitignore, is_dir: bool) -> bool {
         ignore.matched_path_or_any_parents(rel_path, is_dir).is_ignore()
-        || DEFAULT_IGNORE_LIST.contains(&path_to_string(rel_path).as_str())
+        || rel_path.components().any(|c| {
+            DEFAULT_IGNORE_LIST.contains(&path_to_string(Path::new(c.as_os_str())).as_str())
+        })
     }
 }
 
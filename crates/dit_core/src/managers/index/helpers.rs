@@ -7,9 +7,10 @@ use crate::models::{
     Change, DeletedFile,
     FileFingerprint, Index,
     IndexEntry, ModifiedFile,
-    NewFile, UnchangedFile
+    NewFile, PendingMerge,
+    UnchangedFile
 };
-use crate::helpers::{hash_file, DitModel};
+use crate::helpers::{hash_file, DitModel, DitModelDefault};
 use crate::errors::DitResult;
 use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
@@ -19,7 +20,7 @@ use rayon::prelude::*;
 impl IndexMgr {
     /// Updates the index based on the index file
     pub(super) fn load(&mut self) -> DitResult<()> {
-        self.index = Index::deserialize_from(self.repo.index_file())?;
+        self.index = Index::deserialize_default_from(self.repo.index_file())?;
         Ok(())
     }
 
@@ -35,6 +36,16 @@ impl IndexMgr {
         &self.index
     }
 
+    /// Checks whether there are unmerged files
+    pub fn has_conflicts(&self) -> bool {
+        !self.index.conflicts.is_empty()
+    }
+
+    /// Returns the merge in progress (if any)
+    pub fn pending_merge(&self) -> Option<&PendingMerge> {
+        self.index.merge.as_ref()
+    }
+
     /// Returns all tracked changes
     pub fn get_all_tracked_changes(
         &self,
@@ -42,10 +53,11 @@ impl IndexMgr {
         commit_mgr: &CommitMgr,
         branch_mgr: &BranchMgr,
     ) -> DitResult<BTreeMap<PathBuf, Change>> {
-        self.index.files
+        let head = branch_mgr.get_head_tree(tree_mgr, commit_mgr)?;
+        let mut changes = self.index.files
             .par_iter()
             .filter_map(|(rel_path, entry)| {
-                match self._get_tracked_change(rel_path, &Some(entry), tree_mgr, commit_mgr, branch_mgr) {
+                match self._get_tracked_change(rel_path, &Some(entry), &head) {
                     Ok(change) => match change {
                         Change::None | Change::Unchanged(_) => None,
                         _ => Some(Ok((rel_path.to_path_buf(), change)))
@@ -54,7 +66,14 @@ impl IndexMgr {
                     Err(e) => Some(Err(e))
                 }
             })
-            .collect::<DitResult<BTreeMap<PathBuf, Change>>>()
+            .collect::<DitResult<BTreeMap<PathBuf, Change>>>()?;
+
+        // Unmerged files are reported as conflicts regardless of their index entries
+        for (rel_path, conflict) in &self.index.conflicts {
+            changes.insert(rel_path.clone(), Change::Conflicted(conflict.clone()));
+        }
+
+        Ok(changes)
     }
 
     /// Returns all untracked changes
@@ -66,6 +85,10 @@ impl IndexMgr {
         let mut unchanged_file = HashMap::new();
         ignore_mgr.walk_dir_files(self.repo.repo_path(), |abs_path| {
             let rel_path = self.repo.rel_path(&abs_path)?;
+            // Unmerged files are reported as tracked conflicts
+            if self.index.conflicts.contains_key(&rel_path) {
+                return Ok(());
+            }
             let change = self.get_untracked_change(&rel_path)?;
             if let Change::New(_) | Change::Modified(_) | Change::Deleted(_) = change {
                 changed_files.insert(rel_path, change);
@@ -79,6 +102,7 @@ impl IndexMgr {
         for (rel_path, IndexEntry { fp, hash }) in &self.index.files {
             if !changed_files.contains_key(rel_path)
                 && !unchanged_file.contains_key(rel_path)
+                && !self.index.conflicts.contains_key(rel_path)
                 && !ignore_mgr.is_ignored(rel_path)
             {
                 changed_files.insert(rel_path.clone(), Change::Deleted(DeletedFile {
@@ -120,10 +144,10 @@ impl IndexMgr {
     ) -> DitResult<(Change, Change)> {
         let in_index = self.index.files.get(rel_path);
 
+        let head = branch_mgr.get_head_tree(tree_mgr, commit_mgr)?;
+
         let untracked_change = self._get_untracked_change(rel_path, &in_index)?;
-        let tracked_change = self._get_tracked_change(
-            rel_path, &in_index, tree_mgr, commit_mgr, branch_mgr
-        )?;
+        let tracked_change = self._get_tracked_change(rel_path, &in_index, &head)?;
 
         Ok((untracked_change, tracked_change))
     }
@@ -136,7 +160,8 @@ impl IndexMgr {
         branch_mgr: &BranchMgr
     ) -> DitResult<Change> {
         let in_index = self.index.files.get(rel_path);
-        self._get_tracked_change(rel_path, &in_index, tree_mgr, commit_mgr, branch_mgr)
+        let head = branch_mgr.get_head_tree(tree_mgr, commit_mgr)?;
+        self._get_tracked_change(rel_path, &in_index, &head)
     }
 
     pub fn get_untracked_change(&self, rel_path: &Path) -> DitResult<Change> {
@@ -148,17 +173,17 @@ impl IndexMgr {
 
 /// Private
 impl IndexMgr {
+    /// Compares the index entry of a file with the files of the head commit tree
     fn _get_tracked_change(
         &self,
         rel_path: &Path,
         in_index: &Option<&IndexEntry>,
-        tree_mgr: &TreeMgr,
-        commit_mgr: &CommitMgr,
-        branch_mgr: &BranchMgr,
+        head: &Option<Index>,
     ) -> DitResult<Change> {
-        let in_tree = branch_mgr
-            .get_head_tree(tree_mgr, commit_mgr)?
-            .and_then(|mut t| t.index.files.remove(rel_path));
+        let in_tree = head
+            .as_ref()
+            .and_then(|head| head.files.get(rel_path))
+            .cloned();
 
         let change = match in_tree {
             Some(in_tree) => match in_index {
@@ -196,7 +221,7 @@ impl IndexMgr {
         Ok(change)
     }
 
-    fn _get_untracked_change(&self, rel_path: &Path, in_index: &Option<&IndexEntry>) -> DitResult<Change> {
+    pub(super) fn _get_untracked_change(&self, rel_path: &Path, in_index: &Option<&IndexEntry>) -> DitResult<Change> {
         let abs_path = self.repo.abs_path_from_repo(rel_path, true)?;
         let exists = abs_path.is_file();
 
@@ -18,6 +18,10 @@ impl IndexMgr {
             let file_path = file_path.as_ref();
             let rel_path = self.repo.rel_path(file_path)?;
             let untracked = self.get_untracked_change(&rel_path)?;
+
+            // Staging an unmerged file marks it as resolved
+            self.index.conflicts.remove(&rel_path);
+
             match &untracked {
                 Change::New(NewFile { hash, fp })
                 | Change::Modified(ModifiedFile { hash, fp, .. }) => {
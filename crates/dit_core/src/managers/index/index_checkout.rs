@@ -0,0 +1,283 @@
+use crate::managers::index::IndexMgr;
+use crate::managers::blob::BlobMgr;
+use crate::models::{CheckoutFile, FileFingerprint, Index, IndexEntry};
+use crate::api_models::checkout::CheckoutMode;
+use crate::helpers::{
+    create_file_all, hash_file, is_binary, merge_lines,
+    remove_empty_parents, remove_file_if_exists,
+};
+use crate::errors::{CheckoutError, DitResult};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::fs;
+use rayon::prelude::*;
+
+impl IndexMgr {
+    /// Updates the working tree and the index to match the target index.
+    ///
+    /// Only the files whose content differs from the current index are written
+    /// or removed. The fingerprints of the written files are refreshed
+    pub fn checkout_index(&mut self, target: Index, blob_mgr: &BlobMgr) -> DitResult<()> {
+        self.index.files = self.checkout_files(&self.index.files, target, blob_mgr)?;
+        self.store()
+    }
+
+    /// Plans updating the working tree and the index from the HEAD tree to the target tree,
+    /// e.g. when switching branches or resetting. Nothing is changed yet, the plan is applied
+    /// by the checkout manager.
+    ///
+    /// Only the paths which differ between the two trees (or are staged) are written or
+    /// removed, so the untracked files and the files which are the same in both trees are
+    /// left alone. If `discard_changes` is set, the tracked files modified in the working
+    /// tree are restored as well. The untracked files and the local modifications which
+    /// would be overwritten are handled according to the `mode`.
+    ///
+    /// Returns the files to write or remove and the index after the update
+    pub fn plan_checkout(
+        &self,
+        head: Option<Index>,
+        target: Index,
+        discard_changes: bool,
+        mode: CheckoutMode,
+        blob_mgr: &BlobMgr,
+    ) -> DitResult<(Vec<CheckoutFile>, Index)> {
+        // The index entries hold the latest fingerprints of the files, and
+        // the staged files have to be replaced like the committed ones
+        let mut current = head.map(|head| head.files).unwrap_or_default();
+        current.extend(self.index.files.clone());
+
+        let mut files = Vec::new();
+        let mut untracked = Vec::new();
+        let mut modified = Vec::new();
+
+        for (rel_path, entry) in &current {
+            let in_target = target.files.get(rel_path);
+            let after = in_target.map(|t| t.hash.clone());
+            let is_replaced = after.as_ref() != Some(&entry.hash);
+            if !is_replaced && !discard_changes {
+                continue;
+            }
+
+            let worktree = self.worktree_hash(rel_path, entry)?;
+            if !is_replaced && worktree.as_ref() == Some(&entry.hash) {
+                continue;
+            }
+
+            let mut file = CheckoutFile {
+                path: rel_path.clone(),
+                before: Some(entry.hash.clone()),
+                after,
+                local: None,
+            };
+
+            // The local modifications which the update would overwrite (a file deleted
+            // locally or already matching the target has nothing to lose)
+            if let Some(local_hash) = worktree
+                && local_hash != entry.hash
+                && file.after.as_ref() != Some(&local_hash)
+                && mode != CheckoutMode::Force
+            {
+                match (mode, in_target) {
+                    (CheckoutMode::Merge, Some(in_target)) => {
+                        let abs_path = self.repo.abs_path_from_repo(rel_path, true)?;
+                        let local = fs::read(&abs_path)?;
+                        let merged = if in_target.hash == entry.hash {
+                            Some(local.clone())
+                        } else {
+                            Self::merge_local_change(&entry.hash, local.clone(), &in_target.hash, blob_mgr)?
+                        };
+
+                        match merged {
+                            Some(merged) => {
+                                // The local content is kept for a rollback
+                                file.before = Some(blob_mgr.create_blob_from_bytes(&local)?);
+                                file.local = Some(blob_mgr.create_blob_from_bytes(&merged)?);
+                            }
+                            None => modified.push(rel_path.clone()),
+                        }
+                    }
+                    _ => modified.push(rel_path.clone()),
+                }
+            }
+
+            files.push(file);
+        }
+
+        for (rel_path, entry) in &target.files {
+            if current.contains_key(rel_path) {
+                continue;
+            }
+
+            let abs_path = self.repo.abs_path_from_repo(rel_path, true)?;
+            if mode != CheckoutMode::Force && abs_path.is_file() && hash_file(&abs_path)? != entry.hash {
+                untracked.push(rel_path.clone());
+            }
+
+            files.push(CheckoutFile {
+                path: rel_path.clone(),
+                before: None,
+                after: Some(entry.hash.clone()),
+                local: None,
+            });
+        }
+
+        if !untracked.is_empty() || !modified.is_empty() {
+            return Err(CheckoutError::WouldClobber { untracked, modified }.into());
+        }
+
+        // The files which aren't written keep their current fingerprints
+        let index_files = target.files
+            .into_iter()
+            .map(|(rel_path, entry)| match current.get(&rel_path) {
+                Some(in_current) if in_current.hash == entry.hash => (rel_path, in_current.clone()),
+                _ => (rel_path, entry),
+            })
+            .collect();
+
+        Ok((files, Index { files: index_files, ..Default::default() }))
+    }
+
+    /// Same as [`IndexMgr::checkout_index`], but the current state of the working tree
+    /// is described by the given index instead of the current one
+    pub fn checkout_index_over(
+        &mut self,
+        worktree: &Index,
+        target: Index,
+        blob_mgr: &BlobMgr,
+    ) -> DitResult<()> {
+        self.index.files = self.checkout_files(&worktree.files, target, blob_mgr)?;
+        self.store()
+    }
+
+    /// Replaces the index entries without touching the working tree.
+    ///
+    /// The entries whose content matches the current index keep their fingerprints,
+    /// so that only the files which differ from the target are reported as modified
+    pub fn restore_index(&mut self, target: Index) -> DitResult<()> {
+        let current = &self.index.files;
+
+        self.index.files = target.files
+            .into_iter()
+            .map(|(rel_path, entry)| match current.get(&rel_path) {
+                Some(in_index) if in_index.hash == entry.hash => (rel_path, in_index.clone()),
+                _ => (rel_path, entry),
+            })
+            .collect();
+
+        self.store()
+    }
+
+    /// Replaces the whole index, including the unmerged files and the merge in progress,
+    /// e.g. with the index saved before an operation which is undone. If `checkout` is set,
+    /// the tracked files of the working tree are updated to match it as well. The unmerged
+    /// files are rewritten in that case, since they contain the conflict markers
+    pub fn replace_index(&mut self, target: Index, checkout: bool, blob_mgr: &BlobMgr) -> DitResult<()> {
+        let Index { files, conflicts, merge } = target;
+
+        if checkout {
+            let mut worktree = self.index.files.clone();
+            for rel_path in self.index.conflicts.keys() {
+                if let Some(entry) = worktree.get_mut(rel_path) {
+                    entry.hash.clear();
+                }
+            }
+
+            let target = Index { files, ..Default::default() };
+            self.index.files = self.checkout_files(&worktree, target, blob_mgr)?;
+            self.index.conflicts = conflicts;
+            self.index.merge = merge;
+            self.store()
+        } else {
+            self.index.conflicts = conflicts;
+            self.index.merge = merge;
+            self.restore_index(Index { files, ..Default::default() })
+        }
+    }
+
+    /// Replaces the whole index without touching the working tree
+    pub fn set_index(&mut self, index: Index) -> DitResult<()> {
+        self.index = index;
+        self.store()
+    }
+
+    /// Removes all the entries (and the merge state) from the index.
+    /// The working tree is not touched
+    pub fn clear(&mut self) -> DitResult<()> {
+        self.index = Index::default();
+        self.store()
+    }
+}
+
+
+/// Private
+impl IndexMgr {
+    /// Returns the hash of the content of a tracked file in the working tree, or `None`
+    /// if it doesn't exist. The fingerprints are compared before hashing
+    fn worktree_hash(&self, rel_path: &Path, entry: &IndexEntry) -> DitResult<Option<String>> {
+        let abs_path = self.repo.abs_path_from_repo(rel_path, true)?;
+        if !abs_path.is_file() {
+            return Ok(None);
+        }
+
+        if FileFingerprint::from(&abs_path)? == entry.fp {
+            Ok(Some(entry.hash.clone()))
+        } else {
+            Ok(Some(hash_file(&abs_path)?))
+        }
+    }
+
+    /// Merges the local content of a file, based on the `base` blob, into the `target` blob.
+    /// Returns `None` if the versions conflict or can't be merged line by line
+    fn merge_local_change(
+        base: &str,
+        local: Vec<u8>,
+        target: &str,
+        blob_mgr: &BlobMgr,
+    ) -> DitResult<Option<Vec<u8>>> {
+        let base = blob_mgr.read_blob(base.to_string())?;
+        let target = blob_mgr.read_blob(target.to_string())?;
+        if is_binary(&base) || is_binary(&local) || is_binary(&target) {
+            return Ok(None);
+        }
+
+        let merge = merge_lines(&base, &local, &target, "local", "target");
+        Ok(merge.is_clean().then_some(merge.content))
+    }
+
+    /// Writes the files of the target index which differ from the current ones
+    /// and removes the files missing from it, along with the directories left empty.
+    /// Returns the entries of the target with up-to-date fingerprints
+    fn checkout_files(
+        &self,
+        current: &BTreeMap<PathBuf, IndexEntry>,
+        target: Index,
+        blob_mgr: &BlobMgr,
+    ) -> DitResult<BTreeMap<PathBuf, IndexEntry>> {
+        let repo_path = self.repo.repo_path();
+        for rel_path in current.keys() {
+            if !target.files.contains_key(rel_path) {
+                let abs_path = self.repo.abs_path_from_repo(rel_path, true)?;
+                remove_file_if_exists(&abs_path)?;
+                remove_empty_parents(&abs_path, repo_path);
+            }
+        }
+
+        target.files
+            .into_par_iter()
+            .map(|(rel_path, entry)| -> DitResult<(PathBuf, IndexEntry)> {
+                if let Some(in_index) = current.get(&rel_path)
+                    && in_index.hash == entry.hash
+                {
+                    return Ok((rel_path, in_index.clone()));
+                }
+
+                let abs_path = self.repo.abs_path_from_repo(&rel_path, true)?;
+                create_file_all(&abs_path)?;
+                blob_mgr.recover_blob(entry.hash.clone(), &abs_path)?;
+                let fp = FileFingerprint::from(&abs_path)?;
+
+                Ok((rel_path, IndexEntry { hash: entry.hash, fp }))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,104 @@
+use crate::managers::index::IndexMgr;
+use crate::managers::blob::BlobMgr;
+use crate::models::{ConflictEntry, Index, IndexEntry, PendingMerge, TreeMerge};
+use crate::api_models::checkout::CheckoutMode;
+use crate::helpers::create_file_all;
+use crate::errors::DitResult;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::fs;
+
+impl IndexMgr {
+    /// Records the merge which stopped because of conflicts, so that committing
+    /// the resolved files concludes it. The unmerged files are recorded by
+    /// [`IndexMgr::apply_tree_merge`]
+    pub fn start_merge(&mut self, merge: PendingMerge) -> DitResult<()> {
+        self.index.merge = Some(merge);
+        self.store()
+    }
+
+    /// Applies the result of merging trees to the working tree and the index. The conflicting
+    /// files are merged line by line if possible, otherwise they are written with conflict
+    /// markers and recorded as unmerged (without a merge in progress, so that resolving them
+    /// doesn't result in a merge commit). Returns the paths of the unmerged files.
+    ///
+    /// `head` is the tree the merge is applied over. Nothing is written if the merged or
+    /// the unmerged files would overwrite untracked files or local modifications, which are
+    /// handled according to the `mode` like by [`IndexMgr::plan_checkout`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_tree_merge(
+        &mut self,
+        mut merge: TreeMerge,
+        head: Option<Index>,
+        mode: CheckoutMode,
+        our_label: &str,
+        their_label: &str,
+        blob_mgr: &BlobMgr,
+    ) -> DitResult<Vec<PathBuf>> {
+        let mut unmerged = BTreeMap::new();
+        for (rel_path, conflict) in std::mem::take(&mut merge.conflicts) {
+            match blob_mgr.merge_blobs(&conflict, our_label, their_label)? {
+                Some(line_merge) if line_merge.is_clean() => {
+                    let hash = blob_mgr.create_blob_from_bytes(&line_merge.content)?;
+                    if let Some(entry) = merge.index.files.get_mut(&rel_path) {
+                        entry.hash = hash;
+                    }
+                }
+                line_merge => {
+                    unmerged.insert(rel_path, (conflict, line_merge.map(|m| m.content)));
+                }
+            }
+        }
+
+        // The unmerged files are always rewritten
+        let mut target = merge.index.clone();
+        for rel_path in unmerged.keys() {
+            target.files.insert(rel_path.clone(), IndexEntry::from_tree(String::new()));
+        }
+        self.plan_checkout(head, target, false, mode, blob_mgr)?;
+
+        self.checkout_index(merge.index, blob_mgr)?;
+
+        for (rel_path, (conflict, content)) in unmerged {
+            self.write_unmerged_file(&rel_path, &conflict, content, blob_mgr)?;
+            self.index.conflicts.insert(rel_path, conflict);
+        }
+        self.store()?;
+
+        Ok(self.index.conflicts.keys().cloned().collect())
+    }
+
+    /// Forgets the merge in progress and its unmerged files
+    pub fn finish_merge(&mut self) -> DitResult<()> {
+        self.index.conflicts.clear();
+        self.index.merge = None;
+        self.store()
+    }
+
+    /// Writes an unmerged file to the working tree: the content with conflict markers
+    /// if it was merged line by line. Otherwise, our version is expected to be in
+    /// the working tree already, unless we deleted the file - then their version is
+    /// written for inspection
+    pub fn write_unmerged_file(
+        &self,
+        rel_path: &Path,
+        conflict: &ConflictEntry,
+        content: Option<Vec<u8>>,
+        blob_mgr: &BlobMgr,
+    ) -> DitResult<()> {
+        let abs_path = self.repo.abs_path_from_repo(rel_path, true)?;
+
+        match (content, &conflict.ours, &conflict.theirs) {
+            (Some(content), _, _) => {
+                fs::write(&abs_path, content)?;
+            }
+            (None, None, Some(theirs)) => {
+                create_file_all(&abs_path)?;
+                blob_mgr.recover_blob(theirs.clone(), &abs_path)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
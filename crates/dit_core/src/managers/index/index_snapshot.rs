@@ -0,0 +1,49 @@
+use crate::managers::index::IndexMgr;
+use crate::managers::blob::BlobMgr;
+use crate::models::{Change, Index, IndexEntry, ModifiedFile, UnchangedFile};
+use crate::errors::DitResult;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use rayon::prelude::*;
+
+impl IndexMgr {
+    /// Returns an index describing the current content of the tracked files
+    /// in the working tree. The content of the modified files is stored as blobs,
+    /// the deleted files are left out. Untracked files are not included
+    pub fn snapshot_working_tree(&self, blob_mgr: &BlobMgr) -> DitResult<Index> {
+        let files = self.index.files
+            .par_iter()
+            .filter_map(|(rel_path, entry)| {
+                let snapshot = || -> DitResult<Option<(PathBuf, IndexEntry)>> {
+                    match self._get_untracked_change(rel_path, &Some(entry))? {
+                        Change::Modified(ModifiedFile { hash, fp, .. }) => {
+                            let abs_path = self.repo.abs_path_from_repo(rel_path, true)?;
+                            blob_mgr.create_blob_with_hash(&abs_path, hash.clone())?;
+                            Ok(Some((rel_path.clone(), IndexEntry { hash, fp })))
+                        }
+                        Change::Unchanged(UnchangedFile { hash, fp }) => {
+                            Ok(Some((rel_path.clone(), IndexEntry { hash, fp })))
+                        }
+                        _ => Ok(None),
+                    }
+                };
+                snapshot().transpose()
+            })
+            .collect::<DitResult<BTreeMap<PathBuf, IndexEntry>>>()?;
+
+        Ok(Index { files, ..Default::default() })
+    }
+
+    /// Checks whether any tracked file was modified or deleted in the working tree
+    pub fn are_unstaged_changes(&self) -> DitResult<bool> {
+        for (rel_path, entry) in &self.index.files {
+            match self._get_untracked_change(rel_path, &Some(entry))? {
+                Change::Modified(ModifiedFile { hash, old_hash, .. }) if hash == old_hash => {}
+                Change::Modified(_) | Change::Deleted(_) => return Ok(true),
+                _ => {}
+            }
+        }
+
+        Ok(false)
+    }
+}
@@ -1,6 +1,9 @@
 mod manager;
 mod index_add_remove;
 mod helpers;
+mod index_checkout;
+mod index_merge;
+mod index_snapshot;
 
 pub use manager::IndexMgr;
 
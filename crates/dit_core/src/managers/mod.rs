@@ -5,3 +5,14 @@ pub(crate) mod index;
 pub(crate) mod branch;
 pub(crate) mod ignore;
 pub(crate) mod config;
+pub(crate) mod diff;
+pub(crate) mod tag;
+pub(crate) mod stash;
+pub(crate) mod gc;
+pub(crate) mod fsck;
+pub(crate) mod pack;
+pub(crate) mod remote;
+pub(crate) mod reflog;
+pub(crate) mod oplog;
+pub(crate) mod rebase;
+pub(crate) mod checkout;
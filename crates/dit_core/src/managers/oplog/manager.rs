@@ -0,0 +1,26 @@
+//! This module manages the operation log in the Dit version control system
+//!
+//! Every operation which changes the index, HEAD or the branches (staging, committing,
+//! resetting, switching, creating and removing branches, merging) is recorded in
+//! `.dit/ops/` together with the index, HEAD and the heads of the branches it changed
+//! as they were before it. Undoing the last operation restores them (and checks out the
+//! tracked files again if the operation changed the working tree) and removes it from the log.
+//! Only the latest [`OP_LOG_MAX_ENTRIES`] operations are kept. The objects referenced by
+//! the recorded states are kept by the garbage collection.
+//!
+//! [`OP_LOG_MAX_ENTRIES`]: crate::helpers::OP_LOG_MAX_ENTRIES
+
+use crate::Repo;
+use std::sync::Arc;
+
+/// Manages the operation log. See [`crate::oplog`] for more info
+pub struct OpLogMgr {
+    pub(super) repo: Arc<Repo>,
+}
+
+/// Constructors
+impl OpLogMgr {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { repo }
+    }
+}
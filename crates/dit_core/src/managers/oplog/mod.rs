@@ -0,0 +1,6 @@
+mod manager;
+mod oplog_read;
+mod oplog_record;
+mod oplog_undo;
+
+pub use manager::OpLogMgr;
@@ -0,0 +1,110 @@
+use crate::managers::oplog::OpLogMgr;
+use crate::managers::rebase::RebaseMgr;
+use crate::models::Operation;
+use crate::helpers::DitModel;
+use crate::errors::DitResult;
+use std::path::PathBuf;
+use std::fs;
+
+/// Public
+impl OpLogMgr {
+    /// Returns the recorded operations, the oldest first
+    pub fn list_operations(&self) -> DitResult<Vec<Operation>> {
+        self.list_operation_files()?
+            .into_iter()
+            .map(|(id, path)| {
+                let mut operation = Operation::deserialize_from(&path)?;
+                operation.id = id;
+                Ok(operation)
+            })
+            .collect()
+    }
+
+    /// Returns the most recent operation
+    pub fn last_operation(&self) -> DitResult<Option<Operation>> {
+        match self.list_operation_files()?.pop() {
+            Some((id, path)) => {
+                let mut operation = Operation::deserialize_from(&path)?;
+                operation.id = id;
+                Ok(Some(operation))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the commits referenced by the recorded operations: HEAD and the changed
+    /// branches before and after them, the merges in progress in the recorded indexes
+    /// and the recorded rebases
+    pub fn list_logged_commits(&self) -> DitResult<Vec<String>> {
+        let mut commits = Vec::new();
+        for operation in self.list_operations()? {
+            commits.extend(Self::operation_commits(&operation));
+        }
+
+        Ok(commits)
+    }
+
+    /// Returns the blobs referenced by the recorded indexes, including the unmerged files
+    pub fn list_logged_blobs(&self) -> DitResult<Vec<String>> {
+        let mut blobs = Vec::new();
+        for operation in self.list_operations()? {
+            blobs.extend(Self::operation_blobs(&operation));
+        }
+
+        Ok(blobs)
+    }
+
+    /// Returns the commits referenced by an operation
+    pub fn operation_commits(operation: &Operation) -> Vec<String> {
+        let mut commits = Vec::new();
+        for refs in [&operation.before, &operation.after] {
+            commits.extend(refs.head.iter().cloned());
+            commits.extend(refs.branches.values().flatten().filter(|c| !c.is_empty()).cloned());
+        }
+        if let Some(merge) = &operation.index.merge {
+            commits.push(merge.head.clone());
+        }
+        if let Some(rebase) = &operation.rebase {
+            commits.extend(RebaseMgr::state_commits(rebase));
+        }
+
+        commits
+    }
+
+    /// Returns the blobs referenced by the index recorded in an operation
+    pub fn operation_blobs(operation: &Operation) -> Vec<String> {
+        let index = &operation.index;
+        let mut blobs: Vec<String> = index.files.values().map(|e| e.hash.clone()).collect();
+        for conflict in index.conflicts.values() {
+            blobs.extend([&conflict.base, &conflict.ours, &conflict.theirs].into_iter().flatten().cloned());
+        }
+
+        blobs
+    }
+}
+
+
+/// Private
+impl OpLogMgr {
+    /// Returns the ids and the paths of the operation files sorted by id.
+    /// The files which are not named by an id (e.g. temp files) are skipped
+    pub(super) fn list_operation_files(&self) -> DitResult<Vec<(u64, PathBuf)>> {
+        let mut files = Vec::new();
+        for entry in fs::read_dir(self.repo.ops())? {
+            let entry = entry?;
+            if let Ok(id) = entry.file_name().to_string_lossy().parse::<u64>()
+                && entry.file_type()?.is_file()
+            {
+                files.push((id, entry.path()));
+            }
+        }
+        files.sort();
+
+        Ok(files)
+    }
+
+    /// Returns the path of the operation file with the given id
+    pub(super) fn operation_path(&self, id: u64) -> PathBuf {
+        self.repo.ops().join(format!("{id:010}"))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::managers::oplog::OpLogMgr;
+use crate::models::{Operation, OperationRefs, OperationState, RefsSnapshot};
+use crate::helpers::{remove_file_if_exists, DitModel, OP_LOG_MAX_ENTRIES};
+use crate::errors::{DitResult, OtherError};
+use std::collections::BTreeSet;
+use std::time::SystemTime;
+
+/// Public
+impl OpLogMgr {
+    /// Records an operation given the states of the repository captured before and after it.
+    /// Nothing is recorded if the operation changed neither the references, nor the index,
+    /// nor the rebase in progress.
+    /// The oldest operations are removed so that at most [`OP_LOG_MAX_ENTRIES`] are kept
+    pub fn record<S1, S2>(
+        &self,
+        before: OperationState,
+        after: &OperationState,
+        author: S1,
+        description: S2,
+        worktree: bool,
+    ) -> DitResult<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let OperationState { refs: before, index, rebase } = before;
+        if before == after.refs && index == after.index && rebase == after.rebase {
+            return Ok(());
+        }
+        let after = &after.refs;
+
+        let changed: BTreeSet<&String> = before.branches.keys()
+            .chain(after.branches.keys())
+            .filter(|branch| before.branches.get(*branch) != after.branches.get(*branch))
+            .collect();
+
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|_| OtherError::TimeWentBackwardsError)?
+            .as_secs();
+
+        let operation = Operation {
+            description: description.into(),
+            author: author.into(),
+            timestamp,
+            worktree,
+            before: Self::operation_refs(&before, changed.iter().copied()),
+            after: Self::operation_refs(after, changed.iter().copied()),
+            index,
+            rebase,
+            id: 0,
+        };
+
+        let files = self.list_operation_files()?;
+        let id = files.last().map_or(1, |(id, _)| id + 1);
+        operation.serialize_to(&self.operation_path(id))?;
+
+        let excess = (files.len() + 1).saturating_sub(OP_LOG_MAX_ENTRIES);
+        for (_, path) in files.iter().take(excess) {
+            remove_file_if_exists(path)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Private
+impl OpLogMgr {
+    /// Describes HEAD and the given branches as recorded in an operation
+    pub(super) fn operation_refs<'a, I>(snapshot: &RefsSnapshot, branches: I) -> OperationRefs
+    where
+        I: IntoIterator<Item = &'a String>,
+    {
+        let branches = branches
+            .into_iter()
+            .map(|branch| {
+                let content = snapshot.branches.get(branch)
+                    .map(|commit| commit.clone().unwrap_or_default());
+                (branch.clone(), content)
+            })
+            .collect();
+
+        OperationRefs {
+            branch: snapshot.branch.clone(),
+            head: snapshot.head.clone(),
+            branches,
+        }
+    }
+}
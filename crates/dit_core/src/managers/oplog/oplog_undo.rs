@@ -0,0 +1,62 @@
+use crate::managers::oplog::OpLogMgr;
+use crate::managers::blob::BlobMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::config::ConfigMgr;
+use crate::managers::index::IndexMgr;
+use crate::managers::rebase::RebaseMgr;
+use crate::models::{Operation, RefsSnapshot};
+use crate::helpers::remove_file_if_exists;
+use crate::errors::{DitResult, OpLogError};
+
+/// Public
+impl OpLogMgr {
+    /// Undoes the most recent operation: restores HEAD, the branches it changed, the index
+    /// (and the tracked files, if the operation changed the working tree) and the rebase
+    /// in progress, and removes it from the log. Returns the undone operation
+    ///
+    /// Fails if HEAD or the branches have been moved since the operation (e.g. by a fetch
+    /// or by another process), if the tracked files which would be overwritten were modified,
+    /// or if a branch requiring signed commits would get commits which aren't correctly signed
+    #[allow(clippy::too_many_arguments)]
+    pub fn undo_last(
+        &self,
+        current: &RefsSnapshot,
+        blob_mgr: &BlobMgr,
+        commit_mgr: &CommitMgr,
+        branch_mgr: &mut BranchMgr,
+        index_mgr: &mut IndexMgr,
+        rebase_mgr: &mut RebaseMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<Operation> {
+        let Some(operation) = self.last_operation()? else {
+            return Err(OpLogError::NothingToUndo.into());
+        };
+
+        if Self::operation_refs(current, operation.after.branches.keys()) != operation.after {
+            return Err(OpLogError::RefsChanged(operation.description).into());
+        }
+
+        if operation.worktree && !index_mgr.has_conflicts() && index_mgr.are_unstaged_changes()? {
+            return Err(OpLogError::LocalChanges(operation.description).into());
+        }
+
+        let before = &operation.before;
+        for (branch, commit) in &before.branches {
+            let old = current.branches.get(branch).cloned().flatten();
+            if let Some(commit) = commit
+                && old.as_ref() != Some(commit)
+            {
+                commit_mgr.verify_branch_update(branch, old.as_deref(), commit, config_mgr)?;
+            }
+        }
+
+        branch_mgr.restore_refs(before.branch.as_deref(), before.head.as_deref(), &before.branches)?;
+        index_mgr.replace_index(operation.index.clone(), operation.worktree, blob_mgr)?;
+        rebase_mgr.restore_state(operation.rebase.clone())?;
+
+        remove_file_if_exists(&self.operation_path(operation.id))?;
+
+        Ok(operation)
+    }
+}
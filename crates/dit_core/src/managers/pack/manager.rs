@@ -0,0 +1,27 @@
+//! This module manages the pack files in the Dit version control system
+//!
+//! Every blob, tree and commit is initially stored as its own (loose) file.
+//! Repacking consolidates all the reachable objects into a single pack file,
+//! which is a concatenation of separately compressed objects, and a pack index
+//! mapping the object hashes to their locations in the pack file. The blobs can
+//! additionally be compressed with a zstd dictionary trained on the packed blobs,
+//! which helps a lot with many small similar files. \
+//! The objects are always looked up as loose files first, then in the packs.
+
+use crate::Repo;
+use crate::managers::pack::PackStore;
+use std::sync::Arc;
+
+/// Manages the pack files. See [`crate::pack`] for more info
+pub struct PackMgr {
+    pub(super) repo: Arc<Repo>,
+
+    pub(super) packs: PackStore,
+}
+
+/// Constructors
+impl PackMgr {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { packs: PackStore::from(repo.clone()), repo }
+    }
+}
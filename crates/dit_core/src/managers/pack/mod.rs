@@ -0,0 +1,6 @@
+mod manager;
+mod pack_store;
+mod pack_write;
+
+pub use manager::PackMgr;
+pub use pack_store::PackStore;
@@ -0,0 +1,164 @@
+use crate::Repo;
+use crate::api_models::object::ObjectKind;
+use crate::models::{PackEntry, PackIndex, PackSpan};
+use crate::helpers::{DitModel, PACK_FILE_EXTENSION, PACK_INDEX_EXTENSION, PACK_MAGIC};
+use crate::errors::{DitResult, PackError};
+use once_cell::sync::OnceCell;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::fs;
+use zstd::stream::read::Decoder;
+
+/// Represents a pack file with its index
+pub struct Pack {
+    /// Represents the pack name (the hash of the pack file)
+    pub name: String,
+
+    pub index: PackIndex,
+
+    /// Represents the zstd dictionary the blobs were compressed with (if any)
+    dictionary: Option<Vec<u8>>,
+}
+
+/// Provides read access to the packed objects. \
+/// The pack indexes are loaded on the first access
+pub struct PackStore {
+    repo: Arc<Repo>,
+
+    packs: OnceCell<Vec<Pack>>,
+}
+
+/// Constructors
+impl PackStore {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { repo, packs: OnceCell::new() }
+    }
+}
+
+
+/// Public
+impl PackStore {
+    /// Returns all the packs sorted by name
+    pub fn packs(&self) -> DitResult<&[Pack]> {
+        let packs = self.packs.get_or_try_init(|| self.load_packs())?;
+        Ok(packs)
+    }
+
+    /// Returns the pack containing the object and its entry, or `None`
+    /// if the object is not packed
+    pub fn find(&self, hash: &str) -> DitResult<Option<(&Pack, &PackEntry)>> {
+        for pack in self.packs()? {
+            if let Some(entry) = pack.index.entries.get(hash) {
+                return Ok(Some((pack, entry)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Checks whether the object is packed
+    pub fn contains(&self, hash: &str) -> DitResult<bool> {
+        Ok(self.find(hash)?.is_some())
+    }
+
+    /// Returns the hashes of all the packed objects of the given kind
+    pub fn hashes(&self, kind: ObjectKind) -> DitResult<Vec<&String>> {
+        Ok(self.packs()?
+            .iter()
+            .flat_map(|pack| &pack.index.entries)
+            .filter(|(_, entry)| entry.kind == kind)
+            .map(|(hash, _)| hash)
+            .collect())
+    }
+
+    /// Reads and decompresses a packed object.
+    /// Returns `None` if the object is not packed
+    pub fn read_object(&self, hash: &str) -> DitResult<Option<Vec<u8>>> {
+        let mut content = Vec::new();
+        if self.read_object_to(hash, &mut content)? {
+            Ok(Some(content))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Decompresses a packed object into the writer.
+    /// Returns `false` if the object is not packed
+    pub fn read_object_to<W: Write>(&self, hash: &str, writer: &mut W) -> DitResult<bool> {
+        let Some((pack, entry)) = self.find(hash)? else {
+            return Ok(false);
+        };
+
+        let dictionary = match entry.kind {
+            ObjectKind::Blob => pack.dictionary.as_deref().unwrap_or_default(),
+            _ => &[],
+        };
+
+        let reader = self.open_span(&pack.name, entry.span)?;
+        let mut decoder = Decoder::with_dictionary(BufReader::new(reader), dictionary)?;
+        io::copy(&mut decoder, writer)?;
+
+        Ok(true)
+    }
+
+    /// Returns the path of the pack file given its name
+    pub fn pack_path(&self, name: &str) -> PathBuf {
+        self.repo.packs().join(name).with_extension(PACK_FILE_EXTENSION)
+    }
+
+    /// Returns the path of the pack index given the pack name
+    pub fn index_path(&self, name: &str) -> PathBuf {
+        self.repo.packs().join(name).with_extension(PACK_INDEX_EXTENSION)
+    }
+}
+
+
+/// Private
+impl PackStore {
+    /// Loads the indexes (and the dictionaries) of all the packs.
+    /// A pack is only visible once its index is written
+    fn load_packs(&self) -> DitResult<Vec<Pack>> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(self.repo.packs())? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| e == PACK_INDEX_EXTENSION)
+                && let Some(name) = path.file_stem()
+            {
+                names.push(name.to_string_lossy().to_string());
+            }
+        }
+        names.sort();
+
+        names.into_iter()
+            .map(|name| {
+                let index = PackIndex::deserialize_from(&self.index_path(&name))?;
+                let dictionary = match index.dictionary {
+                    Some(span) => {
+                        let mut dictionary = Vec::new();
+                        self.open_span(&name, span)?.read_to_end(&mut dictionary)?;
+                        Some(dictionary)
+                    }
+                    None => None,
+                };
+
+                Ok(Pack { name, index, dictionary })
+            })
+            .collect()
+    }
+
+    /// Opens the pack file and returns a reader limited to the given span
+    fn open_span(&self, name: &str, span: PackSpan) -> DitResult<io::Take<File>> {
+        let mut file = File::open(self.pack_path(name))?;
+
+        let mut magic = [0; PACK_MAGIC.len()];
+        file.read_exact(&mut magic)?;
+        if magic != PACK_MAGIC {
+            return Err(PackError::CorruptPack(name.to_string(), String::from("invalid header")).into());
+        }
+
+        file.seek(SeekFrom::Start(span.offset))?;
+        Ok(file.take(span.length))
+    }
+}
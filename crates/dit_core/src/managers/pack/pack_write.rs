@@ -0,0 +1,207 @@
+use crate::managers::pack::PackMgr;
+use crate::managers::blob::BlobMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::object::ObjectKind;
+use crate::api_models::pack::RepackReport;
+use crate::models::{PackEntry, PackIndex, PackSpan, ReachableObjects};
+use crate::helpers::{
+    create_temp_file, remove_file_if_exists, DitModel, HashingWriter,
+    PACK_DICTIONARY_MAX_SAMPLES, PACK_DICTIONARY_MAX_SAMPLE_SIZE,
+    PACK_DICTIONARY_MAX_SIZE, PACK_MAGIC, ZSTD_COMPRESSION_LEVEL,
+};
+use crate::errors::DitResult;
+use std::collections::HashSet;
+use std::io::{BufWriter, Write};
+use std::fs;
+use zstd::bulk::Compressor;
+
+/// Public
+impl PackMgr {
+    /// Writes all the reachable objects into a new pack and removes the old packs
+    /// and the loose copies of the packed objects.
+    ///
+    /// The unreachable objects found in the old packs are written back as loose
+    /// objects, so that they are removed by the garbage collection once the grace
+    /// period passes. The unreachable loose objects are left untouched
+    pub fn repack(
+        &mut self,
+        reachable: &ReachableObjects,
+        use_dictionary: bool,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+    ) -> DitResult<RepackReport> {
+        let mut report = RepackReport::default();
+
+        let mut objects = Vec::new();
+        for (kind, hashes) in [
+            (ObjectKind::Commit, &reachable.commits),
+            (ObjectKind::Tree, &reachable.trees),
+            (ObjectKind::Blob, &reachable.blobs),
+        ] {
+            let mut hashes: Vec<&String> = hashes.iter().collect();
+            hashes.sort();
+            objects.extend(hashes.into_iter().map(|hash| (kind, hash.as_str())));
+        }
+
+        let dictionary = match use_dictionary {
+            true => self.train_dictionary(&reachable.blobs, blob_mgr)?,
+            false => None,
+        };
+
+        if !objects.is_empty() {
+            let name = self.write_pack(&objects, dictionary.as_deref(), blob_mgr, tree_mgr, commit_mgr)?;
+            report.size = fs::metadata(self.packs.pack_path(&name))?.len();
+            report.dictionary = dictionary.is_some();
+            report.packed = objects.len();
+            report.pack = Some(name);
+        }
+
+        report.loosened = self.loosen_unreachable(reachable, blob_mgr, tree_mgr, commit_mgr)?;
+
+        for pack in self.packs.packs()? {
+            if report.pack.as_ref() != Some(&pack.name) {
+                remove_file_if_exists(&self.packs.index_path(&pack.name))?;
+                remove_file_if_exists(&self.packs.pack_path(&pack.name))?;
+                report.removed_packs += 1;
+            }
+        }
+
+        if report.pack.is_some() {
+            for (kind, hash) in objects {
+                let path = match kind {
+                    ObjectKind::Commit => self.repo.commits().join(hash),
+                    ObjectKind::Tree => self.repo.trees().join(hash),
+                    _ => blob_mgr.get_blob_path(hash.to_string()),
+                };
+                if path.is_file() {
+                    fs::remove_file(&path)?;
+                    report.removed_loose += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+
+/// Private
+impl PackMgr {
+    /// Writes the objects to a new pack file followed by its index.
+    /// Returns the pack name
+    fn write_pack(
+        &self,
+        objects: &[(ObjectKind, &str)],
+        dictionary: Option<&[u8]>,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+    ) -> DitResult<String> {
+        let (file, temp_path) = create_temp_file(self.repo.packs())?;
+        let mut writer = HashingWriter::new(BufWriter::new(file));
+        let mut offset = 0;
+        let mut index = PackIndex::default();
+
+        let mut append = |writer: &mut HashingWriter<_>, content: &[u8]| -> DitResult<PackSpan> {
+            writer.write_all(content)?;
+            let span = PackSpan { offset, length: content.len() as u64 };
+            offset += span.length;
+            Ok(span)
+        };
+
+        append(&mut writer, PACK_MAGIC)?;
+        if let Some(dictionary) = dictionary {
+            index.dictionary = Some(append(&mut writer, dictionary)?);
+        }
+
+        let level = ZSTD_COMPRESSION_LEVEL as i32;
+        let mut compressor = Compressor::new(level)?;
+        let mut blob_compressor = match dictionary {
+            Some(dictionary) => Compressor::with_dictionary(level, dictionary)?,
+            None => Compressor::new(level)?,
+        };
+
+        for &(kind, hash) in objects {
+            let compressed = match kind {
+                ObjectKind::Commit => compressor.compress(&commit_mgr.read_commit_bytes(hash)?)?,
+                ObjectKind::Tree => compressor.compress(&tree_mgr.read_tree_bytes(hash)?)?,
+                _ => blob_compressor.compress(&blob_mgr.read_blob(hash.to_string())?)?,
+            };
+
+            let span = append(&mut writer, &compressed)?;
+            index.entries.insert(hash.to_string(), PackEntry { kind, span });
+        }
+
+        writer.flush()?;
+        let name = writer.finalize_string();
+
+        fs::rename(&temp_path, self.packs.pack_path(&name))?;
+
+        // The index is written last, the pack isn't visible until then
+        let (_, temp_index_path) = create_temp_file(self.repo.packs())?;
+        index.serialize_to(&temp_index_path)?;
+        fs::rename(&temp_index_path, self.packs.index_path(&name))?;
+
+        Ok(name)
+    }
+
+    /// Trains a zstd dictionary on a sample of the blobs.
+    /// Returns `None` if there are too few blobs to train on
+    fn train_dictionary(&self, blobs: &HashSet<String>, blob_mgr: &BlobMgr) -> DitResult<Option<Vec<u8>>> {
+        let mut hashes: Vec<&String> = blobs.iter().collect();
+        hashes.sort();
+
+        let mut samples = Vec::new();
+        for hash in hashes {
+            if samples.len() >= PACK_DICTIONARY_MAX_SAMPLES {
+                break;
+            }
+
+            let content = blob_mgr.read_blob(hash.clone())?;
+            if (content.len() as u64) <= PACK_DICTIONARY_MAX_SAMPLE_SIZE {
+                samples.push(content);
+            }
+        }
+
+        // Training fails if the samples are too few or too small
+        Ok(zstd::dict::from_samples(&samples, PACK_DICTIONARY_MAX_SIZE).ok())
+    }
+
+    /// Writes the unreachable objects of the existing packs as loose objects.
+    /// Returns the number of the written objects
+    fn loosen_unreachable(
+        &self,
+        reachable: &ReachableObjects,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+    ) -> DitResult<usize> {
+        let mut loosened = 0;
+
+        for pack in self.packs.packs()? {
+            for (hash, entry) in &pack.index.entries {
+                let (is_reachable, path) = match entry.kind {
+                    ObjectKind::Commit => (reachable.commits.contains(hash), self.repo.commits().join(hash)),
+                    ObjectKind::Tree => (reachable.trees.contains(hash), self.repo.trees().join(hash)),
+                    _ => (reachable.blobs.contains(hash), blob_mgr.get_blob_path(hash.clone())),
+                };
+                if is_reachable || path.is_file() {
+                    continue;
+                }
+
+                match entry.kind {
+                    ObjectKind::Commit => commit_mgr.write_commit(&commit_mgr.get_commit(hash)?)?,
+                    ObjectKind::Tree => tree_mgr.write_tree(&tree_mgr.get_tree(hash.clone())?)?,
+                    _ => {
+                        blob_mgr.create_blob_from_bytes(&blob_mgr.read_blob(hash.clone())?)?;
+                    }
+                }
+                loosened += 1;
+            }
+        }
+
+        Ok(loosened)
+    }
+}
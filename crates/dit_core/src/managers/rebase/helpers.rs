@@ -0,0 +1,59 @@
+use crate::managers::rebase::RebaseMgr;
+use crate::models::RebaseState;
+use crate::helpers::{remove_file_if_exists, DitModel};
+use crate::errors::{DitResult, RebaseError};
+
+/// Manage the rebase file
+impl RebaseMgr {
+    /// Loads the rebase in progress (if any) from the rebase file
+    pub(super) fn load(&mut self) -> DitResult<()> {
+        let path = self.repo.rebase_file();
+        self.state = match path.is_file() {
+            true => Some(RebaseState::deserialize_from(path)?),
+            false => None,
+        };
+        Ok(())
+    }
+
+    /// Updates the rebase file based on the current state. The file is removed
+    /// when there is no rebase in progress
+    pub(super) fn store(&self) -> DitResult<()> {
+        match &self.state {
+            Some(state) => state.serialize_to(self.repo.rebase_file()),
+            None => remove_file_if_exists(self.repo.rebase_file()),
+        }
+    }
+
+    /// Replaces the rebase in progress, e.g. with the one saved before an operation which is undone
+    pub fn restore_state(&mut self, state: Option<RebaseState>) -> DitResult<()> {
+        self.state = state;
+        self.store()
+    }
+}
+
+
+/// Getters
+impl RebaseMgr {
+    /// Returns the rebase in progress
+    pub fn state(&self) -> Option<&RebaseState> {
+        self.state.as_ref()
+    }
+
+    /// Returns the rebase in progress, or an error if there is none
+    pub(super) fn require_state(&self) -> DitResult<&RebaseState> {
+        self.state.as_ref().ok_or_else(|| RebaseError::NoRebaseInProgress.into())
+    }
+
+    /// Returns the commits referenced by the rebase in progress
+    pub fn list_commits(&self) -> Vec<String> {
+        self.state.as_ref().map(Self::state_commits).unwrap_or_default()
+    }
+
+    /// Returns the commits referenced by a rebase state
+    pub fn state_commits(state: &RebaseState) -> Vec<String> {
+        let mut commits = vec![state.onto.clone(), state.orig_head.clone()];
+        commits.extend(state.todo.iter().cloned());
+        commits.extend(state.current.iter().cloned());
+        commits
+    }
+}
@@ -0,0 +1,33 @@
+//! This module manages rebasing in the Dit version control system
+//!
+//! Rebasing replays the commits of the current branch which are not in the upstream
+//! on top of the upstream, one by one, as new commits (merge commits are left out).
+//! While the commits are replayed, HEAD is detached and points to the latest replayed
+//! commit, and the state of the rebase is stored in `.dit/rebase`. If a commit doesn't
+//! apply cleanly, the rebase stops with the conflicts recorded in the index, and can be
+//! continued once they are resolved, or the commit can be skipped, or the whole rebase
+//! aborted. The branch is only moved once all the commits are replayed.
+
+use crate::Repo;
+use crate::errors::DitResult;
+use crate::models::RebaseState;
+use std::sync::Arc;
+
+/// Manages rebasing. See [`crate::rebase`] for more info
+pub struct RebaseMgr {
+    pub(super) repo: Arc<Repo>,
+
+    pub(super) state: Option<RebaseState>,
+}
+
+/// Constructors
+impl RebaseMgr {
+    pub fn from(repo: Arc<Repo>) -> DitResult<Self> {
+        let mut mgr = Self {
+            repo,
+            state: None,
+        };
+        Self::load(&mut mgr)?;
+        Ok(mgr)
+    }
+}
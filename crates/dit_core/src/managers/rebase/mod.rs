@@ -0,0 +1,6 @@
+mod manager;
+mod helpers;
+mod rebase_start;
+mod rebase_continue;
+
+pub use manager::RebaseMgr;
@@ -0,0 +1,192 @@
+use crate::managers::rebase::RebaseMgr;
+use crate::managers::blob::BlobMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::config::ConfigMgr;
+use crate::managers::index::IndexMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::checkout::CheckoutMode;
+use crate::api_models::rebase::RebaseResult;
+use crate::helpers::path_to_string;
+use crate::errors::{DitResult, RebaseError};
+use std::collections::BTreeMap;
+
+/// Public
+impl RebaseMgr {
+    /// Continues the rebase which stopped because of conflicts. The resolved changes
+    /// (if any) are committed with the message and the author of the replayed commit,
+    /// then the remaining commits are replayed
+    pub fn continue_rebase(
+        &mut self,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &mut CommitMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<RebaseResult> {
+        let current = self.require_state()?.current.clone();
+
+        if index_mgr.has_conflicts() {
+            let conflicts = index_mgr.index().conflicts.keys()
+                .map(|p| path_to_string(p))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(RebaseError::UnresolvedConflicts(conflicts).into());
+        }
+
+        if index_mgr.are_unstaged_changes()? {
+            return Err(RebaseError::LocalChanges.into());
+        }
+
+        if let Some(current) = current {
+            self.commit_replayed(&current, tree_mgr, commit_mgr, index_mgr, branch_mgr, config_mgr)?;
+            self.set_current(None)?;
+        }
+
+        self.replay_todo(blob_mgr, tree_mgr, commit_mgr, index_mgr, branch_mgr, config_mgr)
+    }
+
+    /// Skips the commit whose replay stopped because of conflicts: the working tree
+    /// and the index are reset to the latest replayed commit, then the remaining commits
+    /// are replayed
+    pub fn skip_commit(
+        &mut self,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &mut CommitMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<RebaseResult> {
+        self.require_state()?;
+
+        let head_tree = branch_mgr.get_head_tree(tree_mgr, commit_mgr)?.unwrap_or_default();
+        index_mgr.replace_index(head_tree, true, blob_mgr)?;
+        self.set_current(None)?;
+
+        self.replay_todo(blob_mgr, tree_mgr, commit_mgr, index_mgr, branch_mgr, config_mgr)
+    }
+
+    /// Aborts the rebase: the working tree, the index and HEAD are reset to the state
+    /// before the rebase. The branch was not moved by the rebase, so it's left as is
+    pub fn abort_rebase(
+        &mut self,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+    ) -> DitResult<()> {
+        let state = self.require_state()?.clone();
+
+        let orig_tree = commit_mgr.get_commit_tree(&state.orig_head, tree_mgr)?;
+        index_mgr.replace_index(orig_tree, true, blob_mgr)?;
+        branch_mgr.restore_refs(Some(&state.branch), Some(&state.orig_head), &BTreeMap::new())?;
+
+        self.restore_state(None)
+    }
+}
+
+
+/// Private
+impl RebaseMgr {
+    /// Replays the remaining commits on top of HEAD. Stops at the first commit which
+    /// conflicts, otherwise points the branch to the last replayed commit and
+    /// concludes the rebase
+    pub(super) fn replay_todo(
+        &mut self,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &mut CommitMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<RebaseResult> {
+        let state = self.require_state()?.clone();
+
+        for (position, hash) in state.todo.iter().enumerate() {
+            let commit = commit_mgr.get_commit(hash)?;
+            let base_tree = match commit.parents.first() {
+                Some(parent) => Some(commit_mgr.get_commit(parent)?.tree),
+                None => None,
+            };
+            let head_tree = match branch_mgr.get_head_commit() {
+                Some(head) => Some(commit_mgr.get_commit(head)?.tree),
+                None => None,
+            };
+
+            let merge = tree_mgr.merge_trees(base_tree, head_tree, Some(commit.tree))?;
+            let head_index = branch_mgr.get_head_tree(tree_mgr, commit_mgr)?;
+            let unmerged = index_mgr.apply_tree_merge(
+                merge, head_index, CheckoutMode::Safe, &state.upstream, &state.branch, blob_mgr
+            )?;
+
+            if let Some(current) = &mut self.state {
+                current.todo = state.todo[position + 1..].to_vec();
+            }
+
+            if !unmerged.is_empty() {
+                self.set_current(Some(hash.clone()))?;
+                return Ok(RebaseResult::Conflicted { commit: hash.clone(), paths: unmerged });
+            }
+
+            self.commit_replayed(hash, tree_mgr, commit_mgr, index_mgr, branch_mgr, config_mgr)?;
+            self.store()?;
+        }
+
+        let head = branch_mgr.get_head_commit().cloned().unwrap_or_default();
+        branch_mgr.set_current_branch(&state.branch)?;
+        self.restore_state(None)?;
+
+        Ok(RebaseResult::Rebased(head))
+    }
+
+    /// Commits the index on top of HEAD with the message and the author of the replayed
+    /// commit, the configured user being the committer. Nothing is committed if the index
+    /// doesn't differ from HEAD, e.g. when the changes of the commit are already in the upstream
+    fn commit_replayed(
+        &self,
+        original: &str,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &mut CommitMgr,
+        index_mgr: &IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<()> {
+        let head = branch_mgr.get_head_commit().cloned();
+        let head_tree = match &head {
+            Some(head) => Some(commit_mgr.get_commit(head)?.tree),
+            None => None,
+        };
+
+        let index = index_mgr.index().clone();
+        if let Some(head_tree) = &head_tree
+            && tree_mgr.matches_index(head_tree, &index)?
+        {
+            return Ok(());
+        }
+
+        let original = commit_mgr.get_commit(original)?;
+        let committer = CommitMgr::committer_now(&original.author, config_mgr)?;
+        let tree_hash = tree_mgr.create_tree(index)?;
+        let commit_hash = commit_mgr.create_commit_from_tree(
+            original.message,
+            original.author,
+            committer,
+            tree_hash,
+            head.into_iter().collect(),
+            config_mgr,
+        )?;
+
+        branch_mgr.set_head_commit(commit_hash)
+    }
+
+    /// Records the commit whose replay stopped because of conflicts
+    fn set_current(&mut self, current: Option<String>) -> DitResult<()> {
+        if let Some(state) = &mut self.state {
+            state.current = current;
+        }
+        self.store()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::managers::rebase::RebaseMgr;
+use crate::managers::blob::BlobMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::config::ConfigMgr;
+use crate::managers::index::IndexMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::checkout::CheckoutMode;
+use crate::api_models::rebase::RebaseResult;
+use crate::models::RebaseState;
+use crate::errors::{DitResult, RebaseError};
+
+/// Public
+impl RebaseMgr {
+    /// Rebases the current branch onto the given commit. `upstream` is the revision
+    /// it was resolved from, used to refer to it in the conflict markers.
+    ///
+    /// The commits of the branch which are not reachable from `onto` are replayed on top
+    /// of it until all of them are replayed or one of them conflicts
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_rebase<S: Into<String>>(
+        &mut self,
+        upstream: S,
+        onto: String,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &mut CommitMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &mut BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<RebaseResult> {
+        if let Some(state) = &self.state {
+            return Err(RebaseError::RebaseInProgress(state.branch.clone()).into());
+        }
+
+        let branch = branch_mgr.get_current_branch().cloned()
+            .ok_or(RebaseError::DetachedHead)?;
+
+        if index_mgr.pending_merge().is_some() || index_mgr.has_conflicts() {
+            return Err(RebaseError::MergeInProgress.into());
+        }
+
+        if index_mgr.are_tracked_changes(tree_mgr, commit_mgr, branch_mgr)?
+            || index_mgr.are_unstaged_changes()?
+        {
+            return Err(RebaseError::LocalChanges.into());
+        }
+
+        let head = branch_mgr.get_head_commit().cloned();
+
+        if let Some(head) = &head
+            && commit_mgr.is_ancestor(&onto, head)?
+        {
+            return Ok(RebaseResult::UpToDate);
+        }
+
+        // The branch has no commits of its own, simply move it to the upstream
+        let head = match head {
+            Some(head) if !commit_mgr.is_ancestor(&head, &onto)? => head,
+            _ => {
+                let onto_tree = commit_mgr.get_commit_tree(&onto, tree_mgr)?;
+                let head_tree = branch_mgr.get_head_tree(tree_mgr, commit_mgr)?;
+                index_mgr.plan_checkout(head_tree, onto_tree.clone(), false, CheckoutMode::Safe, blob_mgr)?;
+
+                index_mgr.checkout_index(onto_tree, blob_mgr)?;
+                branch_mgr.set_head_commit(&onto)?;
+                return Ok(RebaseResult::FastForward(onto));
+            }
+        };
+
+        let todo = commit_mgr.list_commits_to_replay(&head, &onto)?;
+
+        // Nothing is changed if checking out the upstream would overwrite untracked files
+        let onto_tree = commit_mgr.get_commit_tree(&onto, tree_mgr)?;
+        let head_tree = branch_mgr.get_head_tree(tree_mgr, commit_mgr)?;
+        index_mgr.plan_checkout(head_tree, onto_tree.clone(), false, CheckoutMode::Safe, blob_mgr)?;
+
+        self.state = Some(RebaseState {
+            branch,
+            upstream: upstream.into(),
+            onto: onto.clone(),
+            orig_head: head,
+            todo,
+            current: None,
+        });
+        self.store()?;
+
+        index_mgr.checkout_index(onto_tree, blob_mgr)?;
+        branch_mgr.detach_head(onto)?;
+
+        self.replay_todo(blob_mgr, tree_mgr, commit_mgr, index_mgr, branch_mgr, config_mgr)
+    }
+}
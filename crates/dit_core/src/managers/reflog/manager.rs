@@ -0,0 +1,25 @@
+//! This module manages the reflog in the Dit version control system
+//!
+//! The reflog records every movement of HEAD (`.dit/logs/HEAD`) and of every branch head
+//! (`.dit/logs/branches/<branch>`): the old and the new commit, who moved the reference, when,
+//! and by which operation. The logs are only appended to, so the commits which are no longer
+//! referenced (e.g. after a hard reset) can be found and referenced as `HEAD@{n}`/`<branch>@{n}`.
+//! The commits in the reflog are kept by the garbage collection.
+
+use crate::Repo;
+use std::sync::Arc;
+
+/// Name of the reference which refers to the current commit
+pub(super) const HEAD_REF: &str = "HEAD";
+
+/// Manages the reflog. See [`crate::reflog`] for more info
+pub struct ReflogMgr {
+    pub(super) repo: Arc<Repo>,
+}
+
+/// Constructors
+impl ReflogMgr {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { repo }
+    }
+}
@@ -0,0 +1,5 @@
+mod manager;
+mod reflog_read;
+mod reflog_write;
+
+pub use manager::ReflogMgr;
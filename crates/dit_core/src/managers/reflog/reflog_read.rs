@@ -0,0 +1,82 @@
+use crate::managers::reflog::ReflogMgr;
+use crate::managers::reflog::manager::HEAD_REF;
+use crate::managers::branch::BranchMgr;
+use crate::models::ReflogEntry;
+use crate::helpers::{is_temp_file_name, read_to_string};
+use crate::errors::{DitResult, ReflogError};
+use std::path::PathBuf;
+use std::fs;
+
+/// Public
+impl ReflogMgr {
+    /// Returns the reflog of HEAD or of a branch, the oldest entry first.
+    /// The reflog of a reference which has never moved is empty. An entry
+    /// torn by an interrupted append (the last line without a line break) is ignored
+    pub fn read_log(&self, name: &str) -> DitResult<Vec<ReflogEntry>> {
+        let path = self.log_path(name)?;
+        if !path.is_file() {
+            return Ok(Vec::new());
+        }
+
+        let content = read_to_string(&path)?;
+        let complete = content.rfind('\n').map_or("", |end| &content[..=end]);
+
+        complete
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(number, line)| {
+                serde_json::from_str(line)
+                    .map_err(|_| ReflogError::CorruptReflog(name.to_string(), number + 1).into())
+            })
+            .collect()
+    }
+
+    /// Returns the names of the references which have a reflog: `HEAD` and the branches
+    pub fn list_logs(&self) -> DitResult<Vec<String>> {
+        let mut names = vec![HEAD_REF.to_string()];
+
+        let mut branches = Vec::new();
+        for entry in fs::read_dir(self.repo.branch_logs())? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type()?.is_file() && !is_temp_file_name(&name) {
+                branches.push(name);
+            }
+        }
+        branches.sort();
+        names.extend(branches);
+
+        Ok(names)
+    }
+
+    /// Returns all the commits recorded in the reflogs
+    pub fn list_logged_commits(&self) -> DitResult<Vec<String>> {
+        let mut commits = Vec::new();
+        for name in self.list_logs()? {
+            for entry in self.read_log(&name)? {
+                commits.extend(entry.old);
+                commits.push(entry.new);
+            }
+        }
+        commits.sort();
+        commits.dedup();
+
+        Ok(commits)
+    }
+}
+
+
+/// Private
+impl ReflogMgr {
+    /// Returns the path of the reflog of HEAD or of a branch
+    pub(super) fn log_path(&self, name: &str) -> DitResult<PathBuf> {
+        if name == HEAD_REF {
+            Ok(self.repo.head_log_file().to_path_buf())
+        } else if BranchMgr::is_valid_branch_name(name) {
+            Ok(self.repo.branch_logs().join(name))
+        } else {
+            Err(ReflogError::InvalidRefName(name.to_string()).into())
+        }
+    }
+}
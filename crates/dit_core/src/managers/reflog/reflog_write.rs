@@ -0,0 +1,129 @@
+use crate::managers::reflog::ReflogMgr;
+use crate::managers::reflog::manager::HEAD_REF;
+use crate::managers::branch::BranchMgr;
+use crate::models::{RefsSnapshot, ReflogEntry};
+use crate::helpers::remove_file_if_exists;
+use crate::errors::{DitResult, OtherError};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::time::SystemTime;
+
+/// Public
+impl ReflogMgr {
+    /// Returns the commits HEAD and the branches currently point to
+    pub fn snapshot(&self, branch_mgr: &BranchMgr) -> DitResult<RefsSnapshot> {
+        let mut snapshot = RefsSnapshot {
+            branch: branch_mgr.get_current_branch().cloned(),
+            head: branch_mgr.get_head_commit().cloned(),
+            ..Default::default()
+        };
+
+        for branch in branch_mgr.list_branches()? {
+            let commit = branch_mgr.get_branch_head(&branch)?;
+            snapshot.branches.insert(branch, commit);
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Compares the snapshots of the references taken before and after an operation and
+    /// records the ones which have moved. HEAD is also recorded when it was switched to another
+    /// branch pointing to the same commit. The reflogs of the removed branches are removed
+    pub fn record_changes<S1, S2>(
+        &self,
+        before: &RefsSnapshot,
+        after: &RefsSnapshot,
+        author: S1,
+        message: S2,
+    ) -> DitResult<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let author = author.into();
+        let message = message.into();
+
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|_| OtherError::TimeWentBackwardsError)?
+            .as_secs();
+
+        let entry = |old: Option<&String>, new: &String| ReflogEntry {
+            old: old.cloned(),
+            new: new.clone(),
+            author: author.clone(),
+            timestamp,
+            message: message.clone(),
+        };
+
+        if let Some(head) = &after.head
+            && (before.head != after.head || before.branch != after.branch)
+        {
+            self.append(HEAD_REF, entry(before.head.as_ref(), head))?;
+        }
+
+        for (branch, commit) in &after.branches {
+            let old = before.branches.get(branch).and_then(Option::as_ref);
+            if let Some(commit) = commit
+                && old != Some(commit)
+            {
+                self.append(branch, entry(old, commit))?;
+            }
+        }
+
+        for branch in before.branches.keys() {
+            if !after.branches.contains_key(branch) {
+                self.remove_log(branch)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the reflog of a branch
+    pub fn remove_log(&self, branch: &str) -> DitResult<()> {
+        remove_file_if_exists(&self.log_path(branch)?)
+    }
+}
+
+
+/// Private
+impl ReflogMgr {
+    /// Appends an entry to the reflog of HEAD or of a branch. An entry torn by
+    /// an interrupted append is removed first, so that the new one starts on a fresh line
+    fn append(&self, name: &str, entry: ReflogEntry) -> DitResult<()> {
+        let path = self.log_path(name)?;
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(&path)?;
+
+        if file.metadata()?.len() > 0 && !Self::ends_with_line_break(&mut file)? {
+            let mut content = Vec::new();
+            file.rewind()?;
+            file.read_to_end(&mut content)?;
+            let end = content.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+            file.set_len(end as u64)?;
+        }
+
+        file.seek(SeekFrom::End(0))?;
+        file.write_all(line.as_bytes())?;
+        file.sync_all()?;
+
+        Ok(())
+    }
+
+    /// Checks whether the last byte of a non-empty file is a line break
+    fn ends_with_line_break(file: &mut File) -> DitResult<bool> {
+        let mut last = [0u8; 1];
+        file.seek(SeekFrom::End(-1))?;
+        file.read_exact(&mut last)?;
+
+        Ok(last[0] == b'\n')
+    }
+}
@@ -0,0 +1,118 @@
+use crate::managers::remote::RemoteMgr;
+use crate::managers::branch::BranchMgr;
+use crate::api_models::remote::RefUpdate;
+use crate::helpers::{is_temp_file_name, read_to_string, write_atomic};
+use crate::errors::{BranchError, DitResult};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::fs;
+
+/// Remote-tracking branches
+impl RemoteMgr {
+    /// Returns the head commit of the remote branch as of the last fetch or push
+    pub fn get_tracking_head(&self, remote: &str, branch: &str) -> DitResult<Option<String>> {
+        if !Self::is_valid_remote_name(remote) || !BranchMgr::is_valid_branch_name(branch) {
+            return Ok(None);
+        }
+
+        let path = self.tracking_path(remote, branch);
+        if path.is_file() {
+            Ok(Some(read_to_string(&path)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the remote branch names of a remote mapped to their head commits
+    pub fn list_tracking_heads(&self, remote: &str) -> DitResult<BTreeMap<String, String>> {
+        let mut heads = BTreeMap::new();
+
+        let dir = self.repo.remotes().join(remote);
+        if !dir.is_dir() {
+            return Ok(heads);
+        }
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let branch = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type()?.is_file() && !is_temp_file_name(&branch) {
+                heads.insert(branch, read_to_string(&entry.path())?);
+            }
+        }
+
+        Ok(heads)
+    }
+
+    /// Returns the head commits of all the remote-tracking branches
+    /// mapped by `<remote>/<branch>`
+    pub fn list_all_tracking_heads(&self) -> DitResult<BTreeMap<String, String>> {
+        let mut heads = BTreeMap::new();
+
+        for entry in fs::read_dir(self.repo.remotes())? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                let remote = entry.file_name().to_string_lossy().to_string();
+                for (branch, commit) in self.list_tracking_heads(&remote)? {
+                    heads.insert(format!("{remote}/{branch}"), commit);
+                }
+            }
+        }
+
+        Ok(heads)
+    }
+
+    /// Checks whether the name can be used as a remote name
+    pub fn is_valid_remote_name(name: &str) -> bool {
+        BranchMgr::is_valid_branch_name(name)
+    }
+}
+
+
+/// Private
+impl RemoteMgr {
+    /// Records the head of a single remote branch
+    pub(super) fn set_tracking_head(&self, remote: &str, branch: &str, commit: &str) -> DitResult<()> {
+        if !BranchMgr::is_valid_branch_name(branch) {
+            return Err(BranchError::InvalidBranchName(branch.to_string()).into());
+        }
+
+        fs::create_dir_all(self.repo.remotes().join(remote))?;
+        write_atomic(self.tracking_path(remote, branch), commit)?;
+        Ok(())
+    }
+
+    /// Replaces the remote-tracking branches of a remote with the given heads.
+    /// Returns the changed branches
+    pub(super) fn store_tracking_heads(
+        &self,
+        remote: &str,
+        heads: &BTreeMap<String, String>,
+    ) -> DitResult<Vec<RefUpdate>> {
+        let current = self.list_tracking_heads(remote)?;
+        let mut updates = Vec::new();
+
+        for (branch, commit) in heads {
+            if current.get(branch) != Some(commit) {
+                self.set_tracking_head(remote, branch, commit)?;
+                updates.push(RefUpdate {
+                    branch: branch.clone(),
+                    old: current.get(branch).cloned(),
+                    new: Some(commit.clone()),
+                });
+            }
+        }
+
+        for (branch, commit) in current {
+            if !heads.contains_key(&branch) {
+                fs::remove_file(self.tracking_path(remote, &branch))?;
+                updates.push(RefUpdate { branch, old: Some(commit), new: None });
+            }
+        }
+
+        Ok(updates)
+    }
+
+    fn tracking_path(&self, remote: &str, branch: &str) -> PathBuf {
+        self.repo.remotes().join(remote).join(branch)
+    }
+}
@@ -0,0 +1,28 @@
+//! This module manages the remotes in the Dit version control system
+//!
+//! A remote is another repository, identified by a url, which the commits are
+//! exchanged with. Fetching copies the objects missing from the local repository
+//! and records the heads of the remote branches as remote-tracking branches
+//! (`.dit/remotes/<remote>/<branch>`), which can be referenced as `<remote>/<branch>`.
+//! Pushing copies the objects missing from the remote repository and updates the
+//! remote branch, refusing to discard remote commits unless forced. \
+//! The repositories talk to each other through a [`Transport`]. The remote
+//! repositories are either located on the same filesystem, or served over TCP
+//! by `dit serve` (`dit://host[:port]` urls, see [`crate::remote::protocol`]).
+//!
+//! [`Transport`]: crate::remote::Transport
+
+use crate::Repo;
+use std::sync::Arc;
+
+/// Manages the remotes. See [`crate::remote`] for more info
+pub struct RemoteMgr {
+    pub(super) repo: Arc<Repo>,
+}
+
+/// Constructors
+impl RemoteMgr {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { repo }
+    }
+}
@@ -0,0 +1,17 @@
+mod manager;
+mod helpers;
+mod object_db;
+mod transport;
+mod transport_local;
+mod transport_network;
+mod protocol;
+mod remote_manage;
+mod remote_fetch;
+mod remote_push;
+mod remote_serve;
+
+pub use manager::RemoteMgr;
+pub use object_db::ObjectDb;
+pub use transport::{normalize_url, open_transport, Transport};
+pub use transport_local::LocalTransport;
+pub use transport_network::NetworkTransport;
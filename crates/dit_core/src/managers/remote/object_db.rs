@@ -0,0 +1,226 @@
+use crate::managers::blob::BlobMgr;
+use crate::managers::tree::TreeMgr;
+use crate::managers::commit::{CommitBfsIterator, CommitMgr};
+use crate::api_models::object::ObjectKind;
+use crate::models::RemoteObject;
+use crate::helpers::DitHasher;
+use crate::errors::{DitResult, RemoteError};
+use std::collections::HashSet;
+
+/// Reads and writes the objects of a repository on behalf of a [`Transport`]
+///
+/// [`Transport`]: crate::remote::Transport
+pub struct ObjectDb<'a> {
+    blob_mgr: &'a BlobMgr,
+    tree_mgr: &'a TreeMgr,
+    commit_mgr: &'a CommitMgr,
+}
+
+/// Constructors
+impl<'a> ObjectDb<'a> {
+    pub fn new(blob_mgr: &'a BlobMgr, tree_mgr: &'a TreeMgr, commit_mgr: &'a CommitMgr) -> Self {
+        Self { blob_mgr, tree_mgr, commit_mgr }
+    }
+}
+
+/// Public
+impl ObjectDb<'_> {
+    /// Checks whether the object exists (either loose or packed)
+    pub fn has_object(&self, kind: ObjectKind, hash: &str) -> DitResult<bool> {
+        match kind {
+            ObjectKind::Blob => self.blob_mgr.has_blob(hash),
+            ObjectKind::Tree => self.tree_mgr.has_tree(hash),
+            ObjectKind::Commit => self.commit_mgr.has_commit(hash),
+            ObjectKind::TagObject => Err(RemoteError::UnsupportedObject(kind.to_string()).into()),
+        }
+    }
+
+    /// Reads an object to be sent to another repository, as it's stored
+    pub fn read_object(&self, kind: ObjectKind, hash: &str) -> DitResult<RemoteObject> {
+        let content = match kind {
+            ObjectKind::Blob => self.blob_mgr.read_blob(hash.to_string())?,
+            ObjectKind::Tree => self.tree_mgr.read_tree_bytes(hash)?,
+            ObjectKind::Commit => self.commit_mgr.read_commit_bytes(hash)?,
+            ObjectKind::TagObject => return Err(RemoteError::UnsupportedObject(kind.to_string()).into()),
+        };
+
+        Ok(RemoteObject { kind, hash: hash.to_string(), content })
+    }
+
+    /// Stores an object received from another repository as a loose object,
+    /// unless it already exists. Every object is identified by the hash of the received
+    /// bytes, except the legacy trees and commits, which keep the hash they were created
+    /// with (see [`TreeMgr::hash_tree`]). Fails if the content doesn't match the hash
+    pub fn write_object(&self, object: RemoteObject) -> DitResult<()> {
+        let RemoteObject { kind, hash, content } = object;
+
+        if self.has_object(kind, &hash)? {
+            return Ok(());
+        }
+
+        let corrupt = || RemoteError::CorruptObject(kind.to_string(), hash.clone());
+
+        match kind {
+            ObjectKind::Blob => {
+                let mut hasher = DitHasher::new();
+                hasher.update(&content);
+                if hasher.finalize_string() != hash {
+                    return Err(corrupt().into());
+                }
+                self.blob_mgr.create_blob_from_bytes(&content)?;
+            }
+            ObjectKind::Tree => {
+                let mut tree = TreeMgr::decode_tree(&content)?;
+                if TreeMgr::hash_tree(&tree) != hash {
+                    return Err(corrupt().into());
+                }
+                tree.hash = hash;
+                self.tree_mgr.write_tree(&tree)?;
+            }
+            ObjectKind::Commit => {
+                let mut commit = CommitMgr::decode_commit(&content)?;
+                if CommitMgr::hash_commit(&commit) != hash {
+                    return Err(corrupt().into());
+                }
+                commit.hash = hash;
+                self.commit_mgr.write_commit(&commit)?;
+            }
+            ObjectKind::TagObject => return Err(RemoteError::UnsupportedObject(kind.to_string()).into()),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the objects reachable from the `wants` commits, except the ones
+    /// reachable from the `haves` commits. The `haves` which don't exist are ignored.
+    ///
+    /// The objects are ordered so that every object comes after the objects it
+    /// references: blobs first, then trees (subtrees before their parents),
+    /// then commits (parents before children)
+    pub fn missing_objects(
+        &self,
+        wants: &[String],
+        haves: &[String],
+    ) -> DitResult<Vec<(ObjectKind, String)>> {
+        let mut known_haves = Vec::new();
+        for have in haves {
+            if self.commit_mgr.has_commit(have)? {
+                known_haves.push(have.clone());
+            }
+        }
+
+        let mut have_commits = HashSet::new();
+        let mut have_trees = HashSet::new();
+        let mut have_blobs = HashSet::new();
+        for commit_hash in CommitBfsIterator::from_many(known_haves, self.commit_mgr) {
+            let commit_hash = commit_hash?;
+            let commit = self.commit_mgr.get_commit(&commit_hash)?;
+            self.tree_mgr.collect_objects(&commit.tree, &mut have_trees, &mut have_blobs)?;
+            have_commits.insert(commit_hash);
+        }
+
+        let mut commits = Vec::new();
+        let mut trees = Vec::new();
+        let mut blobs = Vec::new();
+
+        // Depth-first search emitting every commit after its parents
+        let mut visited = have_commits;
+        let mut stack: Vec<(String, bool /* parents done */)> =
+            wants.iter().map(|want| (want.clone(), false)).collect();
+
+        while let Some((commit_hash, parents_done)) = stack.pop() {
+            if parents_done {
+                commits.push((ObjectKind::Commit, commit_hash));
+                continue;
+            }
+            if !visited.insert(commit_hash.clone()) {
+                continue;
+            }
+
+            let commit = self.commit_mgr.get_commit(&commit_hash)?;
+            self.add_missing_tree(commit.tree, &mut have_trees, &mut have_blobs, &mut trees, &mut blobs)?;
+
+            stack.push((commit_hash, true));
+            for parent in commit.parents {
+                if !visited.contains(&parent) {
+                    stack.push((parent, false));
+                }
+            }
+        }
+
+        Ok(blobs.into_iter().chain(trees).chain(commits).collect())
+    }
+
+    /// Fails unless the commit and all the commits, trees and blobs it references exist.
+    /// The history of the `known` commits is assumed to be complete, so it's not walked
+    pub fn require_complete(&self, commit: &str, known: &[String]) -> DitResult<()> {
+        let mut visited: HashSet<String> = known.iter().cloned().collect();
+        let mut visited_trees = HashSet::new();
+        let mut visited_blobs = HashSet::new();
+        let missing = |kind: ObjectKind, hash: &str| RemoteError::MissingObject(kind.to_string(), hash.to_string());
+
+        let mut commits = vec![commit.to_string()];
+        while let Some(commit_hash) = commits.pop() {
+            if !visited.insert(commit_hash.clone()) {
+                continue;
+            }
+            if !self.commit_mgr.has_commit(&commit_hash)? {
+                return Err(missing(ObjectKind::Commit, &commit_hash).into());
+            }
+
+            let commit = self.commit_mgr.get_commit(&commit_hash)?;
+            commits.extend(commit.parents);
+
+            let mut trees = vec![commit.tree];
+            while let Some(tree_hash) = trees.pop() {
+                if !visited_trees.insert(tree_hash.clone()) {
+                    continue;
+                }
+                if !self.tree_mgr.has_tree(&tree_hash)? {
+                    return Err(missing(ObjectKind::Tree, &tree_hash).into());
+                }
+
+                for entry in self.tree_mgr.get_tree(tree_hash)?.entries.into_values() {
+                    if entry.is_tree() {
+                        trees.push(entry.hash);
+                    } else if visited_blobs.insert(entry.hash.clone()) && !self.blob_mgr.has_blob(&entry.hash)? {
+                        return Err(missing(ObjectKind::Blob, &entry.hash).into());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Private
+impl ObjectDb<'_> {
+    /// Adds a tree, its subtrees and their blobs which aren't known yet to the missing
+    /// objects, every tree after its subtrees. The known trees are skipped along with
+    /// their subtrees
+    fn add_missing_tree(
+        &self,
+        tree_hash: String,
+        have_trees: &mut HashSet<String>,
+        have_blobs: &mut HashSet<String>,
+        trees: &mut Vec<(ObjectKind, String)>,
+        blobs: &mut Vec<(ObjectKind, String)>,
+    ) -> DitResult<()> {
+        if !have_trees.insert(tree_hash.clone()) {
+            return Ok(());
+        }
+
+        for entry in self.tree_mgr.get_tree(tree_hash.clone())?.entries.into_values() {
+            if entry.is_tree() {
+                self.add_missing_tree(entry.hash, have_trees, have_blobs, trees, blobs)?;
+            } else if have_blobs.insert(entry.hash.clone()) {
+                blobs.push((ObjectKind::Blob, entry.hash));
+            }
+        }
+
+        trees.push((ObjectKind::Tree, tree_hash));
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+//! The wire protocol spoken between `dit serve` and the `dit://` remotes
+//!
+//! Every message is sent as a frame: a big-endian `u32` length followed by the
+//! zstd-compressed payload. The payload is a big-endian `u32` header length,
+//! the JSON-serialized [`Message`] and an optional body, which holds the content
+//! of a transferred object. Both the frame and the payload are limited in size,
+//! so the content of a large object is split into chunks: the first one is sent
+//! with [`Message::Object`] and the rest with [`Message::ObjectData`].
+//!
+//! A session starts with both sides exchanging [`Message::Hello`]. The client then
+//! sends any number of requests:
+//! - [`Message::ListRefs`], answered by [`Message::Refs`]
+//! - [`Message::Fetch`], answered by the missing objects followed by [`Message::Done`]
+//! - [`Message::Push`] followed by the objects and [`Message::Done`], answered by [`Message::Done`]
+//! - [`Message::UpdateBranch`], answered by [`Message::Done`]
+//!
+//! Any request can be answered by [`Message::Error`] instead.
+
+use crate::api_models::object::ObjectKind;
+use crate::models::{RemoteObject, RemoteRefs};
+use crate::helpers::{
+    PROTOCOL_MAX_FRAME_SIZE, PROTOCOL_MAX_PAYLOAD_SIZE, PROTOCOL_OBJECT_CHUNK_SIZE, ZSTD_COMPRESSION_LEVEL,
+};
+use crate::errors::{DitResult, RemoteError};
+use serde::{Deserialize, Serialize};
+use std::io::{ErrorKind, Read, Write};
+
+/// Represents a protocol message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum Message {
+    Hello { version: u32 },
+    ListRefs,
+    Refs(RemoteRefs),
+    Fetch { wants: Vec<String>, haves: Vec<String> },
+    Push,
+    /// The first chunk of the content of the object is sent as the body
+    Object { kind: ObjectKind, hash: String, size: u64 },
+    /// The next chunk of the content of the object being sent
+    ObjectData,
+    UpdateBranch { branch: String, old: Option<String>, new: String, force: bool },
+    Done,
+    Error(String),
+}
+
+/// Writes a message and flushes the writer
+pub(crate) fn write_message<W: Write>(writer: &mut W, message: &Message, body: &[u8]) -> DitResult<()> {
+    write_frame(writer, message, body)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes an object message with the content as the body, split into chunks.
+/// The writer is not flushed, since the objects are always followed by [`Message::Done`]
+pub(crate) fn write_object<W: Write>(writer: &mut W, object: RemoteObject) -> DitResult<()> {
+    let RemoteObject { kind, hash, content } = object;
+    let mut chunks = content.chunks(PROTOCOL_OBJECT_CHUNK_SIZE);
+
+    let first = chunks.next().unwrap_or_default();
+    write_frame(writer, &Message::Object { kind, hash, size: content.len() as u64 }, first)?;
+    for chunk in chunks {
+        write_frame(writer, &Message::ObjectData, chunk)?;
+    }
+
+    Ok(())
+}
+
+fn write_frame<W: Write>(writer: &mut W, message: &Message, body: &[u8]) -> DitResult<()> {
+    let header = serde_json::to_vec(message)?;
+
+    let mut payload = Vec::with_capacity(4 + header.len() + body.len());
+    payload.extend_from_slice(&(header.len() as u32).to_be_bytes());
+    payload.extend_from_slice(&header);
+    payload.extend_from_slice(body);
+
+    let frame = zstd::bulk::compress(&payload, ZSTD_COMPRESSION_LEVEL as i32)?;
+    writer.write_all(&(frame.len() as u32).to_be_bytes())?;
+    writer.write_all(&frame)?;
+
+    Ok(())
+}
+
+/// Reads a message and its body. The chunks of an object are joined into its content.
+/// Returns `None` if the other side closed the connection
+pub(crate) fn read_message<R: Read>(reader: &mut R) -> DitResult<Option<(Message, Vec<u8>)>> {
+    let Some((message, mut body)) = read_frame(reader)? else {
+        return Ok(None);
+    };
+
+    if let Message::Object { size, .. } = &message {
+        while (body.len() as u64) < *size {
+            match read_frame(reader)? {
+                Some((Message::ObjectData, chunk)) if !chunk.is_empty() => body.extend_from_slice(&chunk),
+                Some((message, _)) => return Err(unexpected(&message).into()),
+                None => return Err(RemoteError::ProtocolError(String::from("connection closed unexpectedly")).into()),
+            }
+        }
+
+        if body.len() as u64 != *size {
+            return Err(RemoteError::ProtocolError(format!("object is larger than the announced {size} bytes")).into());
+        }
+    }
+
+    Ok(Some((message, body)))
+}
+
+/// Reads a single frame. The payload is decompressed into a buffer of a limited size,
+/// so a small frame can't expand into an arbitrarily large payload
+fn read_frame<R: Read>(reader: &mut R) -> DitResult<Option<(Message, Vec<u8>)>> {
+    let mut length = [0u8; 4];
+    match reader.read_exact(&mut length) {
+        Ok(()) => {}
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+
+    let length = u32::from_be_bytes(length);
+    if length > PROTOCOL_MAX_FRAME_SIZE {
+        return Err(RemoteError::ProtocolError(format!("frame of {length} bytes is too large")).into());
+    }
+
+    let mut frame = vec![0u8; length as usize];
+    reader.read_exact(&mut frame)?;
+    let payload = zstd::bulk::decompress(&frame, PROTOCOL_MAX_PAYLOAD_SIZE).map_err(|_| {
+        RemoteError::ProtocolError(format!("frame is malformed or larger than {PROTOCOL_MAX_PAYLOAD_SIZE} bytes"))
+    })?;
+
+    let invalid = || RemoteError::ProtocolError(String::from("malformed frame"));
+    let header_length = payload.get(..4)
+        .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+        .ok_or_else(invalid)?;
+    let header = payload.get(4..4 + header_length).ok_or_else(invalid)?;
+    let message = serde_json::from_slice(header).map_err(|_| invalid())?;
+    let body = payload[4 + header_length..].to_vec();
+
+    Ok(Some((message, body)))
+}
+
+/// Reads a message, failing if the connection was closed
+/// or the other side reported an error
+pub(crate) fn expect_message<R: Read>(reader: &mut R) -> DitResult<(Message, Vec<u8>)> {
+    match read_message(reader)? {
+        Some((Message::Error(e), _)) => Err(RemoteError::ServerError(e).into()),
+        Some(message) => Ok(message),
+        None => Err(RemoteError::ProtocolError(String::from("connection closed unexpectedly")).into()),
+    }
+}
+
+/// Returns an error describing an unexpected message
+pub(crate) fn unexpected(message: &Message) -> RemoteError {
+    RemoteError::ProtocolError(format!("unexpected message {message:?}"))
+}
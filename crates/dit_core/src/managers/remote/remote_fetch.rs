@@ -0,0 +1,84 @@
+use crate::managers::remote::{open_transport, ObjectDb, RemoteMgr};
+use crate::managers::blob::BlobMgr;
+use crate::managers::tree::TreeMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::config::ConfigMgr;
+use crate::api_models::remote::FetchReport;
+use crate::errors::{DitResult, RemoteError};
+
+impl RemoteMgr {
+    /// Copies the objects of the remote branches which are missing from the local
+    /// repository and updates the remote-tracking branches to match the remote branches
+    pub fn fetch(
+        &self,
+        remote: &str,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+        branch_mgr: &BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<FetchReport> {
+        let url = config_mgr.require_remote_url(remote)?;
+        let mut transport = open_transport(url)?;
+        let refs = transport.list_refs()?;
+
+        let mut wants = Vec::new();
+        for commit in refs.branches.values() {
+            if !commit_mgr.has_commit(commit)? && !wants.contains(commit) {
+                wants.push(commit.clone());
+            }
+        }
+
+        let mut objects = 0;
+        if !wants.is_empty() {
+            let db = ObjectDb::new(blob_mgr, tree_mgr, commit_mgr);
+            let haves = self.local_heads(commit_mgr, branch_mgr)?;
+
+            transport.fetch_objects(&wants, &haves, &mut |object| {
+                db.write_object(object)?;
+                objects += 1;
+                Ok(())
+            })?;
+
+            for want in &wants {
+                if !commit_mgr.has_commit(want)? {
+                    return Err(RemoteError::MissingCommit(want.clone()).into());
+                }
+            }
+        }
+
+        let updates = self.store_tracking_heads(remote, &refs.branches)?;
+
+        Ok(FetchReport {
+            remote: remote.to_string(),
+            head: refs.head,
+            updates,
+            objects,
+        })
+    }
+}
+
+
+/// Private
+impl RemoteMgr {
+    /// Returns the existing head commits of the local and the remote-tracking branches,
+    /// which the other side doesn't have to send
+    pub(super) fn local_heads(&self, commit_mgr: &CommitMgr, branch_mgr: &BranchMgr) -> DitResult<Vec<String>> {
+        let mut heads = Vec::new();
+
+        for branch in branch_mgr.list_branches()? {
+            heads.extend(branch_mgr.get_branch_head(branch)?);
+        }
+        heads.extend(self.list_all_tracking_heads()?.into_values());
+
+        let mut existing = Vec::new();
+        for head in heads {
+            if !existing.contains(&head) && commit_mgr.has_commit(&head)? {
+                existing.push(head);
+            }
+        }
+
+        Ok(existing)
+    }
+}
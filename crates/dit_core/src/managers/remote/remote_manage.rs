@@ -0,0 +1,41 @@
+use crate::managers::remote::{normalize_url, RemoteMgr};
+use crate::managers::config::ConfigMgr;
+use crate::api_models::remote::RemoteInfo;
+use crate::errors::{DitResult, RemoteError};
+use std::fs;
+
+impl RemoteMgr {
+    /// Adds a remote repository given its name and url
+    pub fn add_remote(&self, name: &str, url: &str, config_mgr: &mut ConfigMgr) -> DitResult<()> {
+        if !Self::is_valid_remote_name(name) {
+            return Err(RemoteError::InvalidRemoteName(name.to_string()).into());
+        }
+
+        let url = normalize_url(url)?;
+        config_mgr.add_remote(name.to_string(), url)
+    }
+
+    /// Removes a remote together with its remote-tracking branches
+    pub fn remove_remote(&self, name: &str, config_mgr: &mut ConfigMgr) -> DitResult<()> {
+        config_mgr.remove_remote(name)?;
+
+        let dir = self.repo.remotes().join(name);
+        if Self::is_valid_remote_name(name) && dir.is_dir() {
+            fs::remove_dir_all(&dir)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the remotes sorted by name
+    pub fn list_remotes(&self, config_mgr: &ConfigMgr) -> DitResult<Vec<RemoteInfo>> {
+        config_mgr.list_remotes()
+            .iter()
+            .map(|(name, url)| Ok(RemoteInfo {
+                name: name.clone(),
+                url: url.clone(),
+                branches: self.list_tracking_heads(name)?,
+            }))
+            .collect()
+    }
+}
@@ -0,0 +1,71 @@
+use crate::managers::remote::{open_transport, ObjectDb, RemoteMgr};
+use crate::managers::blob::BlobMgr;
+use crate::managers::tree::TreeMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::config::ConfigMgr;
+use crate::api_models::remote::PushReport;
+use crate::errors::{BranchError, DitResult, RemoteError};
+
+impl RemoteMgr {
+    /// Copies the objects of the local branch which are missing from the remote repository
+    /// and points the remote branch to the head of the local branch.
+    ///
+    /// Unless forced, fails if the remote branch contains commits which are not
+    /// in the local branch
+    #[allow(clippy::too_many_arguments)]
+    pub fn push(
+        &self,
+        remote: &str,
+        branch: &str,
+        force: bool,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+        branch_mgr: &BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<PushReport> {
+        if !branch_mgr.list_branches()?.iter().any(|b| b == branch) {
+            return Err(BranchError::BranchDoesNotExist(branch.to_string()).into());
+        }
+        let local = branch_mgr.get_branch_head(branch)?
+            .ok_or_else(|| RemoteError::NothingToPush(branch.to_string()))?;
+
+        let url = config_mgr.require_remote_url(remote)?;
+        let mut transport = open_transport(url)?;
+        let refs = transport.list_refs()?;
+        let old = refs.branches.get(branch).cloned();
+
+        let mut report = PushReport {
+            remote: remote.to_string(),
+            branch: branch.to_string(),
+            old: old.clone(),
+            new: local.clone(),
+            objects: 0,
+            forced: false,
+        };
+
+        if !report.is_up_to_date() {
+            let fast_forward = match &old {
+                None => true,
+                Some(old) => commit_mgr.has_commit(old)? && commit_mgr.is_ancestor(old, &local)?,
+            };
+            if !fast_forward && !force {
+                return Err(RemoteError::NonFastForward(branch.to_string()).into());
+            }
+            report.forced = !fast_forward;
+
+            let db = ObjectDb::new(blob_mgr, tree_mgr, commit_mgr);
+            let haves: Vec<String> = refs.branches.into_values().collect();
+            let missing = db.missing_objects(std::slice::from_ref(&local), &haves)?;
+            report.objects = missing.len();
+
+            transport.push_objects(&mut missing.iter().map(|(kind, hash)| db.read_object(*kind, hash)))?;
+            transport.update_branch(branch, old.as_deref(), &local, force)?;
+        }
+
+        self.set_tracking_head(remote, branch, &local)?;
+
+        Ok(report)
+    }
+}
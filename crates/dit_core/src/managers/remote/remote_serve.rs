@@ -0,0 +1,98 @@
+use crate::managers::remote::{LocalTransport, RemoteMgr, Transport};
+use crate::managers::remote::protocol::{read_message, unexpected, write_message, write_object, Message};
+use crate::models::RemoteObject;
+use crate::helpers::PROTOCOL_VERSION;
+use crate::errors::{DitResult, RemoteError};
+use std::io::{Read, Write};
+
+impl RemoteMgr {
+    /// Serves the repository to a single `dit://` client until it disconnects.
+    /// The requests are answered through a [`LocalTransport`] on this repository,
+    /// so the server sees the changes made since the connection was opened
+    pub fn serve<R: Read, W: Write>(&self, mut reader: R, mut writer: W) -> DitResult<()> {
+        match read_message(&mut reader)? {
+            Some((Message::Hello { version }, _)) if version == PROTOCOL_VERSION => {
+                write_message(&mut writer, &Message::Hello { version: PROTOCOL_VERSION }, &[])?;
+            }
+            Some((Message::Hello { version }, _)) => {
+                let error = format!("unsupported protocol version {version}");
+                write_message(&mut writer, &Message::Error(error.clone()), &[])?;
+                return Err(RemoteError::ProtocolError(error).into());
+            }
+            Some((message, _)) => {
+                let error = unexpected(&message);
+                write_message(&mut writer, &Message::Error(error.to_string()), &[])?;
+                return Err(error.into());
+            }
+            None => return Ok(()),
+        }
+
+        while let Some((request, _)) = read_message(&mut reader)? {
+            // Every request sees the current state of the repository
+            let mut transport = LocalTransport::open(self.repo.repo_path())?;
+
+            let result = match request {
+                Message::ListRefs => transport.list_refs()
+                    .and_then(|refs| write_message(&mut writer, &Message::Refs(refs), &[])),
+                Message::Fetch { wants, haves } => {
+                    transport.fetch_objects(&wants, &haves, &mut |object| write_object(&mut writer, object))
+                        .and_then(|_| write_message(&mut writer, &Message::Done, &[]))
+                }
+                Message::Push => Self::receive_objects(&mut transport, &mut reader)
+                    .and_then(|_| write_message(&mut writer, &Message::Done, &[])),
+                Message::UpdateBranch { branch, old, new, force } => {
+                    transport.update_branch(&branch, old.as_deref(), &new, force)
+                        .and_then(|_| write_message(&mut writer, &Message::Done, &[]))
+                }
+                message => {
+                    let error = unexpected(&message);
+                    write_message(&mut writer, &Message::Error(error.to_string()), &[])?;
+                    return Err(error.into());
+                }
+            };
+
+            if let Err(e) = result {
+                write_message(&mut writer, &Message::Error(e.to_string()), &[])?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Private
+impl RemoteMgr {
+    /// Stores the pushed objects until [`Message::Done`]. If an object can't be stored,
+    /// the rest of them are still read, so that the client receives the error
+    fn receive_objects<R: Read>(transport: &mut LocalTransport, reader: &mut R) -> DitResult<()> {
+        let mut failure = None;
+
+        let mut objects = std::iter::from_fn(|| match read_message(reader) {
+            Ok(Some((Message::Object { kind, hash, .. }, content))) => Some(Ok(RemoteObject { kind, hash, content })),
+            Ok(Some((Message::Done, _))) => None,
+            Ok(Some((message, _))) => {
+                failure = Some(unexpected(&message).into());
+                None
+            }
+            Ok(None) => {
+                failure = Some(RemoteError::ProtocolError(String::from("connection closed unexpectedly")).into());
+                None
+            }
+            Err(e) => {
+                failure = Some(e);
+                None
+            }
+        }).fuse();
+
+        let result = transport.push_objects(&mut objects);
+        // Skip the rest of the objects if storing them failed
+        objects.by_ref().for_each(drop);
+        drop(objects);
+
+        match failure {
+            Some(e) => Err(e),
+            None => result,
+        }
+    }
+}
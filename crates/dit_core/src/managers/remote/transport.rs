@@ -0,0 +1,58 @@
+use crate::managers::remote::{LocalTransport, NetworkTransport};
+use crate::models::{RemoteObject, RemoteRefs};
+use crate::dit_component_paths::DIT_ROOT;
+use crate::helpers::{path_to_string, resolve_absolute_path, NETWORK_URL_SCHEME};
+use crate::errors::{DitResult, RemoteError};
+use std::path::Path;
+
+/// Represents a connection to a remote repository
+pub trait Transport {
+    /// Returns the current branch and the branch heads of the remote repository
+    fn list_refs(&mut self) -> DitResult<RemoteRefs>;
+
+    /// Passes the objects reachable from the `wants` commits, except the ones
+    /// reachable from the `haves` commits, to `receive`. Every object comes after
+    /// the objects it references
+    fn fetch_objects(
+        &mut self,
+        wants: &[String],
+        haves: &[String],
+        receive: &mut dyn FnMut(RemoteObject) -> DitResult<()>,
+    ) -> DitResult<()>;
+
+    /// Stores the objects in the remote repository
+    fn push_objects(&mut self, objects: &mut dyn Iterator<Item = DitResult<RemoteObject>>) -> DitResult<()>;
+
+    /// Points the remote branch to the `new` commit, provided that it still points to
+    /// the `old` commit (`None` if the branch must not exist yet). Unless the update is
+    /// forced, the `new` commit has to be a descendant of the `old` one
+    fn update_branch(&mut self, branch: &str, old: Option<&str>, new: &str, force: bool) -> DitResult<()>;
+}
+
+/// Validates the url of a remote repository. Local paths are made absolute,
+/// so that they don't depend on the working directory
+pub fn normalize_url(url: &str) -> DitResult<String> {
+    if url.starts_with(NETWORK_URL_SCHEME) {
+        NetworkTransport::parse_url(url)?;
+        return Ok(url.to_string());
+    }
+
+    let path = resolve_absolute_path(Path::new(url))
+        .map_err(|_| RemoteError::NotARepository(url.to_string()))?;
+
+    if !path.join(DIT_ROOT).is_dir() {
+        return Err(RemoteError::NotARepository(url.to_string()).into());
+    }
+
+    Ok(path_to_string(&path))
+}
+
+/// Connects to the repository at the url. `dit://host[:port]` urls are served
+/// by `dit serve`, everything else is a local path
+pub fn open_transport(url: &str) -> DitResult<Box<dyn Transport>> {
+    if url.starts_with(NETWORK_URL_SCHEME) {
+        Ok(Box::new(NetworkTransport::connect(url)?))
+    } else {
+        Ok(Box::new(LocalTransport::open(url)?))
+    }
+}
@@ -0,0 +1,129 @@
+use crate::managers::remote::{ObjectDb, Transport};
+use crate::managers::blob::BlobMgr;
+use crate::managers::tree::TreeMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::config::ConfigMgr;
+use crate::models::{RemoteObject, RemoteRefs};
+use crate::dit_component_paths::DIT_ROOT;
+use crate::errors::{DitResult, RemoteError};
+use crate::Repo;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Connects to a repository located on the same filesystem
+pub struct LocalTransport {
+    repo: Arc<Repo>,
+    blob_mgr: BlobMgr,
+    tree_mgr: TreeMgr,
+    commit_mgr: CommitMgr,
+    branch_mgr: BranchMgr,
+    config_mgr: ConfigMgr,
+}
+
+/// Constructors
+impl LocalTransport {
+    /// Opens the repository at the given path (where `.dit` is located)
+    pub fn open<P: AsRef<Path>>(path: P) -> DitResult<Self> {
+        let path = path.as_ref();
+        if !path.join(DIT_ROOT).is_dir() {
+            return Err(RemoteError::NotARepository(path.display().to_string()).into());
+        }
+
+        let repo = Arc::new(Repo::init(path)?);
+
+        Ok(Self {
+            blob_mgr: BlobMgr::from(repo.clone()),
+            tree_mgr: TreeMgr::from(repo.clone()),
+            commit_mgr: CommitMgr::from(repo.clone()),
+            branch_mgr: BranchMgr::from(repo.clone())?,
+            config_mgr: ConfigMgr::from(repo.clone())?,
+            repo,
+        })
+    }
+}
+
+impl Transport for LocalTransport {
+    fn list_refs(&mut self) -> DitResult<RemoteRefs> {
+        let mut refs = RemoteRefs {
+            head: self.branch_mgr.get_current_branch().cloned(),
+            ..Default::default()
+        };
+
+        for branch in self.branch_mgr.list_branches()? {
+            if let Some(commit) = self.branch_mgr.get_branch_head(&branch)? {
+                refs.branches.insert(branch, commit);
+            }
+        }
+
+        Ok(refs)
+    }
+
+    fn fetch_objects(
+        &mut self,
+        wants: &[String],
+        haves: &[String],
+        receive: &mut dyn FnMut(RemoteObject) -> DitResult<()>,
+    ) -> DitResult<()> {
+        let objects = self.objects();
+
+        for want in wants {
+            if !self.commit_mgr.has_commit(want)? {
+                return Err(RemoteError::MissingCommit(want.clone()).into());
+            }
+        }
+
+        for (kind, hash) in objects.missing_objects(wants, haves)? {
+            receive(objects.read_object(kind, &hash)?)?;
+        }
+
+        Ok(())
+    }
+
+    fn push_objects(&mut self, objects: &mut dyn Iterator<Item = DitResult<RemoteObject>>) -> DitResult<()> {
+        let _lock = self.repo.lock()?;
+        let db = self.objects();
+
+        for object in objects {
+            db.write_object(object?)?;
+        }
+
+        Ok(())
+    }
+
+    fn update_branch(&mut self, branch: &str, old: Option<&str>, new: &str, force: bool) -> DitResult<()> {
+        // The remote branch is compared and updated while no other process can change it
+        let _lock = self.repo.lock()?;
+
+        if self.branch_mgr.get_current_branch().is_some_and(|current| current == branch) {
+            return Err(RemoteError::BranchCheckedOut(branch.to_string()).into());
+        }
+
+        if self.branch_mgr.get_branch_head(branch)?.as_deref() != old {
+            return Err(RemoteError::RemoteBranchChanged(branch.to_string()).into());
+        }
+
+        // The pushed objects are checked before the branch can point to them
+        let known: Vec<String> = old.map(str::to_string).into_iter().collect();
+        self.objects().require_complete(new, &known)?;
+
+        if let Some(old) = old
+            && !force
+            && !self.commit_mgr.is_ancestor(old, new)?
+        {
+            return Err(RemoteError::NonFastForward(branch.to_string()).into());
+        }
+
+        self.commit_mgr.verify_branch_update(branch, old, new, &self.config_mgr)?;
+
+        self.branch_mgr.update_branch_head(branch, new)
+    }
+}
+
+
+/// Private
+impl LocalTransport {
+    fn objects(&self) -> ObjectDb<'_> {
+        ObjectDb::new(&self.blob_mgr, &self.tree_mgr, &self.commit_mgr)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::managers::remote::Transport;
+use crate::managers::remote::protocol::{expect_message, unexpected, write_message, write_object, Message};
+use crate::models::{RemoteObject, RemoteRefs};
+use crate::helpers::{DEFAULT_SERVE_PORT, NETWORK_URL_SCHEME, PROTOCOL_VERSION};
+use crate::errors::{DitResult, RemoteError};
+use std::io::{BufReader, BufWriter};
+use std::net::TcpStream;
+
+/// Connects to a repository served by `dit serve` (a `dit://host[:port]` url)
+pub struct NetworkTransport {
+    reader: BufReader<TcpStream>,
+    writer: BufWriter<TcpStream>,
+}
+
+/// Constructors
+impl NetworkTransport {
+    /// Connects to the server and exchanges the protocol versions
+    pub fn connect(url: &str) -> DitResult<Self> {
+        let address = Self::parse_url(url)?;
+        let stream = TcpStream::connect(&address)?;
+
+        let mut transport = Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: BufWriter::new(stream),
+        };
+
+        write_message(&mut transport.writer, &Message::Hello { version: PROTOCOL_VERSION }, &[])?;
+        match expect_message(&mut transport.reader)? {
+            (Message::Hello { version }, _) if version == PROTOCOL_VERSION => Ok(transport),
+            (Message::Hello { version }, _) => Err(RemoteError::ProtocolError(
+                format!("unsupported protocol version {version}")
+            ).into()),
+            (message, _) => Err(unexpected(&message).into()),
+        }
+    }
+
+    /// Returns the `host:port` address of a `dit://host[:port]` url
+    pub fn parse_url(url: &str) -> DitResult<String> {
+        let invalid = || RemoteError::InvalidUrl(url.to_string());
+
+        let address = url.strip_prefix(NETWORK_URL_SCHEME).ok_or_else(invalid)?;
+        let address = address.strip_suffix('/').unwrap_or(address);
+        if address.is_empty() || address.contains('/') {
+            return Err(invalid().into());
+        }
+
+        // IPv6 addresses are enclosed in brackets, e.g. [::1]:9419
+        let has_port = match address.rfind(']') {
+            Some(end) => address[end..].contains(':'),
+            None => address.contains(':'),
+        };
+
+        if has_port {
+            Ok(address.to_string())
+        } else {
+            Ok(format!("{address}:{DEFAULT_SERVE_PORT}"))
+        }
+    }
+}
+
+impl Transport for NetworkTransport {
+    fn list_refs(&mut self) -> DitResult<RemoteRefs> {
+        write_message(&mut self.writer, &Message::ListRefs, &[])?;
+
+        match expect_message(&mut self.reader)? {
+            (Message::Refs(refs), _) => Ok(refs),
+            (message, _) => Err(unexpected(&message).into()),
+        }
+    }
+
+    fn fetch_objects(
+        &mut self,
+        wants: &[String],
+        haves: &[String],
+        receive: &mut dyn FnMut(RemoteObject) -> DitResult<()>,
+    ) -> DitResult<()> {
+        let request = Message::Fetch { wants: wants.to_vec(), haves: haves.to_vec() };
+        write_message(&mut self.writer, &request, &[])?;
+
+        loop {
+            match expect_message(&mut self.reader)? {
+                (Message::Object { kind, hash, .. }, content) => receive(RemoteObject { kind, hash, content })?,
+                (Message::Done, _) => return Ok(()),
+                (message, _) => return Err(unexpected(&message).into()),
+            }
+        }
+    }
+
+    fn push_objects(&mut self, objects: &mut dyn Iterator<Item = DitResult<RemoteObject>>) -> DitResult<()> {
+        write_message(&mut self.writer, &Message::Push, &[])?;
+        for object in objects {
+            write_object(&mut self.writer, object?)?;
+        }
+        write_message(&mut self.writer, &Message::Done, &[])?;
+
+        match expect_message(&mut self.reader)? {
+            (Message::Done, _) => Ok(()),
+            (message, _) => Err(unexpected(&message).into()),
+        }
+    }
+
+    fn update_branch(&mut self, branch: &str, old: Option<&str>, new: &str, force: bool) -> DitResult<()> {
+        let request = Message::UpdateBranch {
+            branch: branch.to_string(),
+            old: old.map(str::to_string),
+            new: new.to_string(),
+            force,
+        };
+        write_message(&mut self.writer, &request, &[])?;
+
+        match expect_message(&mut self.reader)? {
+            (Message::Done, _) => Ok(()),
+            (message, _) => Err(unexpected(&message).into()),
+        }
+    }
+}
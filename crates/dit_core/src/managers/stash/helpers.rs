@@ -0,0 +1,53 @@
+use crate::managers::stash::StashMgr;
+use crate::managers::commit::CommitMgr;
+use crate::api_models::stash::StashEntry;
+use crate::models::Stash;
+use crate::errors::{DitResult, StashError};
+use crate::helpers::{DitModel, DitModelDefault};
+
+/// Manage the stash file
+impl StashMgr {
+    /// Updates the stash based on the stash file
+    pub(super) fn load(&mut self) -> DitResult<()> {
+        self.stash = Stash::deserialize_default_from(self.repo.stash_file())?;
+        Ok(())
+    }
+
+    /// Updates the stash file based on the current state
+    pub(super) fn store(&self) -> DitResult<()> {
+        self.stash.serialize_to(self.repo.stash_file())
+    }
+}
+
+
+/// Getters
+impl StashMgr {
+    /// Returns the hash of the stash commit at the given position
+    pub fn get_stash_commit(&self, index: usize) -> DitResult<&String> {
+        self.stash.entries
+            .get(index)
+            .ok_or_else(|| StashError::StashDoesNotExist(index).into())
+    }
+
+    /// Returns the hashes of all the stash commits, the most recent first
+    pub fn get_stash_commits(&self) -> &[String] {
+        &self.stash.entries
+    }
+
+    /// Returns all the stash entries, the most recent first
+    pub fn list_stash(&self, commit_mgr: &CommitMgr) -> DitResult<Vec<StashEntry>> {
+        self.stash.entries
+            .iter()
+            .enumerate()
+            .map(|(index, hash)| {
+                let commit = commit_mgr.get_commit(hash)?;
+                Ok(StashEntry {
+                    index,
+                    commit: commit.hash,
+                    message: commit.message,
+                    timestamp: commit.committer.timestamp,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,30 @@
+//! This module manages the stash in the Dit version control system
+//!
+//! The stash is a stack of uncommitted changes put aside for later.
+//! Each entry is a commit whose tree is the state of the tracked files
+//! in the working tree. Its first parent is the commit the changes were
+//! made on, and its second parent is a commit holding the state of the index.
+
+use crate::Repo;
+use crate::errors::DitResult;
+use crate::models::Stash;
+use std::sync::Arc;
+
+/// Manages the stashed changes. See [`crate::stash`] for more info
+pub struct StashMgr {
+    pub(super) repo: Arc<Repo>,
+
+    pub(super) stash: Stash,
+}
+
+/// Constructors
+impl StashMgr {
+    pub fn from(repo: Arc<Repo>) -> DitResult<Self> {
+        let mut mgr = Self {
+            repo,
+            stash: Stash::default(),
+        };
+        Self::load(&mut mgr)?;
+        Ok(mgr)
+    }
+}
@@ -0,0 +1,7 @@
+mod manager;
+mod helpers;
+mod stash_push;
+mod stash_apply;
+mod stash_drop;
+
+pub use manager::StashMgr;
@@ -0,0 +1,102 @@
+use crate::managers::stash::StashMgr;
+use crate::managers::blob::BlobMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::index::IndexMgr;
+use crate::managers::tree::TreeMgr;
+use crate::api_models::checkout::CheckoutMode;
+use crate::api_models::stash::StashApplyResult;
+use crate::errors::{DitResult, StashError};
+
+/// Label of the current commit used in the conflict markers
+const UPSTREAM_LABEL: &str = "Updated upstream";
+
+/// Label of the stashed changes used in the conflict markers
+const STASHED_LABEL: &str = "Stashed changes";
+
+/// Public
+impl StashMgr {
+    /// Applies the stash entry at the given position on top of the current commit.
+    ///
+    /// The stashed changes are merged with the changes made since the commit they
+    /// were stashed on. If the current commit is that same commit, the stashed index
+    /// is restored as well. Otherwise, all the changes are left unstaged
+    pub fn apply_stash(
+        &self,
+        index: usize,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &BranchMgr,
+    ) -> DitResult<StashApplyResult> {
+        if index_mgr.pending_merge().is_some() || index_mgr.has_conflicts() {
+            return Err(StashError::MergeInProgress.into());
+        }
+
+        if index_mgr.are_tracked_changes(tree_mgr, commit_mgr, branch_mgr)?
+            || index_mgr.are_unstaged_changes()?
+        {
+            return Err(StashError::LocalChanges.into());
+        }
+
+        let stash_commit = commit_mgr.get_commit(self.get_stash_commit(index)?)?;
+        let (base, index_commit) = match stash_commit.parents.as_slice() {
+            [base, index_commit] => (base.clone(), index_commit.clone()),
+            _ => return Err(StashError::StashDoesNotExist(index).into()),
+        };
+
+        let head = branch_mgr.get_head_commit().cloned();
+        let head_tree = match &head {
+            Some(head) => Some(commit_mgr.get_commit(head)?.tree),
+            None => None,
+        };
+        let base_tree = commit_mgr.get_commit(&base)?.tree;
+
+        let head_index = match &head_tree {
+            Some(head_tree) => Some(tree_mgr.get_tree_index(head_tree)?),
+            None => None,
+        };
+
+        let merge = tree_mgr.merge_trees(Some(base_tree), head_tree, Some(stash_commit.tree))?;
+
+        // The unmerged files are recorded in the index, so they have to be staged
+        // once resolved
+        let unmerged = index_mgr.apply_tree_merge(
+            merge, head_index.clone(), CheckoutMode::Safe, UPSTREAM_LABEL, STASHED_LABEL, blob_mgr
+        )?;
+
+        let target_index = if head.as_ref() == Some(&base) && unmerged.is_empty() {
+            commit_mgr.get_commit_tree(index_commit, tree_mgr)?
+        } else {
+            head_index.unwrap_or_default()
+        };
+        index_mgr.restore_index(target_index)?;
+
+        if unmerged.is_empty() {
+            Ok(StashApplyResult::Applied)
+        } else {
+            Ok(StashApplyResult::Conflicted(unmerged))
+        }
+    }
+
+    /// Applies the stash entry at the given position and removes it from the stash
+    /// unless applying it resulted in conflicts
+    pub fn pop_stash(
+        &mut self,
+        index: usize,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &CommitMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &BranchMgr,
+    ) -> DitResult<StashApplyResult> {
+        let result = self.apply_stash(index, blob_mgr, tree_mgr, commit_mgr, index_mgr, branch_mgr)?;
+
+        if let StashApplyResult::Applied = result {
+            self.drop_stash(index)?;
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::managers::stash::StashMgr;
+use crate::errors::DitResult;
+
+impl StashMgr {
+    /// Removes the stash entry at the given position and returns its commit hash
+    pub fn drop_stash(&mut self, index: usize) -> DitResult<String> {
+        self.get_stash_commit(index)?;
+
+        let commit = self.stash.entries.remove(index);
+        self.store()?;
+
+        Ok(commit)
+    }
+}
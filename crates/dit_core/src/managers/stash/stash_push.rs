@@ -0,0 +1,91 @@
+use crate::managers::stash::StashMgr;
+use crate::managers::blob::BlobMgr;
+use crate::managers::branch::BranchMgr;
+use crate::managers::commit::CommitMgr;
+use crate::managers::config::ConfigMgr;
+use crate::managers::index::IndexMgr;
+use crate::managers::tree::TreeMgr;
+use crate::models::Index;
+use crate::errors::{DitResult, StashError};
+
+impl StashMgr {
+    /// Saves the staged and unstaged changes of the tracked files as a new stash entry
+    /// and reverts the working tree and the index to the current commit. \
+    /// Untracked files are left untouched. Returns the hash of the stash commit
+    #[allow(clippy::too_many_arguments)]
+    pub fn push_stash(
+        &mut self,
+        message: Option<String>,
+        blob_mgr: &BlobMgr,
+        tree_mgr: &TreeMgr,
+        commit_mgr: &mut CommitMgr,
+        index_mgr: &mut IndexMgr,
+        branch_mgr: &BranchMgr,
+        config_mgr: &ConfigMgr,
+    ) -> DitResult<String> {
+        if index_mgr.pending_merge().is_some() || index_mgr.has_conflicts() {
+            return Err(StashError::MergeInProgress.into());
+        }
+
+        let head = branch_mgr.get_head_commit()
+            .cloned()
+            .ok_or(StashError::NoInitialCommit)?;
+        let head_index = commit_mgr.get_commit_tree(&head, tree_mgr)?;
+
+        let staged = index_mgr.index().clone();
+        let worktree = index_mgr.snapshot_working_tree(blob_mgr)?;
+
+        if Self::same_files(&staged, &head_index) && Self::same_files(&worktree, &staged) {
+            return Err(StashError::NoLocalChanges.into());
+        }
+
+        let author = CommitMgr::user_signature_now(config_mgr)?;
+        let head_commit = commit_mgr.get_commit(&head)?;
+        let branch = branch_mgr.get_current_branch()
+            .cloned()
+            .unwrap_or_else(|| String::from("(no branch)"));
+        let description = match message {
+            Some(message) => format!("On {branch}: {message}"),
+            None => format!("WIP on {branch}: {} {}", &head[..head.len().min(12)], head_commit.message),
+        };
+
+        let index_tree = tree_mgr.create_tree(staged)?;
+        let index_commit = commit_mgr.create_commit_from_tree(
+            format!("index on {branch}"),
+            author.clone(),
+            author.clone(),
+            index_tree,
+            vec![head.clone()],
+            config_mgr,
+        )?;
+
+        let worktree_tree = tree_mgr.create_tree(worktree.clone())?;
+        let stash_commit = commit_mgr.create_commit_from_tree(
+            description,
+            author.clone(),
+            author,
+            worktree_tree,
+            vec![head, index_commit],
+            config_mgr,
+        )?;
+
+        self.stash.entries.insert(0, stash_commit.clone());
+        self.store()?;
+
+        index_mgr.checkout_index_over(&worktree, head_index, blob_mgr)?;
+
+        Ok(stash_commit)
+    }
+}
+
+
+/// Private
+impl StashMgr {
+    /// Checks whether two indexes contain the same files with the same content
+    fn same_files(first: &Index, second: &Index) -> bool {
+        first.files.len() == second.files.len()
+            && first.files.iter().zip(&second.files).all(|((p1, e1), (p2, e2))| {
+                p1 == p2 && e1.hash == e2.hash
+            })
+    }
+}
@@ -0,0 +1,108 @@
+use crate::managers::tag::TagMgr;
+use crate::api_models::tag::TagInfo;
+use crate::models::{Tag, TagRef};
+use crate::errors::{DitResult, TagError};
+use crate::helpers::{is_temp_file_name, read_bytes, read_to_string, write_atomic};
+use std::path::PathBuf;
+use std::fs;
+
+/// Load/write to the tag objects directory
+impl TagMgr {
+    /// Writes the given tag object to the tag objects directory
+    pub(super) fn write_tag_object(&self, tag: &Tag) -> DitResult<()> {
+        let path = self.repo.tag_objects().join(&tag.hash);
+
+        write_atomic(path, Self::encode_tag(tag))
+    }
+
+    /// Reads and returns a tag object given its hash
+    pub fn get_tag_object<S: Into<String>>(&self, hash: S) -> DitResult<Tag> {
+        let hash = hash.into();
+        let path = self.repo.tag_objects().join(&hash);
+
+        let mut tag = Self::decode_tag(&read_bytes(&path)?)?;
+        tag.hash = hash;
+
+        Ok(tag)
+    }
+}
+
+
+/// Getters
+impl TagMgr {
+    /// Returns the tag with the commit it points to, or `None` if the tag doesn't exist
+    pub fn get_tag<S: AsRef<str>>(&self, name: S) -> DitResult<Option<TagInfo>> {
+        let name = name.as_ref();
+        let (exists, path) = self.find_tag(name);
+
+        if !exists {
+            return Ok(None);
+        }
+
+        let tag_ref = TagRef::parse(&read_to_string(&path)?)
+            .ok_or_else(|| TagError::InvalidTagRef(name.to_string()))?;
+
+        // An annotated tag points to a tag object instead of a commit
+        match tag_ref {
+            TagRef::Commit(commit) => Ok(Some(TagInfo {
+                name: name.to_string(),
+                commit,
+                annotation: None,
+            })),
+            TagRef::TagObject(hash) => {
+                let tag = self.get_tag_object(hash)?;
+                Ok(Some(TagInfo {
+                    name: name.to_string(),
+                    commit: tag.commit.clone(),
+                    annotation: Some(tag),
+                }))
+            }
+        }
+    }
+
+    /// Returns the hash of the commit which the tag points to
+    pub fn get_tag_commit<S: AsRef<str>>(&self, name: S) -> DitResult<Option<String>> {
+        Ok(self.get_tag(name)?.map(|tag| tag.commit))
+    }
+
+    /// Returns all the tags sorted by name
+    pub fn list_tags(&self) -> DitResult<Vec<TagInfo>> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(self.repo.tags())? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type()?.is_file() && !is_temp_file_name(&name) {
+                names.push(name);
+            }
+        }
+        names.sort();
+
+        let mut tags = Vec::with_capacity(names.len());
+        for name in names {
+            if let Some(tag) = self.get_tag(name)? {
+                tags.push(tag);
+            }
+        }
+
+        Ok(tags)
+    }
+
+    /// Checks whether the name can be used as a tag name. \
+    /// Besides the characters allowed in branch names, tags may contain dots
+    /// (for example "v1.0.0"), but cannot start with one
+    pub fn is_valid_tag_name<S: AsRef<str>>(name: S) -> bool {
+        let name = name.as_ref();
+        !name.is_empty()
+            && !name.starts_with('.')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    }
+
+    /// Returns a bool indicating whether the tag exists or not and
+    /// the path to that tag file
+    pub(super) fn find_tag<S: AsRef<str>>(&self, name: S) -> (bool, PathBuf) {
+        let name = name.as_ref();
+        let path = self.repo.tags().join(name);
+
+        (path.is_file(), path)
+    }
+}
@@ -0,0 +1,20 @@
+//! This module manages the tags in the Dit version control system
+//!
+//! A tag is a named, immutable reference to a commit. Lightweight tags
+//! store the commit hash directly, while annotated tags reference a tag
+//! object which records the tagger, the creation time and a message.
+
+use crate::Repo;
+use std::sync::Arc;
+
+/// Manages the tags in our Dit version control system
+pub struct TagMgr {
+    pub(super) repo: Arc<Repo>,
+}
+
+/// Constructors
+impl TagMgr {
+    pub fn from(repo: Arc<Repo>) -> Self {
+        Self { repo }
+    }
+}
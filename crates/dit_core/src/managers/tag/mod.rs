@@ -0,0 +1,7 @@
+mod manager;
+mod helpers;
+mod tag_create;
+mod tag_delete;
+mod tag_encoding;
+
+pub use manager::TagMgr;
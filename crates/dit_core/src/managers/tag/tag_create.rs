@@ -0,0 +1,94 @@
+use crate::managers::tag::TagMgr;
+use crate::models::{Tag, TagRef};
+use crate::helpers::{write_atomic, DitHasher};
+use crate::errors::{DitResult, OtherError, TagError};
+use std::time::SystemTime;
+
+impl TagMgr {
+    /// Creates a lightweight tag pointing directly to the given commit
+    pub fn create_tag<S1, S2>(&mut self, name: S1, commit: S2) -> DitResult<()>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let name = name.as_ref();
+        let path = self.require_new_tag(name)?;
+
+        write_atomic(&path, TagRef::Commit(commit.as_ref().to_string()).to_string())?;
+
+        Ok(())
+    }
+
+    /// Creates an annotated tag - writes a tag object describing the tagger,
+    /// the time and the message, and points the tag to that object
+    pub fn create_annotated_tag<S1, S2, S3, S4>(
+        &mut self,
+        name: S1,
+        commit: S2,
+        tagger: S3,
+        message: S4,
+    ) -> DitResult<String>
+    where
+        S1: AsRef<str>,
+        S2: Into<String>,
+        S3: Into<String>,
+        S4: Into<String>,
+    {
+        let name = name.as_ref();
+        let path = self.require_new_tag(name)?;
+
+        let commit = commit.into();
+        let tagger = tagger.into();
+        let message = message.into();
+
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|_| OtherError::TimeWentBackwardsError)?
+            .as_secs();
+
+        let mut tag = Tag {
+            name: name.to_string(),
+            commit,
+            tagger,
+            message,
+            timestamp,
+            hash: String::new(),
+        };
+        tag.hash = Self::hash_tag(&tag);
+
+        self.write_tag_object(&tag)?;
+        write_atomic(&path, TagRef::TagObject(tag.hash.clone()).to_string())?;
+
+        Ok(tag.hash)
+    }
+}
+
+
+/// Hashing
+impl TagMgr {
+    /// Calculates the hash of a tag object given its content: the hash of its canonical encoding
+    pub fn hash_tag(tag: &Tag) -> String {
+        let mut hasher = DitHasher::new();
+        hasher.update(&Self::encode_tag(tag));
+        hasher.finalize_string()
+    }
+}
+
+
+/// Private
+impl TagMgr {
+    /// Validates the name of a tag which is about to be created and
+    /// returns the path of its tag file
+    fn require_new_tag(&self, name: &str) -> DitResult<std::path::PathBuf> {
+        if !Self::is_valid_tag_name(name) {
+            return Err(TagError::InvalidTagName(name.to_string()).into());
+        }
+
+        let (exists, path) = self.find_tag(name);
+        if exists {
+            return Err(TagError::TagAlreadyExists(name.to_string()).into());
+        }
+
+        Ok(path)
+    }
+}
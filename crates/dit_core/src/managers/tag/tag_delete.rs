@@ -0,0 +1,18 @@
+use crate::managers::tag::TagMgr;
+use crate::errors::{DitResult, TagError};
+use crate::helpers::remove_file_if_exists;
+
+impl TagMgr {
+    /// Deletes a given tag. The tag object of an annotated tag is kept
+    pub fn delete_tag<S: AsRef<str>>(&mut self, name: S) -> DitResult<()> {
+        let name = name.as_ref();
+
+        let (exists, path) = self.find_tag(name);
+
+        if !exists {
+            return Err(TagError::TagDoesNotExist(name.to_string()).into());
+        }
+
+        remove_file_if_exists(&path)
+    }
+}
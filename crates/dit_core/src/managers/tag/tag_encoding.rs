@@ -0,0 +1,46 @@
+use crate::managers::tag::TagMgr;
+use crate::api_models::object::ObjectKind;
+use crate::models::{Tag, TAG_FORMAT_VERSION};
+use crate::helpers::{ObjectDecoder, ObjectEncoder};
+use crate::errors::{DitResult, ObjectError, TagError};
+
+/// Public
+impl TagMgr {
+    /// Encodes a tag object in the canonical encoding: the name, the tagged commit,
+    /// the tagger, the message and the creation time
+    pub fn encode_tag(tag: &Tag) -> Vec<u8> {
+        let mut encoder = ObjectEncoder::new(ObjectKind::TagObject, TAG_FORMAT_VERSION);
+
+        encoder.str(&tag.name);
+        encoder.str(&tag.commit);
+        encoder.str(&tag.tagger);
+        encoder.str(&tag.message);
+        encoder.u64(tag.timestamp);
+
+        encoder.finish()
+    }
+
+    /// Decodes a stored tag object. A tag object which isn't in the canonical form is rejected
+    pub fn decode_tag(bytes: &[u8]) -> DitResult<Tag> {
+        let (mut decoder, version) = ObjectDecoder::new(ObjectKind::TagObject, bytes)?;
+        if version != TAG_FORMAT_VERSION {
+            return Err(TagError::UnsupportedVersion(version).into());
+        }
+
+        let tag = Tag {
+            name: decoder.str()?,
+            commit: decoder.str()?,
+            tagger: decoder.str()?,
+            message: decoder.str()?,
+            timestamp: decoder.u64()?,
+            hash: String::new(),
+        };
+        decoder.finish()?;
+
+        if Self::encode_tag(&tag) != bytes {
+            return Err(ObjectError::NonCanonical(ObjectKind::TagObject.to_string()).into());
+        }
+
+        Ok(tag)
+    }
+}
@@ -1,22 +1,74 @@
 use crate::errors::DitResult;
-use crate::helpers::DitModel;
+use crate::helpers::{read_bytes, write_atomic};
 use crate::managers::tree::TreeMgr;
-use crate::models::Tree;
+use crate::models::{Index, IndexEntry, Tree};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 
 /// Manage tree files
 impl TreeMgr {
     /// Reads and returns a tree from the tree's hash
     pub fn get_tree(&self, tree_hash: String) -> DitResult<Tree> {
+        let mut tree = Self::decode_tree(&self.read_tree_bytes(&tree_hash)?)?;
+
+        tree.hash = tree_hash;
+        Ok(tree)
+    }
+
+    /// Reads the tree (either loose or packed) as it's stored, see [`TreeMgr::decode_tree`]
+    pub fn read_tree_bytes(&self, tree_hash: &str) -> DitResult<Vec<u8>> {
         let path = self.repo.trees().join(tree_hash);
+        if !path.is_file()
+            && let Some(content) = self.packs.read_object(tree_hash)?
+        {
+            return Ok(content);
+        }
+
+        read_bytes(&path)
+    }
 
-        Tree::deserialize_from(&path)
+    /// Checks whether the tree exists (either loose or packed)
+    pub fn has_tree(&self, tree_hash: &str) -> DitResult<bool> {
+        Ok(self.repo.trees().join(tree_hash).is_file() || self.packs.contains(tree_hash)?)
     }
-    
+
     /// Writes the tree to the trees directory
-    pub(super) fn write_tree(&self, tree: &Tree) -> DitResult<()> {
+    pub fn write_tree(&self, tree: &Tree) -> DitResult<()> {
         let path = self.repo.trees().join(&tree.hash);
-        
-        tree.serialize_to(&path)
+
+        write_atomic(path, Self::stored_bytes(tree)?)
+    }
+
+    /// Reads a tree with all its subtrees and returns all the files it contains as an index.
+    /// The fingerprints of the entries are unknown, see [`IndexEntry::from_tree`]
+    pub fn get_tree_index(&self, tree_hash: &str) -> DitResult<Index> {
+        let mut files = BTreeMap::new();
+        self.collect_files(tree_hash, Path::new(""), &mut files)?;
+
+        Ok(Index { files, ..Default::default() })
+    }
+}
+
+
+/// Private
+impl TreeMgr {
+    /// Adds the files of a tree (and its subtrees) to the map, under the given directory
+    fn collect_files(
+        &self,
+        tree_hash: &str,
+        dir: &Path,
+        files: &mut BTreeMap<PathBuf, IndexEntry>,
+    ) -> DitResult<()> {
+        for (name, entry) in self.get_tree(tree_hash.to_string())?.entries {
+            let rel_path = dir.join(name);
+            if entry.is_tree() {
+                self.collect_files(&entry.hash, &rel_path, files)?;
+            } else {
+                files.insert(rel_path, IndexEntry::from_tree(entry.hash));
+            }
+        }
+
+        Ok(())
     }
 }
@@ -1,43 +1,56 @@
 ﻿//! This module manages the trees in dit version control system
 //!
-//! Trees are *snapshots* of all files included in the repo during a particular commit.
-//! They store all files and their corresponding blob hashes. Each commit has one
-//! corresponding tree.
+//! Trees are *snapshots* of the files included in the repo during a particular commit.
+//! Each tree describes a single directory: it maps the names of its files to their blob
+//! hashes and the names of its subdirectories to their own trees. Each commit has one
+//! corresponding (root) tree.
 //!
-//! Let's say we do an initial commit with only one file, `a.txt`.
-//! We can imagine the tree for this commit as something like this (simplified): \
-//! `.dit/trees/18b7cb09..`
+//! Let's say we do an initial commit with two files, `a.txt` and `src/main.rs`.
+//! We can imagine the trees for this commit as something like this (simplified, and shown as
+//! JSON, though they are stored in the canonical encoding, see [`crate::helpers::ObjectEncoder`]): \
+//! `.dit/trees/18b7cb09..` (the root tree)
 //! ```json
 //! {
-//!     "files": {
-//!         "a.txt": "b1ac8a822.."
+//!     "version": 2,
+//!     "entries": {
+//!         "a.txt": { "kind": "blob", "mode": 33188, "hash": "b1ac8a822.." },
+//!         "src": { "kind": "tree", "mode": 16384, "hash": "5f0e21ac.." }
 //!     }
 //! }
 //! ```
-//!
-//! Suppose in the next commit we commited a single new file, `b.txt` and did not change `a.txt`
-//! The tree for this commit will look like this: \
-//! `.dit/trees/c52b214f..`
+//! `.dit/trees/5f0e21ac..`
 //! ```json
 //! {
-//!     "files": {
-//!         "a.txt": "b1ac8a82..",
-//!         "b.txt": "273c662e.."
+//!     "version": 2,
+//!     "entries": {
+//!         "main.rs": { "kind": "blob", "mode": 33188, "hash": "273c662e.." }
 //!     }
 //! }
 //! ```
+//!
+//! Suppose in the next commit we changed only `a.txt`. A new root tree is written, but it
+//! points to the same `5f0e21ac..` tree for `src`, so the unchanged directories are shared
+//! between the commits and are skipped when the trees are compared.
+//!
+//! Trees created by the first version of Dit are *legacy* trees: a single flat tree listing
+//! every file of the repository, stored as JSON. They can still be read and keep their
+//! hashes, but are never created anymore.
 
 use crate::Repo;
+use crate::managers::pack::PackStore;
 use std::sync::Arc;
 
 /// Manages the trees in our Dit version control system
 pub struct TreeMgr {
     pub(super) repo: Arc<Repo>,
+
+    /// Represents the packed trees, which are read if there is no loose tree
+    pub(super) packs: PackStore,
 }
 
 /// Constructors
 impl TreeMgr {
     pub fn from(repo: Arc<Repo>) -> Self {
-        Self { repo }
+        Self { packs: PackStore::from(repo.clone()), repo }
     }
 }
@@ -2,6 +2,9 @@ mod manager;
 mod tree_create;
 mod helpers;
 mod tree_recover;
+mod tree_merge;
+mod tree_walk;
+mod tree_encoding;
 
 pub use manager::TreeMgr;
 
@@ -1,25 +1,134 @@
 use crate::managers::tree::TreeMgr;
-use crate::models::{Index, Tree};
-use crate::errors::DitResult;
+use crate::models::{Index, IndexEntry, Tree, TreeEntry, LEGACY_TREE_FORMAT_VERSION, TREE_FORMAT_VERSION};
+use crate::errors::{DitResult, TreeError};
 use crate::helpers::DitHasher;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
+/// Represents a directory of an index while its trees are being built
+#[derive(Default)]
+struct DirNode {
+    /// Maps the names of the subdirectories to their contents
+    dirs: BTreeMap<String, DirNode>,
+
+    /// Maps the names of the files to their blob hashes
+    files: BTreeMap<String, String>,
+}
+
+/// Public
 impl TreeMgr {
-    /// Creates a tree from an index and returns the tree hash
+    /// Creates the trees of all the directories of an index and returns the hash of the root tree.
+    ///
+    /// The trees which already exist (e.g. of the directories which haven't changed since
+    /// the previous commit) aren't written again, so they are shared between the commits
     pub fn create_tree(
         &self,
         index: Index
     ) -> DitResult<String> {
+        let trees = Self::build_trees(&index.files)?;
+        let hash = Self::root_hash(&trees);
+
+        for tree in &trees {
+            if !self.has_tree(&tree.hash)? {
+                self.write_tree(tree)?;
+            }
+        }
+
+        Ok(hash)
+    }
+
+    /// Checks whether the tree (which may be of an older format) contains exactly the files of the index
+    pub fn matches_index(&self, tree_hash: &str, index: &Index) -> DitResult<bool> {
+        let actual = match self.get_tree(tree_hash.to_string())?.is_legacy() {
+            true => Self::hash_tree(&Self::legacy_tree(&index.files)),
+            false => Self::root_hash(&Self::build_trees(&index.files)?),
+        };
+
+        Ok(actual == tree_hash)
+    }
+
+    /// Calculates the hash of a tree given its content: the hash of its canonical encoding.
+    /// A legacy tree is hashed the way it was when it was created
+    pub fn hash_tree(tree: &Tree) -> String {
         let mut hasher = DitHasher::new();
-        for (rel_path, entry) in &index.files {
+
+        if !tree.is_legacy() {
+            hasher.update(&Self::encode_tree(tree));
+            return hasher.finalize_string();
+        }
+
+        // The files were ordered as paths, not as strings
+        let files: BTreeMap<PathBuf, &TreeEntry> = tree.entries
+            .iter()
+            .map(|(rel_path, entry)| (PathBuf::from(rel_path), entry))
+            .collect();
+
+        for (rel_path, entry) in files {
             hasher.update(rel_path.to_string_lossy().as_bytes());
             hasher.update(entry.hash.as_bytes());
         }
-        let hash = hasher.finalize_string();
+        hasher.finalize_string()
+    }
+}
+
+
+/// Private
+impl TreeMgr {
+    /// Builds the trees of all the directories of the files,
+    /// every tree after the trees of its subdirectories (so the root tree is the last one)
+    fn build_trees(files: &BTreeMap<PathBuf, IndexEntry>) -> DitResult<Vec<Tree>> {
+        let mut root = DirNode::default();
+
+        for (rel_path, entry) in files {
+            let names = rel_path
+                .iter()
+                .map(|name| name.to_str().map(str::to_string))
+                .collect::<Option<Vec<String>>>()
+                .ok_or_else(|| TreeError::NonUtf8Path(rel_path.to_string_lossy().into_owned()))?;
+            let Some((file_name, dir_names)) = names.split_last() else { continue };
+
+            let mut dir = &mut root;
+            for name in dir_names {
+                dir = dir.dirs.entry(name.clone()).or_default();
+            }
+            dir.files.insert(file_name.clone(), entry.hash.clone());
+        }
 
-        let tree = Tree { index, hash: hash.clone(), };
+        let mut trees = Vec::new();
+        Self::build_dir(root, &mut trees);
+        Ok(trees)
+    }
 
-        self.write_tree(&tree)?;
+    /// Builds the trees of a directory and its subdirectories and returns the hash of its tree
+    fn build_dir(dir: DirNode, trees: &mut Vec<Tree>) -> String {
+        let mut entries = BTreeMap::new();
+        for (name, subdir) in dir.dirs {
+            entries.insert(name, TreeEntry::tree(Self::build_dir(subdir, trees)));
+        }
+        for (name, hash) in dir.files {
+            entries.insert(name, TreeEntry::blob(hash));
+        }
 
-        Ok(hash)
+        let mut tree = Tree { version: TREE_FORMAT_VERSION, entries, hash: String::new() };
+        tree.hash = Self::hash_tree(&tree);
+
+        let hash = tree.hash.clone();
+        trees.push(tree);
+        hash
+    }
+
+    /// Returns the hash of the root tree among the built trees
+    fn root_hash(trees: &[Tree]) -> String {
+        trees.last().map(|tree| tree.hash.clone()).unwrap_or_default()
+    }
+
+    /// Builds a legacy (flat) tree of the files
+    fn legacy_tree(files: &BTreeMap<PathBuf, IndexEntry>) -> Tree {
+        let entries = files
+            .iter()
+            .map(|(rel_path, entry)| (rel_path.to_string_lossy().into_owned(), TreeEntry::blob(entry.hash.clone())))
+            .collect();
+
+        Tree { version: LEGACY_TREE_FORMAT_VERSION, entries, hash: String::new() }
     }
 }
@@ -0,0 +1,119 @@
+use crate::managers::tree::TreeMgr;
+use crate::api_models::object::ObjectKind;
+use crate::models::{is_valid_entry_name, Tree, TreeEntry, TreeEntryKind, DIR_MODE, FILE_MODE, TREE_FORMAT_VERSION};
+use crate::helpers::{is_encoded_object, ObjectDecoder, ObjectEncoder};
+use crate::errors::{DitResult, ObjectError, TreeError};
+use std::collections::BTreeMap;
+
+/// Public
+impl TreeMgr {
+    /// Encodes a tree in the canonical encoding. Every entry is encoded as its name,
+    /// its mode (which tells its kind) and its hash, ordered by the names
+    pub fn encode_tree(tree: &Tree) -> Vec<u8> {
+        let mut encoder = ObjectEncoder::new(ObjectKind::Tree, tree.version);
+
+        encoder.u64(tree.entries.len() as u64);
+        for (name, entry) in &tree.entries {
+            encoder.str(name);
+            encoder.u32(entry.mode);
+            encoder.str(&entry.hash);
+        }
+
+        encoder.finish()
+    }
+
+    /// Decodes a stored tree, either in the canonical encoding or as a legacy
+    /// JSON document. A tree which isn't in the canonical form (e.g. with unordered
+    /// entries) is rejected, since its hash wouldn't match its content
+    pub fn decode_tree(bytes: &[u8]) -> DitResult<Tree> {
+        if !is_encoded_object(bytes) {
+            return Ok(serde_json::from_slice(bytes)?);
+        }
+
+        let (mut decoder, version) = ObjectDecoder::new(ObjectKind::Tree, bytes)?;
+        if version != TREE_FORMAT_VERSION {
+            return Err(TreeError::UnsupportedVersion(version).into());
+        }
+
+        let mut entries = BTreeMap::new();
+        for _ in 0..decoder.u64()? {
+            let name = decoder.str()?;
+            let mode = decoder.u32()?;
+            let hash = decoder.str()?;
+
+            let kind = match mode {
+                FILE_MODE => TreeEntryKind::Blob,
+                DIR_MODE => TreeEntryKind::Tree,
+                _ => return Err(decoder.malformed(format!("unknown mode {mode:o}"))),
+            };
+            if !is_valid_entry_name(&name) {
+                return Err(TreeError::InvalidEntryName(name).into());
+            }
+
+            entries.insert(name, TreeEntry { kind, mode, hash });
+        }
+        decoder.finish()?;
+
+        let tree = Tree { version, entries, hash: String::new() };
+        if Self::encode_tree(&tree) != bytes {
+            return Err(ObjectError::NonCanonical(ObjectKind::Tree.to_string()).into());
+        }
+
+        Ok(tree)
+    }
+}
+
+
+/// Private
+impl TreeMgr {
+    /// Returns the bytes a tree is stored as. A legacy tree
+    /// is stored as JSON, like it was when it was created
+    pub(super) fn stored_bytes(tree: &Tree) -> DitResult<Vec<u8>> {
+        match tree.is_legacy() {
+            true => Ok(serde_json::to_vec_pretty(tree)?),
+            false => Ok(Self::encode_tree(tree)),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::DitCoreError;
+
+    fn tree_with_entry(name: &str) -> Tree {
+        let entries = BTreeMap::from([(name.to_string(), TreeEntry::blob(String::from("0123abcd")))]);
+        Tree { version: TREE_FORMAT_VERSION, entries, hash: String::new() }
+    }
+
+    fn assert_invalid_entry_name(result: DitResult<Tree>, name: &str) {
+        match result {
+            Err(DitCoreError::TreeError(TreeError::InvalidEntryName(invalid))) => assert_eq!(invalid, name),
+            other => panic!("expected the entry name {name:?} to be rejected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_a_valid_tree() {
+        let tree = tree_with_entry("file.txt");
+        let decoded = TreeMgr::decode_tree(&TreeMgr::encode_tree(&tree)).unwrap();
+        assert_eq!(decoded.entries.len(), 1);
+        assert!(decoded.entries.contains_key("file.txt"));
+    }
+
+    #[test]
+    fn rejects_the_metadata_directory_as_an_entry() {
+        for name in [".dit", ".DIT", ".Dit"] {
+            let bytes = TreeMgr::encode_tree(&tree_with_entry(name));
+            assert_invalid_entry_name(TreeMgr::decode_tree(&bytes), name);
+        }
+    }
+
+    #[test]
+    fn rejects_the_metadata_directory_in_legacy_trees() {
+        let legacy = br#"{ "index": { "files": { ".dit/config": { "hash": "0123abcd" } } } }"#;
+        let error = TreeMgr::decode_tree(legacy).unwrap_err().to_string();
+        assert!(error.contains("invalid entry name '.dit/config'"), "{error}");
+    }
+}
@@ -0,0 +1,83 @@
+use crate::managers::tree::TreeMgr;
+use crate::models::{ConflictEntry, Index, IndexEntry, TreeMerge};
+use crate::errors::DitResult;
+use std::collections::BTreeSet;
+
+impl TreeMgr {
+    /// Performs a file-level three-way merge of two trees given their merge base.
+    ///
+    /// `None` stands for an empty tree (e.g. when the branches have no common history)
+    pub fn merge_trees(
+        &self,
+        base: Option<String>,
+        ours: Option<String>,
+        theirs: Option<String>,
+    ) -> DitResult<TreeMerge> {
+        let base = self.get_tree_files(base)?;
+        let ours = self.get_tree_files(ours)?;
+        let theirs = self.get_tree_files(theirs)?;
+
+        Ok(Self::merge_indexes(&base, &ours, &theirs))
+    }
+
+    /// Performs a file-level three-way merge of two indexes given their merge base.
+    ///
+    /// A file is resolved automatically if only one side changed it compared
+    /// to the base, or if both sides changed it identically. Otherwise, it's
+    /// recorded as a conflict
+    pub fn merge_indexes(base: &Index, ours: &Index, theirs: &Index) -> TreeMerge {
+        let paths: BTreeSet<_> = base.files.keys()
+            .chain(ours.files.keys())
+            .chain(theirs.files.keys())
+            .collect();
+
+        let mut merge = TreeMerge::default();
+
+        for rel_path in paths {
+            let in_base = base.files.get(rel_path);
+            let in_ours = ours.files.get(rel_path);
+            let in_theirs = theirs.files.get(rel_path);
+
+            let resolved = if same_content(in_ours, in_theirs) || same_content(in_base, in_theirs) {
+                in_ours
+            } else if same_content(in_base, in_ours) {
+                in_theirs
+            } else {
+                merge.conflicts.insert(rel_path.clone(), ConflictEntry {
+                    base: in_base.map(|e| e.hash.clone()),
+                    ours: in_ours.map(|e| e.hash.clone()),
+                    theirs: in_theirs.map(|e| e.hash.clone()),
+                });
+                in_ours
+            };
+
+            if let Some(entry) = resolved {
+                merge.index.files.insert(rel_path.clone(), entry.clone());
+            }
+        }
+
+        merge
+    }
+}
+
+
+/// Private
+impl TreeMgr {
+    /// Returns the files of a tree, or an empty index if there is no tree
+    fn get_tree_files(&self, tree_hash: Option<String>) -> DitResult<Index> {
+        match tree_hash {
+            Some(tree_hash) => self.get_tree_index(&tree_hash),
+            None => Ok(Index::default()),
+        }
+    }
+}
+
+
+/// Checks whether two (possibly missing) files have the same content
+fn same_content(first: Option<&IndexEntry>, second: Option<&IndexEntry>) -> bool {
+    match (first, second) {
+        (Some(first), Some(second)) => first.hash == second.hash,
+        (None, None) => true,
+        _ => false,
+    }
+}
@@ -14,7 +14,7 @@ impl TreeMgr {
         blob_mgr: &mut BlobMgr
     ) -> DitResult<()>
     {
-        let index = self.get_tree(tree_hash)?.index;
+        let index = self.get_tree_index(&tree_hash)?;
 
         index.files
             .into_par_iter()
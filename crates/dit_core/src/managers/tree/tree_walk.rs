@@ -0,0 +1,124 @@
+use crate::managers::tree::TreeMgr;
+use crate::models::{Index, IndexEntry, TreeEntry};
+use crate::errors::DitResult;
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+/// Public
+impl TreeMgr {
+    /// Compares two trees and returns the files which differ between them, as the files
+    /// of the old tree and the files of the new tree. `None` stands for an empty tree.
+    ///
+    /// The subtrees which are the same in both trees are skipped without being read
+    pub fn diff_trees(&self, old: Option<&str>, new: Option<&str>) -> DitResult<(Index, Index)> {
+        let mut old_index = Index::default();
+        let mut new_index = Index::default();
+        self.diff_dirs(old, new, Path::new(""), &mut old_index, &mut new_index)?;
+
+        Ok((old_index, new_index))
+    }
+
+    /// Adds the hashes of a tree, its subtrees and their blobs to the sets.
+    /// The trees which are already in the set are skipped along with their subtrees
+    pub fn collect_objects(
+        &self,
+        tree_hash: &str,
+        trees: &mut HashSet<String>,
+        blobs: &mut HashSet<String>,
+    ) -> DitResult<()> {
+        if !trees.insert(tree_hash.to_string()) {
+            return Ok(());
+        }
+
+        for entry in self.get_tree(tree_hash.to_string())?.entries.into_values() {
+            if entry.is_tree() {
+                self.collect_objects(&entry.hash, trees, blobs)?;
+            } else {
+                blobs.insert(entry.hash);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Private
+impl TreeMgr {
+    /// Adds the files which differ between two trees of the same directory to the indexes
+    fn diff_dirs(
+        &self,
+        old: Option<&str>,
+        new: Option<&str>,
+        dir: &Path,
+        old_index: &mut Index,
+        new_index: &mut Index,
+    ) -> DitResult<()> {
+        if old == new {
+            return Ok(());
+        }
+
+        let old_tree = old.map(|hash| self.get_tree(hash.to_string())).transpose()?;
+        let new_tree = new.map(|hash| self.get_tree(hash.to_string())).transpose()?;
+
+        // Legacy trees are flat, so they are compared file by file
+        if old_tree.as_ref().is_some_and(|tree| tree.is_legacy())
+            || new_tree.as_ref().is_some_and(|tree| tree.is_legacy())
+        {
+            let old_files = old.map(|hash| self.get_tree_index(hash)).transpose()?.unwrap_or_default();
+            let new_files = new.map(|hash| self.get_tree_index(hash)).transpose()?.unwrap_or_default();
+            Self::diff_files(old_files, new_files, old_index, new_index);
+            return Ok(());
+        }
+
+        let old_entries = old_tree.map(|tree| tree.entries).unwrap_or_default();
+        let new_entries = new_tree.map(|tree| tree.entries).unwrap_or_default();
+        let names: BTreeSet<&String> = old_entries.keys().chain(new_entries.keys()).collect();
+
+        for name in names {
+            let in_old = old_entries.get(name);
+            let in_new = new_entries.get(name);
+            if in_old == in_new {
+                continue;
+            }
+
+            let rel_path = dir.join(name);
+            self.diff_dirs(subtree(in_old), subtree(in_new), &rel_path, old_index, new_index)?;
+
+            if let Some(hash) = blob(in_old) {
+                old_index.files.insert(rel_path.clone(), IndexEntry::from_tree(hash));
+            }
+            if let Some(hash) = blob(in_new) {
+                new_index.files.insert(rel_path, IndexEntry::from_tree(hash));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the files which differ between two flat lists of files to the indexes
+    fn diff_files(old: Index, new: Index, old_index: &mut Index, new_index: &mut Index) {
+        for (rel_path, entry) in &old.files {
+            if new.files.get(rel_path).is_none_or(|in_new| in_new.hash != entry.hash) {
+                old_index.files.insert(rel_path.clone(), entry.clone());
+            }
+        }
+
+        for (rel_path, entry) in new.files {
+            if old.files.get(&rel_path).is_none_or(|in_old| in_old.hash != entry.hash) {
+                new_index.files.insert(rel_path, entry);
+            }
+        }
+    }
+}
+
+
+/// Returns the hash of the subtree the entry points to, if it's a directory
+fn subtree(entry: Option<&TreeEntry>) -> Option<&str> {
+    entry.filter(|entry| entry.is_tree()).map(|entry| entry.hash.as_str())
+}
+
+/// Returns the hash of the blob the entry points to, if it's a file
+fn blob(entry: Option<&TreeEntry>) -> Option<&str> {
+    entry.filter(|entry| !entry.is_tree()).map(|entry| entry.hash.as_str())
+}
@@ -1,4 +1,4 @@
-use crate::models::FileFingerprint;
+use crate::models::{ConflictEntry, FileFingerprint};
 
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Change {
@@ -6,6 +6,7 @@ pub enum Change {
     Modified(ModifiedFile),
     Deleted(DeletedFile),
     Unchanged(UnchangedFile),
+    Conflicted(ConflictEntry), // if the file is unmerged
     None, // if the file does not exist and did not exist
 }
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
@@ -0,0 +1,69 @@
+use crate::models::Index;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Represents a working tree update in progress (a branch switch or a hard reset). It is
+/// recorded before any file is changed, so that an interrupted update can be continued
+/// or rolled back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckoutJournal {
+    /// Describes the operation \
+    /// Example: "switch: moving from main to feature"
+    pub description: String,
+
+    /// Represents the step the update has reached
+    pub step: CheckoutStep,
+
+    /// Represents the tree being checked out (`None` for a branch without commits)
+    pub tree: Option<String>,
+
+    /// Represents the current branch before the update (`None` when detached)
+    pub orig_branch: Option<String>,
+
+    /// Represents the current commit before the update
+    pub orig_head: Option<String>,
+
+    /// Represents the current branch after the update (`None` when detached)
+    pub branch: Option<String>,
+
+    /// Represents the current commit after the update
+    pub head: Option<String>,
+
+    /// Represents the index before the update
+    pub orig_index: Index,
+
+    /// Represents the index after the update
+    pub index: Index,
+
+    /// Represents the files which are written or removed by the update
+    pub files: Vec<CheckoutFile>,
+}
+
+/// Represents the step a working tree update has reached
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CheckoutStep {
+    /// The files are being written or removed
+    Files,
+
+    /// All the files are written, the index and HEAD are being updated
+    Refs,
+}
+
+/// Represents a file written or removed by a working tree update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckoutFile {
+    /// Represents the relative path of the file
+    pub path: PathBuf,
+
+    /// Represents the blob of the file before the update (`None` if it didn't exist
+    /// or was untracked)
+    pub before: Option<String>,
+
+    /// Represents the blob of the file after the update (`None` if it's removed)
+    pub after: Option<String>,
+
+    /// Represents the blob of the local modifications merged into the file, written over
+    /// it after the update and left unstaged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local: Option<String>,
+}
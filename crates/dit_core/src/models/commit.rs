@@ -1,27 +1,134 @@
-﻿use serde::{Deserialize, Serialize};
+﻿use crate::helpers::{format_identity, parse_identity};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Represents the version of the commit format written by this version of Dit,
+/// stored in the canonical encoding (see [`ObjectEncoder`])
+///
+/// [`ObjectEncoder`]: crate::helpers::ObjectEncoder
+pub const COMMIT_FORMAT_VERSION: u32 = 2;
+
+/// Represents the version of the original commit format, which only recorded
+/// a free-form author string and a UTC timestamp
+pub const LEGACY_COMMIT_FORMAT_VERSION: u32 = 1;
 
 /// Represents a commit model
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "LegacyCommit", into = "LegacyCommit")]
 pub struct Commit {
-    /// Represents the committer name and email address \
-    /// Example: "Alice <alice@example.com>"
-    pub author: String,
+    /// Represents the version of the format the commit was created with
+    pub version: u32,
+
+    /// Represents the person who wrote the changes and when they did it
+    pub author: Signature,
+
+    /// Represents the person who created the commit and when they did it.
+    /// Differs from the author e.g. for rebased or cherry-picked commits
+    pub committer: Signature,
 
     /// Represents the commit message \
     /// Example: "initial commit"
     pub message: String,
 
-    /// Represents the commit time as a Unix timestamp - number of seconds
-    /// since January 1, 1970 (UTC)
-    pub timestamp: u64,
-
     /// Represents the tree hash of this commit
     pub tree: String,
 
     /// Represents the hash (or hashes) of the parent commit(s)
     pub parents: Vec<String>,
 
+    /// Represents the signature of the commit, if the commit is signed.
+    /// It signs the hash the commit has without the signature
+    pub signature: Option<CommitSignature>,
+
+    /// Represents the author string of a commit in the legacy format.
+    /// It's kept as is, because the commit hash depends on it
+    pub legacy_author: Option<String>,
+
     /// Represents the commit hash
-    #[serde(skip)]
     pub hash: String,
 }
+
+/// Represents a person (the author or the committer of a commit) and the time of their action
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Signature {
+    /// Represents the name of the person \
+    /// Example: "Alice"
+    pub name: String,
+
+    /// Represents the email address of the person (empty if unknown) \
+    /// Example: "alice@example.com"
+    pub email: String,
+
+    /// Represents the time as a Unix timestamp - number of seconds
+    /// since January 1, 1970 (UTC)
+    pub timestamp: u64,
+
+    /// Represents the offset of the person's timezone from UTC in minutes \
+    /// Example: 120 for UTC+02:00
+    pub offset: i32,
+}
+
+/// Represents an Ed25519 signature of a commit together with the public key it
+/// can be verified with. Both are hex-encoded
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommitSignature {
+    pub public_key: String,
+    pub signature: String,
+}
+
+impl Signature {
+    /// Checks whether both signatures refer to the same person
+    pub fn same_identity(&self, other: &Signature) -> bool {
+        self.name == other.name && self.email == other.email
+    }
+}
+
+impl Display for Signature {
+    /// Formats the identity as `Name <email>`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format_identity(&self.name, &self.email))
+    }
+}
+
+
+/// Represents the JSON form of a legacy commit, which is how the commits of the original
+/// format were stored
+#[derive(Serialize, Deserialize)]
+struct LegacyCommit {
+    author: String,
+    message: String,
+    timestamp: u64,
+    tree: String,
+    parents: Vec<String>,
+}
+
+impl From<LegacyCommit> for Commit {
+    fn from(legacy: LegacyCommit) -> Self {
+        let (name, email) = parse_identity(&legacy.author);
+        let signature = Signature { name, email, timestamp: legacy.timestamp, offset: 0 };
+
+        Self {
+            version: LEGACY_COMMIT_FORMAT_VERSION,
+            author: signature.clone(),
+            committer: signature,
+            message: legacy.message,
+            tree: legacy.tree,
+            parents: legacy.parents,
+            signature: None,
+            legacy_author: Some(legacy.author),
+            hash: String::new(),
+        }
+    }
+}
+
+impl From<Commit> for LegacyCommit {
+    fn from(commit: Commit) -> Self {
+        LegacyCommit {
+            author: commit.legacy_author.unwrap_or_else(|| commit.author.to_string()),
+            message: commit.message,
+            timestamp: commit.author.timestamp,
+            tree: commit.tree,
+            parents: commit.parents,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
 
 
 /// Username config name
@@ -15,4 +16,33 @@ pub struct Config {
 
     #[serde(rename = "user.email")]
     pub user_email: Option<String>,
+
+    /// Represents the command which opens the editor for commit messages
+    #[serde(rename = "core.editor", default)]
+    pub core_editor: Option<String>,
+
+    /// Represents the maximum length of the first line of commit messages (no limit if not set)
+    #[serde(rename = "commit.max_subject_length", default)]
+    pub commit_max_subject_length: Option<usize>,
+
+    /// Represents whether the second line of commit messages must be blank
+    #[serde(rename = "commit.blank_second_line", default)]
+    pub commit_blank_second_line: Option<bool>,
+
+    /// Represents the path of the private key new commits are signed with (they aren't signed if not set)
+    #[serde(rename = "user.signing_key", default)]
+    pub user_signing_key: Option<String>,
+
+    /// Represents the hex-encoded public keys whose commit signatures are trusted,
+    /// besides the key of the configured user
+    #[serde(rename = "commit.trusted_keys", default)]
+    pub commit_trusted_keys: Vec<String>,
+
+    /// Represents the branches which may only receive signed commits
+    #[serde(rename = "commit.signed_branches", default)]
+    pub commit_signed_branches: Vec<String>,
+
+    /// Maps the remote names to their urls
+    #[serde(default)]
+    pub remotes: BTreeMap<String, String>,
 }
@@ -1,7 +1,7 @@
 use crate::helpers::path_to_string;
 use crate::errors::{DitResult, FsError};
 use std::path::Path;
-use std::time::SystemTime;
+use std::time::{SystemTime, UNIX_EPOCH};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
@@ -25,4 +25,10 @@ impl FileFingerprint {
 
         Ok(Self { size, modified_at })
     }
+
+    /// Returns a fingerprint which doesn't match any file, e.g. for the files read from
+    /// a tree. The files compared to it are always hashed
+    pub fn unknown() -> Self {
+        Self { size: u64::MAX, modified_at: UNIX_EPOCH }
+    }
 }
@@ -0,0 +1,21 @@
+use crate::models::{Commit, Tag, Tree};
+use std::collections::{HashMap, HashSet};
+
+/// Represents the objects found in the repository during the check.
+/// The objects which couldn't be parsed are stored as `None`
+#[derive(Debug, Clone, Default)]
+pub struct CheckedObjects {
+    pub blobs: HashSet<String>,
+    pub trees: HashMap<String, Option<Tree>>,
+    pub commits: HashMap<String, Option<Commit>>,
+    pub tag_objects: HashMap<String, Option<Tag>>,
+}
+
+/// Represents the objects directly referenced by HEAD, the branches, the tags,
+/// the index and the stash
+#[derive(Debug, Clone, Default)]
+pub struct ObjectRoots {
+    pub blobs: HashSet<String>,
+    pub commits: HashSet<String>,
+    pub tag_objects: HashSet<String>,
+}
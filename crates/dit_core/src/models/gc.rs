@@ -0,0 +1,10 @@
+use std::collections::HashSet;
+
+/// Represents the hashes of the objects reachable from the references
+#[derive(Debug, Clone, Default)]
+pub struct ReachableObjects {
+    pub blobs: HashSet<String>,
+    pub trees: HashSet<String>,
+    pub commits: HashSet<String>,
+    pub tag_objects: HashSet<String>,
+}
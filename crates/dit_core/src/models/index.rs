@@ -1,15 +1,24 @@
 use crate::models::file_fingerprint::FileFingerprint;
+use crate::models::{ConflictEntry, PendingMerge};
 use std::collections::BTreeMap;
 use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct Index {
     /// Maps the relative paths of the files to their corresponding entries
     pub files: BTreeMap<PathBuf, IndexEntry>,
+
+    /// Maps the relative paths of the unmerged files to their conflicting versions
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub conflicts: BTreeMap<PathBuf, ConflictEntry>,
+
+    /// Represents the merge in progress (if any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<PendingMerge>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct IndexEntry {
     /// Represents the file hash
     pub hash: String,
@@ -18,3 +27,11 @@ pub struct IndexEntry {
     pub fp: FileFingerprint,
 }
 
+impl IndexEntry {
+    /// Creates an entry of a file read from a tree. Trees don't store the fingerprints,
+    /// so the fingerprint is unknown
+    pub fn from_tree<S: Into<String>>(hash: S) -> Self {
+        Self { hash: hash.into(), fp: FileFingerprint::unknown() }
+    }
+}
+
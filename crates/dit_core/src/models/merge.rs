@@ -0,0 +1,41 @@
+use crate::models::Index;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Represents the result of a file-level three-way merge of two trees
+#[derive(Debug, Clone, Default)]
+pub struct TreeMerge {
+    /// Represents the merged files (including the automatically resolved ones).
+    /// Conflicting files keep the version from our side
+    pub index: Index,
+
+    /// Maps the relative paths of the files which were changed differently
+    /// on both sides to their versions
+    pub conflicts: BTreeMap<PathBuf, ConflictEntry>,
+}
+
+/// Represents the versions of a conflicting file. `None` means that the file
+/// does not exist in that version
+#[derive(Debug, Clone, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ConflictEntry {
+    /// Represents the blob hash of the file in the merge base
+    pub base: Option<String>,
+
+    /// Represents the blob hash of the file on our side
+    pub ours: Option<String>,
+
+    /// Represents the blob hash of the file on their side
+    pub theirs: Option<String>,
+}
+
+/// Represents a merge which stopped because of conflicts and waits for them to be
+/// resolved and committed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PendingMerge {
+    /// Represents the head commit of the branch being merged
+    pub head: String,
+
+    /// Represents the name of the branch being merged
+    pub branch: String,
+}
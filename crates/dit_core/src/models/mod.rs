@@ -4,6 +4,17 @@ mod file_fingerprint;
 mod index;
 mod change;
 mod config;
+mod merge;
+mod tag;
+mod stash;
+mod gc;
+mod fsck;
+mod pack;
+mod remote;
+mod reflog;
+mod oplog;
+mod rebase;
+mod checkout;
 
 pub use tree::*;
 pub use commit::*;
@@ -11,3 +22,14 @@ pub use index::*;
 pub use change::*;
 pub use file_fingerprint::*;
 pub use config::*;
+pub use merge::*;
+pub use tag::*;
+pub use stash::*;
+pub use gc::*;
+pub use fsck::*;
+pub use pack::*;
+pub use remote::*;
+pub use reflog::*;
+pub use oplog::*;
+pub use rebase::*;
+pub use checkout::*;
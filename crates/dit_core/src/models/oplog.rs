@@ -0,0 +1,66 @@
+use crate::models::{Index, RebaseState, RefsSnapshot};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Represents an operation recorded in the operation log together with
+/// the state it changed, so that it can be undone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Operation {
+    /// Describes the operation \
+    /// Example: "commit: initial commit"
+    pub description: String,
+
+    /// Represents the name and email address of the person who performed the operation \
+    /// Example: "Alice <alice@example.com>"
+    pub author: String,
+
+    /// Represents the time of the operation as a Unix timestamp - number of seconds
+    /// since January 1, 1970 (UTC)
+    pub timestamp: u64,
+
+    /// Whether the operation updated the working tree, in which case the tracked files
+    /// are checked out from the restored index when undoing it
+    pub worktree: bool,
+
+    /// Represents HEAD and the changed branches before the operation
+    pub before: OperationRefs,
+
+    /// Represents HEAD and the changed branches after the operation
+    pub after: OperationRefs,
+
+    /// Represents the index before the operation
+    pub index: Index,
+
+    /// Represents the rebase in progress before the operation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rebase: Option<RebaseState>,
+
+    /// Represents the position of the operation in the log
+    #[serde(skip)]
+    pub id: u64,
+}
+
+/// Represents HEAD and the branches changed by an operation at a moment
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationRefs {
+    /// Represents the current branch (`None` when detached)
+    pub branch: Option<String>,
+
+    /// Represents the current commit
+    pub head: Option<String>,
+
+    /// Maps the names of the branches changed by the operation to the content of their
+    /// files: the head commit, or an empty string for a branch without commits.
+    /// `None` means that the branch didn't exist
+    pub branches: BTreeMap<String, Option<String>>,
+}
+
+/// Represents the state of the repository captured before or after an operation
+#[derive(Debug, Clone)]
+pub struct OperationState {
+    pub refs: RefsSnapshot,
+
+    pub index: Index,
+
+    pub rebase: Option<RebaseState>,
+}
@@ -0,0 +1,31 @@
+use crate::api_models::object::ObjectKind;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Represents the index of a pack file, stored next to it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackIndex {
+    /// Represents the location of the zstd dictionary the blobs were compressed with (if any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dictionary: Option<PackSpan>,
+
+    /// Maps the object hashes to their locations in the pack file
+    pub entries: BTreeMap<String, PackEntry>,
+}
+
+/// Represents a packed object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackEntry {
+    pub kind: ObjectKind,
+
+    /// Represents the location of the compressed object in the pack file
+    #[serde(flatten)]
+    pub span: PackSpan,
+}
+
+/// Represents a range of bytes in the pack file
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PackSpan {
+    pub offset: u64,
+    pub length: u64,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a rebase in progress
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RebaseState {
+    /// Represents the name of the branch being rebased
+    pub branch: String,
+
+    /// Represents the revision the branch is rebased onto, as it was given
+    pub upstream: String,
+
+    /// Represents the commit the branch is rebased onto
+    pub onto: String,
+
+    /// Represents the head of the branch before the rebase
+    pub orig_head: String,
+
+    /// Represents the commits which are left to be replayed, the next one first
+    pub todo: Vec<String>,
+
+    /// Represents the commit whose replay stopped because of conflicts
+    pub current: Option<String>,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Represents a single movement of HEAD or of a branch head, stored as a line of the reflog
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReflogEntry {
+    /// Represents the commit the reference pointed to before (`None` if it didn't point to any)
+    pub old: Option<String>,
+
+    /// Represents the commit the reference points to after the movement
+    pub new: String,
+
+    /// Represents the name and email address of the person who moved the reference \
+    /// Example: "Alice <alice@example.com>"
+    pub author: String,
+
+    /// Represents the time of the movement as a Unix timestamp - number of seconds
+    /// since January 1, 1970 (UTC)
+    pub timestamp: u64,
+
+    /// Describes the operation which moved the reference \
+    /// Example: "commit: initial commit"
+    pub message: String,
+}
+
+/// Represents the commits HEAD and the branches point to at a moment, so that
+/// the references moved by an operation can be found by comparing two snapshots
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefsSnapshot {
+    /// Represents the current branch (`None` when detached)
+    pub branch: Option<String>,
+
+    /// Represents the current commit
+    pub head: Option<String>,
+
+    /// Maps the branch names to their head commits (`None` for the branches without commits)
+    pub branches: BTreeMap<String, Option<String>>,
+}
@@ -0,0 +1,25 @@
+use crate::api_models::object::ObjectKind;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Represents an object transferred between repositories
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteObject {
+    pub kind: ObjectKind,
+
+    pub hash: String,
+
+    /// Represents the uncompressed content of a blob, or a serialized tree or commit
+    pub content: Vec<u8>,
+}
+
+/// Represents the references advertised by a remote repository
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RemoteRefs {
+    /// Represents the current branch of the remote repository (if any)
+    pub head: Option<String>,
+
+    /// Maps the branch names to their head commits. The branches without
+    /// commits are omitted
+    pub branches: BTreeMap<String, String>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the stack of stashed changes
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Stash {
+    /// Represents the hashes of the stash commits, the most recent first
+    pub entries: Vec<String>,
+}
@@ -0,0 +1,64 @@
+use std::fmt::{Display, Formatter};
+
+/// Represents the version of the tag object format, stored in the canonical
+/// encoding (see [`ObjectEncoder`])
+///
+/// [`ObjectEncoder`]: crate::helpers::ObjectEncoder
+pub const TAG_FORMAT_VERSION: u32 = 1;
+
+/// Represents an annotated tag object
+#[derive(Debug, Clone)]
+pub struct Tag {
+    /// Represents the tag name \
+    /// Example: "v1.0.0"
+    pub name: String,
+
+    /// Represents the hash of the tagged commit
+    pub commit: String,
+
+    /// Represents the name and email address of the person who created the tag \
+    /// Example: "Alice <alice@example.com>"
+    pub tagger: String,
+
+    /// Represents the tag message
+    pub message: String,
+
+    /// Represents the tag creation time as a Unix timestamp - number of seconds
+    /// since January 1, 1970 (UTC)
+    pub timestamp: u64,
+
+    /// Represents the tag object hash
+    pub hash: String,
+}
+
+
+/// Represents what a tag file points to. It is stored as the kind of the target
+/// followed by its hash, e.g. "tag 3f2a..." for an annotated tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagRef {
+    /// A lightweight tag pointing directly to a commit
+    Commit(String),
+
+    /// An annotated tag pointing to a tag object
+    TagObject(String),
+}
+
+impl TagRef {
+    /// Parses the content of a tag file. Returns `None` if it isn't a tag reference
+    pub fn parse(content: &str) -> Option<Self> {
+        match content.trim().split_once(' ')? {
+            ("commit", hash) => Some(Self::Commit(hash.to_string())),
+            ("tag", hash) => Some(Self::TagObject(hash.to_string())),
+            _ => None,
+        }
+    }
+}
+
+impl Display for TagRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Commit(hash) => write!(f, "commit {hash}"),
+            Self::TagObject(hash) => write!(f, "tag {hash}"),
+        }
+    }
+}
@@ -1,13 +1,143 @@
-﻿use crate::models::Index;
+﻿use crate::dit_component_paths::DIT_ROOT;
+use crate::errors::TreeError;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
-/// Represents a tree model
+/// Represents the version of the tree format written by this version of Dit,
+/// stored in the canonical encoding (see [`ObjectEncoder`])
+///
+/// [`ObjectEncoder`]: crate::helpers::ObjectEncoder
+pub const TREE_FORMAT_VERSION: u32 = 2;
+
+/// Represents the version of the original tree format, which stored the whole
+/// index (every file of the repository) in a single flat tree
+pub const LEGACY_TREE_FORMAT_VERSION: u32 = 1;
+
+/// Represents the mode of a regular file entry
+pub const FILE_MODE: u32 = 0o100644;
+
+/// Represents the mode of a directory (subtree) entry
+pub const DIR_MODE: u32 = 0o040000;
+
+/// Represents a tree model - the contents of a single directory
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "LegacyTree", into = "LegacyTree")]
 pub struct Tree {
-    /// Maps the relative file paths to corresponding blob hashes
-    pub index: Index,
+    /// Represents the version of the format the tree was created with
+    pub version: u32,
+
+    /// Maps the names of the files and directories to their entries. \
+    /// In a legacy tree, the names are the relative paths of all the files of the repository
+    pub entries: BTreeMap<String, TreeEntry>,
 
     /// Represents the tree hash
-    #[serde(skip)]
     pub hash: String,
 }
+
+/// Represents a file or a directory in a tree
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TreeEntry {
+    /// Represents the kind of the object the entry points to
+    pub kind: TreeEntryKind,
+
+    /// Represents the mode of the entry, either [`FILE_MODE`] or [`DIR_MODE`]
+    pub mode: u32,
+
+    /// Represents the hash of the blob (for a file) or of the subtree (for a directory)
+    pub hash: String,
+}
+
+/// Represents the kind of object a tree entry points to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TreeEntryKind {
+    Blob,
+    Tree,
+}
+
+impl TreeEntry {
+    /// Creates an entry pointing to the blob of a file
+    pub fn blob(hash: String) -> Self {
+        Self { kind: TreeEntryKind::Blob, mode: FILE_MODE, hash }
+    }
+
+    /// Creates an entry pointing to the tree of a directory
+    pub fn tree(hash: String) -> Self {
+        Self { kind: TreeEntryKind::Tree, mode: DIR_MODE, hash }
+    }
+
+    /// Checks whether the entry is a directory
+    pub fn is_tree(&self) -> bool {
+        self.kind == TreeEntryKind::Tree
+    }
+}
+
+impl Tree {
+    /// Checks whether the tree is in the legacy (flat) format
+    pub fn is_legacy(&self) -> bool {
+        self.version == LEGACY_TREE_FORMAT_VERSION
+    }
+}
+
+/// Checks whether the name can be the name of an entry of a tree: a single component
+/// of a path other than the [`DIT_ROOT`], so that the files of a tree are never written
+/// outside of the repository or into its metadata. The [`DIT_ROOT`] is compared ignoring
+/// the case, since it's the same directory on case-insensitive file systems
+pub fn is_valid_entry_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "."
+        && name != ".."
+        && !name.contains(['/', '\\', '\0'])
+        && !name.eq_ignore_ascii_case(DIT_ROOT)
+}
+
+
+/// Represents the JSON form of a legacy tree, which is how the trees of the original
+/// format were stored
+#[derive(Serialize, Deserialize)]
+struct LegacyTree {
+    index: LegacyIndex,
+}
+
+/// Represents the files of a legacy tree. The fingerprints of the files it used
+/// to store are ignored
+#[derive(Serialize, Deserialize)]
+struct LegacyIndex {
+    files: BTreeMap<PathBuf, LegacyEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LegacyEntry {
+    hash: String,
+}
+
+impl TryFrom<LegacyTree> for Tree {
+    type Error = TreeError;
+
+    fn try_from(legacy: LegacyTree) -> Result<Self, Self::Error> {
+        let mut entries = BTreeMap::new();
+        for (rel_path, entry) in legacy.index.files {
+            let rel_path = rel_path.to_string_lossy().into_owned();
+            if !rel_path.split(['/', '\\']).all(is_valid_entry_name) {
+                return Err(TreeError::InvalidEntryName(rel_path));
+            }
+            entries.insert(rel_path, TreeEntry::blob(entry.hash));
+        }
+
+        Ok(Self { version: LEGACY_TREE_FORMAT_VERSION, entries, hash: String::new() })
+    }
+}
+
+impl From<Tree> for LegacyTree {
+    fn from(tree: Tree) -> Self {
+        LegacyTree {
+            index: LegacyIndex {
+                files: tree.entries
+                    .into_iter()
+                    .map(|(rel_path, entry)| (PathBuf::from(rel_path), LegacyEntry { hash: entry.hash }))
+                    .collect(),
+            },
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use dit_core::Dit;
+use dit_core::errors::{ConfigError, DitCoreError};
+use std::fs;
+
+#[test]
+fn commit_requires_a_user_identity() {
+    let path = std::env::temp_dir().join(format!("dit-test-no-identity-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).unwrap();
+
+    let mut dit = Dit::from(&path).unwrap();
+    dit.create_branch("main").unwrap();
+    fs::write(path.join("a.txt"), "a\n").unwrap();
+    dit.add_files([path.join("a.txt")]).unwrap();
+    let result = dit.commit("first", None::<String>);
+    let _ = fs::remove_dir_all(&path);
+
+    match result {
+        Err(DitCoreError::ConfigError(ConfigError::UserIdentityNotFound)) => {}
+        other => panic!("expected the missing identity to be reported, got {other:?}"),
+    }
+}
@@ -0,0 +1,182 @@
+use dit_core::Dit;
+use dit_core::api_models::diff::{DiffContent, DiffLine};
+use dit_core::helpers::{diff_lines, merge_lines, DiffOp};
+use std::fs;
+use std::path::PathBuf;
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, dit }
+    }
+
+    fn commit(&mut self, rel_path: &str, content: &str) {
+        fs::write(self.path.join(rel_path), content).unwrap();
+        self.dit.add_files([self.path.join(rel_path)]).unwrap();
+        self.dit.commit("commit", None::<String>).unwrap();
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Applies the edit script to the old sequence, checking that the equal lines match
+fn apply_ops<'a>(old: &[&'a str], new: &[&'a str], ops: &[DiffOp]) -> Vec<&'a str> {
+    let mut result = Vec::new();
+    let (mut next_old, mut next_new) = (0, 0);
+
+    for op in ops {
+        match *op {
+            DiffOp::Equal { old: o, new: n } => {
+                assert_eq!((o, n), (next_old, next_new));
+                assert_eq!(old[o], new[n]);
+                result.push(old[o]);
+                next_old += 1;
+                next_new += 1;
+            }
+            DiffOp::Delete { old: o } => {
+                assert_eq!(o, next_old);
+                next_old += 1;
+            }
+            DiffOp::Insert { new: n } => {
+                assert_eq!(n, next_new);
+                result.push(new[n]);
+                next_new += 1;
+            }
+        }
+    }
+
+    assert_eq!((next_old, next_new), (old.len(), new.len()));
+    result
+}
+
+/// Counts the deleted and inserted lines of the edit script
+fn edit_distance(ops: &[DiffOp]) -> usize {
+    ops.iter().filter(|op| !matches!(op, DiffOp::Equal { .. })).count()
+}
+
+
+#[test]
+fn changed_line_endings_are_shown() {
+    let mut repo = TestRepo::new("diff-crlf");
+    repo.commit("a.txt", "one\ntwo\n");
+    fs::write(repo.path.join("a.txt"), "one\r\ntwo\n").unwrap();
+
+    let diff = repo.dit.diff_unstaged().unwrap();
+    let DiffContent::Text(hunks) = &diff.files[0].content else {
+        panic!("expected a text diff");
+    };
+    let lines: Vec<String> = hunks[0].lines.iter()
+        .map(|line| match line {
+            DiffLine::Context(text) => format!(" {text}"),
+            DiffLine::Added(text) => format!("+{text}"),
+            DiffLine::Removed(text) => format!("-{text}"),
+            DiffLine::NoNewlineAtEnd => String::from("\\"),
+        })
+        .collect();
+
+    assert_eq!(lines, ["-one", "+one\r", " two"]);
+}
+
+#[test]
+fn diff_finds_the_shortest_edit_script() {
+    // The example from the paper of Myers, whose edit distance is 5
+    let old = ["A", "B", "C", "A", "B", "B", "A"];
+    let new = ["C", "B", "A", "B", "A", "C"];
+
+    let ops = diff_lines(&old, &new);
+    assert_eq!(apply_ops(&old, &new, &ops), new);
+    assert_eq!(edit_distance(&ops), 5);
+}
+
+#[test]
+fn diff_handles_empty_and_equal_sequences() {
+    let lines = ["a", "b", "c"];
+
+    assert!(diff_lines::<&str>(&[], &[]).is_empty());
+    assert_eq!(diff_lines(&lines, &[]), (0..3).map(|old| DiffOp::Delete { old }).collect::<Vec<_>>());
+    assert_eq!(diff_lines(&[], &lines), (0..3).map(|new| DiffOp::Insert { new }).collect::<Vec<_>>());
+    assert_eq!(edit_distance(&diff_lines(&lines, &lines)), 0);
+}
+
+#[test]
+fn diff_of_long_sequences_is_minimal() {
+    let old: Vec<String> = (0..2000).map(|i| format!("line {i}")).collect();
+    let mut new = old.clone();
+    new.remove(1500);
+    new.insert(700, String::from("inserted"));
+    new[100] = String::from("changed");
+
+    let old: Vec<&str> = old.iter().map(String::as_str).collect();
+    let new: Vec<&str> = new.iter().map(String::as_str).collect();
+    let ops = diff_lines(&old, &new);
+
+    assert_eq!(apply_ops(&old, &new, &ops), new);
+    assert_eq!(edit_distance(&ops), 4);
+}
+
+#[test]
+fn merge_combines_changes_to_different_lines() {
+    let base = b"one\ntwo\nthree\nfour\n";
+    let ours = b"ONE\ntwo\nthree\nfour\n";
+    let theirs = b"one\ntwo\nthree\nFOUR\nfive\n";
+
+    let merge = merge_lines(base, ours, theirs, "ours", "theirs");
+    assert!(merge.is_clean());
+    assert_eq!(merge.content, b"ONE\ntwo\nthree\nFOUR\nfive\n");
+}
+
+#[test]
+fn merge_takes_the_same_change_once() {
+    let base = b"one\ntwo\n";
+    let changed = b"one\n2\n";
+
+    let merge = merge_lines(base, changed, changed, "ours", "theirs");
+    assert!(merge.is_clean());
+    assert_eq!(merge.content, changed);
+}
+
+#[test]
+fn merge_marks_conflicting_changes() {
+    let base = b"one\ntwo\nthree\n";
+    let ours = b"one\nours\nthree\n";
+    let theirs = b"one\ntheirs\nthree\n";
+
+    let merge = merge_lines(base, ours, theirs, "main", "feature");
+    assert_eq!(merge.conflicts, 1);
+    assert_eq!(
+        String::from_utf8(merge.content).unwrap(),
+        "one\n<<<<<<< main\nours\n=======\ntheirs\n>>>>>>> feature\nthree\n",
+    );
+}
+
+#[test]
+fn merge_puts_markers_on_their_own_lines() {
+    let base = b"one\ntwo";
+    let ours = b"one\nours";
+    let theirs = b"one\ntheirs";
+
+    let merge = merge_lines(base, ours, theirs, "main", "feature");
+    assert_eq!(merge.conflicts, 1);
+    assert_eq!(
+        String::from_utf8(merge.content).unwrap(),
+        "one\n<<<<<<< main\nours\n=======\ntheirs\n>>>>>>> feature\n",
+    );
+}
@@ -0,0 +1,58 @@
+use dit_core::{Dit, Repo};
+use dit_core::errors::{DitCoreError, LockError};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        Dit::from(&path).unwrap();
+
+        Self { path }
+    }
+
+    /// Opens the repository like a separate process would
+    fn open(&self) -> Arc<Repo> {
+        Arc::new(Repo::init(&self.path).unwrap())
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+
+#[test]
+fn lock_is_exclusive_until_released() {
+    let repo = TestRepo::new("lock-exclusive");
+    let (first, second) = (repo.open(), repo.open());
+
+    let lock = first.lock().unwrap();
+    let nested = first.lock().unwrap();
+    assert!(lock.is_outermost());
+    assert!(!nested.is_outermost());
+
+    match second.lock() {
+        Err(DitCoreError::LockError(LockError::RepositoryLocked { pid, .. })) => {
+            assert_eq!(pid, std::process::id().to_string());
+        }
+        other => panic!("expected the repository to be locked, got {:?}", other.map(|_| ())),
+    }
+
+    // The lock is held until the outermost lock is dropped
+    drop(lock);
+    assert!(second.lock().is_err());
+
+    drop(nested);
+    assert!(second.lock().is_ok());
+}
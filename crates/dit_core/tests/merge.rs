@@ -0,0 +1,212 @@
+use dit_core::Dit;
+use dit_core::api_models::checkout::CheckoutMode;
+use dit_core::api_models::stash::StashApplyResult;
+use dit_core::errors::{CheckoutError, CommitError, DitCoreError};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, dit }
+    }
+
+    fn write(&self, rel_path: &str, content: &str) {
+        fs::write(self.path.join(rel_path), content).unwrap();
+    }
+
+    fn read(&self, rel_path: &str) -> String {
+        fs::read_to_string(self.path.join(rel_path)).unwrap()
+    }
+
+    fn commit(&mut self, files: &[(&str, &str)], message: &str) {
+        for (rel_path, content) in files {
+            self.write(rel_path, content);
+        }
+        let paths: Vec<PathBuf> = files.iter().map(|(rel_path, _)| self.path.join(rel_path)).collect();
+        self.dit.add_files(&paths).unwrap();
+        self.dit.commit(message, None::<String>).unwrap();
+    }
+
+    /// Creates the branch `feature` with one commit on top of the current one and switches back
+    fn commit_on_feature(&mut self, files: &[(&str, &str)]) {
+        self.dit.create_branch("feature").unwrap();
+        self.dit.switch_branch("feature", false, CheckoutMode::Safe).unwrap();
+        self.commit(files, "feature");
+        self.dit.switch_branch("main", false, CheckoutMode::Safe).unwrap();
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Asserts that the change was refused because of the given untracked and modified files
+fn assert_would_clobber(result: Result<impl std::fmt::Debug, DitCoreError>, untracked: &[&str], modified: &[&str]) {
+    let to_paths = |paths: &[&str]| paths.iter().map(Path::new).map(Path::to_path_buf).collect::<Vec<_>>();
+
+    match result {
+        Err(DitCoreError::CheckoutError(CheckoutError::WouldClobber { untracked: u, modified: m })) => {
+            assert_eq!(u, to_paths(untracked));
+            assert_eq!(m, to_paths(modified));
+        }
+        other => panic!("expected the change to be refused, got {other:?}"),
+    }
+}
+
+
+#[test]
+fn fast_forward_merge_keeps_local_changes() {
+    let mut repo = TestRepo::new("ff-dirty");
+    repo.commit(&[("a.txt", "base\n")], "base");
+    repo.commit_on_feature(&[("a.txt", "feature\n")]);
+    repo.write("a.txt", "local\n");
+
+    assert_would_clobber(repo.dit.merge_branch("feature", CheckoutMode::Safe), &[], &["a.txt"]);
+    assert_eq!(repo.read("a.txt"), "local\n");
+}
+
+#[test]
+fn diverged_merge_keeps_local_changes() {
+    let mut repo = TestRepo::new("diverged-dirty");
+    repo.commit(&[("a.txt", "base\n"), ("b.txt", "base\n")], "base");
+    repo.commit_on_feature(&[("a.txt", "feature\n")]);
+    repo.commit(&[("b.txt", "main\n")], "main");
+    let head = repo.dit.get_head_commit().unwrap();
+    repo.write("a.txt", "local\n");
+
+    assert_would_clobber(repo.dit.merge_branch("feature", CheckoutMode::Safe), &[], &["a.txt"]);
+    assert_eq!(repo.read("a.txt"), "local\n");
+    assert_eq!(repo.dit.get_head_commit().unwrap(), head);
+}
+
+#[test]
+fn conflicting_merge_keeps_local_changes() {
+    let mut repo = TestRepo::new("conflict-dirty");
+    repo.commit(&[("a.txt", "base\n")], "base");
+    repo.commit_on_feature(&[("a.txt", "feature\n")]);
+    repo.commit(&[("a.txt", "main\n")], "main");
+    repo.write("a.txt", "local\n");
+
+    assert_would_clobber(repo.dit.merge_branch("feature", CheckoutMode::Safe), &[], &["a.txt"]);
+    assert_eq!(repo.read("a.txt"), "local\n");
+}
+
+#[test]
+fn merge_keeps_untracked_files_in_the_way() {
+    let mut repo = TestRepo::new("untracked");
+    repo.commit(&[("a.txt", "base\n")], "base");
+    repo.commit_on_feature(&[("new.txt", "feature\n")]);
+    repo.commit(&[("a.txt", "main\n")], "main");
+    repo.write("new.txt", "untracked\n");
+
+    assert_would_clobber(repo.dit.merge_branch("feature", CheckoutMode::Safe), &["new.txt"], &[]);
+    assert_eq!(repo.read("new.txt"), "untracked\n");
+}
+
+#[test]
+fn forced_merge_overwrites_files_in_the_way() {
+    let mut repo = TestRepo::new("forced");
+    repo.commit(&[("a.txt", "base\n")], "base");
+    repo.commit_on_feature(&[("new.txt", "feature\n")]);
+    repo.write("new.txt", "untracked\n");
+
+    repo.dit.merge_branch("feature", CheckoutMode::Force).unwrap();
+    assert_eq!(repo.read("new.txt"), "feature\n");
+}
+
+#[test]
+fn cherry_pick_keeps_untracked_files_in_the_way() {
+    let mut repo = TestRepo::new("pick-untracked");
+    repo.commit(&[("a.txt", "base\n")], "base");
+    repo.commit_on_feature(&[("new.txt", "feature\n")]);
+    let head = repo.dit.get_head_commit().unwrap();
+    repo.write("new.txt", "untracked\n");
+
+    assert_would_clobber(repo.dit.cherry_pick("feature"), &["new.txt"], &[]);
+    assert_eq!(repo.read("new.txt"), "untracked\n");
+    assert_eq!(repo.dit.get_head_commit().unwrap(), head);
+}
+
+#[test]
+fn revert_keeps_untracked_files_in_the_way() {
+    let mut repo = TestRepo::new("revert-untracked");
+    repo.commit(&[("a.txt", "base\n"), ("old.txt", "old\n")], "base");
+    fs::remove_file(repo.path.join("old.txt")).unwrap();
+    repo.dit.add_files([repo.path.join("old.txt")]).unwrap();
+    repo.dit.commit("remove", None::<String>).unwrap();
+    let head = repo.dit.get_head_commit().unwrap();
+    repo.write("old.txt", "untracked\n");
+
+    assert_would_clobber(repo.dit.revert("HEAD"), &["old.txt"], &[]);
+    assert_eq!(repo.read("old.txt"), "untracked\n");
+    assert_eq!(repo.dit.get_head_commit().unwrap(), head);
+}
+
+#[test]
+fn rebase_keeps_untracked_files_in_the_way() {
+    let mut repo = TestRepo::new("rebase-untracked");
+    repo.commit(&[("a.txt", "base\n")], "base");
+    repo.commit_on_feature(&[("b.txt", "feature\n")]);
+    repo.commit(&[("new.txt", "main\n")], "main");
+    repo.dit.switch_branch("feature", false, CheckoutMode::Safe).unwrap();
+    let head = repo.dit.get_head_commit().unwrap();
+    repo.write("new.txt", "untracked\n");
+
+    assert_would_clobber(repo.dit.rebase("main"), &["new.txt"], &[]);
+    assert_eq!(repo.read("new.txt"), "untracked\n");
+    assert_eq!(repo.dit.get_head_commit().unwrap(), head);
+    assert!(repo.dit.rebase_abort().is_err());
+}
+
+#[test]
+fn stash_apply_keeps_untracked_files_in_the_way() {
+    let mut repo = TestRepo::new("stash-untracked");
+    repo.commit(&[("a.txt", "base\n")], "base");
+    repo.write("new.txt", "stashed\n");
+    repo.dit.add_files([repo.path.join("new.txt")]).unwrap();
+    repo.dit.stash_push(None).unwrap();
+    repo.write("new.txt", "untracked\n");
+
+    assert_would_clobber(repo.dit.stash_apply(0), &["new.txt"], &[]);
+    assert_eq!(repo.read("new.txt"), "untracked\n");
+}
+
+#[test]
+fn stash_apply_records_the_unmerged_files() {
+    let mut repo = TestRepo::new("stash-conflict");
+    repo.commit(&[("a.txt", "base\n")], "base");
+    repo.write("a.txt", "stashed\n");
+    repo.dit.stash_push(None).unwrap();
+    repo.commit(&[("a.txt", "main\n")], "main");
+
+    match repo.dit.stash_apply(0).unwrap() {
+        StashApplyResult::Conflicted(paths) => assert_eq!(paths, vec![PathBuf::from("a.txt")]),
+        other => panic!("expected conflicts, got {other:?}"),
+    }
+    assert!(repo.read("a.txt").contains("<<<<<<<"));
+    assert!(matches!(
+        repo.dit.commit("resolved", None::<String>),
+        Err(DitCoreError::CommitError(CommitError::UnresolvedConflicts(_)))
+    ));
+
+    repo.write("a.txt", "resolved\n");
+    repo.dit.add_files([repo.path.join("a.txt")]).unwrap();
+    repo.dit.commit("resolved", None::<String>).unwrap();
+}
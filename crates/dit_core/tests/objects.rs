@@ -0,0 +1,190 @@
+use dit_core::Dit;
+use dit_core::api_models::checkout::CheckoutMode;
+use dit_core::api_models::fsck::FsckProblem;
+use dit_core::api_models::object::ObjectKind;
+use dit_core::dit_component_paths::{BRANCHES_ROOT, COMMITS_ROOT, TREES_ROOT};
+use dit_core::helpers::{DitHasher, ObjectEncoder, OBJECT_MAGIC};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The format version of the trees and the commits in the canonical encoding
+const FORMAT_VERSION: u32 = 2;
+
+/// The mode of a file entry of a tree
+const FILE_MODE: u32 = 0o100644;
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, dit }
+    }
+
+    fn commit(&mut self, files: &[(&str, &str)]) -> String {
+        for (rel_path, content) in files {
+            let abs_path = self.path.join(rel_path);
+            fs::create_dir_all(abs_path.parent().unwrap()).unwrap();
+            fs::write(&abs_path, content).unwrap();
+        }
+        let paths: Vec<PathBuf> = files.iter().map(|(rel_path, _)| self.path.join(rel_path)).collect();
+        self.dit.add_files(&paths).unwrap();
+        self.dit.commit("commit", None::<String>).unwrap();
+        self.dit.get_head_commit().unwrap().unwrap()
+    }
+
+    /// Returns the loose objects in the directory with their stored bytes
+    fn loose_objects(&self, root: &str) -> Vec<(String, Vec<u8>)> {
+        fs::read_dir(self.path.join(root)).unwrap()
+            .map(|entry| entry.unwrap().path())
+            .map(|path| (file_name(&path), fs::read(&path).unwrap()))
+            .collect()
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().unwrap().to_string_lossy().to_string()
+}
+
+fn hash(bytes: &[u8]) -> String {
+    let mut hasher = DitHasher::new();
+    hasher.update(bytes);
+    hasher.finalize_string()
+}
+
+/// Writes the tree and the commit of a repository created before the canonical encoding
+/// (JSON documents with the hashes of their content) and points `main` to the commit.
+/// The blob of the content is expected to be stored already. Returns the hash of the commit
+fn write_legacy_commit(repo_path: &Path, content: &str) -> String {
+    let blob = hash(content.as_bytes());
+
+    let mut hasher = DitHasher::new();
+    hasher.update(b"a.txt");
+    hasher.update(blob.as_bytes());
+    let tree = hasher.finalize_string();
+    let tree_json = format!(r#"{{ "index": {{ "files": {{ "a.txt": {{ "hash": "{blob}" }} }} }} }}"#);
+    fs::write(repo_path.join(TREES_ROOT).join(&tree), tree_json).unwrap();
+
+    let (author, message, timestamp) = ("Tester <tester@example.com>", "legacy commit", 1_600_000_000u64);
+    let mut hasher = DitHasher::new();
+    hasher.update(author.as_bytes());
+    hasher.update(message.as_bytes());
+    hasher.update(&timestamp.to_le_bytes());
+    hasher.update(tree.as_bytes());
+    hasher.update(&[0]);
+    let commit = hasher.finalize_string();
+    let commit_json = format!(
+        r#"{{ "author": "{author}", "message": "{message}", "timestamp": {timestamp}, "tree": "{tree}", "parents": [] }}"#
+    );
+    fs::write(repo_path.join(COMMITS_ROOT).join(&commit), commit_json).unwrap();
+    fs::write(repo_path.join(BRANCHES_ROOT).join("main"), &commit).unwrap();
+
+    commit
+}
+
+
+#[test]
+fn new_objects_are_stored_in_the_canonical_encoding() {
+    let mut repo = TestRepo::new("objects-canonical");
+    repo.commit(&[("a.txt", "a\n"), ("dir/b.txt", "b\n")]);
+    repo.commit(&[("dir/b.txt", "changed\n")]);
+
+    let trees = repo.loose_objects(TREES_ROOT);
+    let commits = repo.loose_objects(COMMITS_ROOT);
+    assert_eq!((trees.len(), commits.len()), (4, 2));
+
+    for (kind_tag, objects) in [(2u8, trees), (3u8, commits)] {
+        for (name, bytes) in objects {
+            let (magic, rest) = bytes.split_at(OBJECT_MAGIC.len());
+            assert_eq!(magic, OBJECT_MAGIC);
+            assert_eq!(rest[0], kind_tag);
+            assert_eq!(rest[1..5], FORMAT_VERSION.to_le_bytes());
+
+            // The id of an object is the hash of its stored bytes
+            assert_eq!(hash(&bytes), name);
+        }
+    }
+}
+
+#[test]
+fn legacy_objects_are_read_with_their_original_hashes() {
+    let mut repo = TestRepo::new("objects-legacy");
+    fs::write(repo.path.join("a.txt"), "legacy\n").unwrap();
+    repo.dit.add_files([repo.path.join("a.txt")]).unwrap();
+    let legacy = write_legacy_commit(&repo.path, "legacy\n");
+    let stored = fs::read(repo.path.join(COMMITS_ROOT).join(&legacy)).unwrap();
+
+    repo.dit = Dit::from(&repo.path).unwrap();
+    assert_eq!(repo.dit.resolve_revision("main").unwrap(), legacy);
+    let fsck = repo.dit.fsck().unwrap();
+    assert!(fsck.is_ok(), "{:?}", fsck.problems);
+
+    repo.dit.hard_reset(&legacy, CheckoutMode::Force).unwrap();
+    assert_eq!(fs::read_to_string(repo.path.join("a.txt")).unwrap(), "legacy\n");
+
+    // A new commit on top of the legacy one is stored in the canonical encoding
+    let head = repo.commit(&[("b.txt", "b\n")]);
+    let bytes = fs::read(repo.path.join(COMMITS_ROOT).join(&head)).unwrap();
+    assert!(bytes.starts_with(OBJECT_MAGIC));
+    assert_eq!(repo.dit.resolve_revision("HEAD~1").unwrap(), legacy);
+
+    let diff = repo.dit.diff_commits(&legacy, &head).unwrap();
+    assert_eq!(diff.files.iter().map(|file| file.path.clone()).collect::<Vec<_>>(), [PathBuf::from("b.txt")]);
+
+    // The legacy objects are left as they are
+    assert_eq!(fs::read(repo.path.join(COMMITS_ROOT).join(&legacy)).unwrap(), stored);
+    assert!(repo.dit.fsck().unwrap().is_ok());
+}
+
+#[test]
+fn non_canonical_objects_are_rejected() {
+    let repo = TestRepo::new("objects-non-canonical");
+
+    // The entries of a canonical tree are ordered by their names
+    let blob = hash(b"");
+    let mut encoder = ObjectEncoder::new(ObjectKind::Tree, FORMAT_VERSION);
+    encoder.u64(2);
+    for name in ["b.txt", "a.txt"] {
+        encoder.str(name);
+        encoder.u32(FILE_MODE);
+        encoder.str(&blob);
+    }
+    let unordered = encoder.finish();
+    fs::write(repo.path.join(TREES_ROOT).join(hash(&unordered)), &unordered).unwrap();
+
+    // A format version which doesn't exist
+    let mut encoder = ObjectEncoder::new(ObjectKind::Tree, FORMAT_VERSION + 1);
+    encoder.u64(0);
+    let unknown_version = encoder.finish();
+    fs::write(repo.path.join(TREES_ROOT).join(hash(&unknown_version)), &unknown_version).unwrap();
+
+    let mut corrupt: Vec<String> = repo.dit.fsck().unwrap().problems.iter()
+        .filter_map(|problem| match problem {
+            FsckProblem::Corrupt { kind: ObjectKind::Tree, hash, .. } => Some(hash.clone()),
+            _ => None,
+        })
+        .collect();
+    corrupt.sort();
+
+    let mut expected = vec![hash(&unordered), hash(&unknown_version)];
+    expected.sort();
+    assert_eq!(corrupt, expected);
+}
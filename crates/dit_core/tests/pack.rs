@@ -0,0 +1,145 @@
+use dit_core::Dit;
+use dit_core::api_models::checkout::CheckoutMode;
+use dit_core::dit_component_paths::{BLOBS_ROOT, COMMITS_ROOT, PACKS_ROOT, TREES_ROOT};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, dit }
+    }
+
+    /// Commits the files with the given contents and returns the hash of the commit
+    fn commit(&mut self, files: &[(String, String)]) -> String {
+        for (rel_path, content) in files {
+            let abs_path = self.path.join(rel_path);
+            fs::create_dir_all(abs_path.parent().unwrap()).unwrap();
+            fs::write(&abs_path, content).unwrap();
+        }
+        let paths: Vec<PathBuf> = files.iter().map(|(rel_path, _)| self.path.join(rel_path)).collect();
+        self.dit.add_files(&paths).unwrap();
+        self.dit.commit("commit", None::<String>).unwrap();
+        self.dit.get_head_commit().unwrap().unwrap()
+    }
+
+    fn read(&self, rel_path: &str) -> String {
+        fs::read_to_string(self.path.join(rel_path)).unwrap()
+    }
+
+    /// Counts the loose objects of the repository
+    fn loose_objects(&self) -> usize {
+        [BLOBS_ROOT, TREES_ROOT, COMMITS_ROOT].iter()
+            .map(|root| count_files(&self.path.join(root)))
+            .sum()
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Counts the files in the directory and its subdirectories
+fn count_files(dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+
+    entries
+        .map(|entry| entry.unwrap().path())
+        .map(|path| if path.is_dir() { count_files(&path) } else { 1 })
+        .sum()
+}
+
+/// Many small similar files, for which a dictionary pays off
+fn similar_files(version: usize) -> Vec<(String, String)> {
+    (0..40)
+        .map(|i| (
+            format!("src/module_{i}.txt"),
+            format!("// Module {i}, version {version}\nfn handle_{i}() {{\n    run(\"step {i}\");\n}}\n"),
+        ))
+        .collect()
+}
+
+/// Commits twice, repacks and checks that both commits can be read back from the pack
+fn check_round_trip(name: &str, use_dictionary: bool) {
+    let mut repo = TestRepo::new(name);
+
+    let mut first = similar_files(1);
+    first.push((String::from("a.txt"), String::from("first\n")));
+    let first_commit = repo.commit(&first);
+
+    let mut second = similar_files(2);
+    second.push((String::from("a.txt"), String::from("second\n")));
+    let second_commit = repo.commit(&second);
+
+    let report = repo.dit.repack(use_dictionary).unwrap();
+    assert!(report.pack.is_some());
+    assert!(report.packed > 0);
+    assert_eq!(report.dictionary, use_dictionary);
+    assert_eq!(repo.loose_objects(), 0);
+
+    // A fresh instance reads everything from the pack
+    let mut dit = Dit::from(&repo.path).unwrap();
+    let fsck = dit.fsck().unwrap();
+    assert!(fsck.is_ok(), "{:?}", fsck.problems);
+    assert!(fsck.checked >= report.packed);
+
+    let diff = dit.diff_commits(&first_commit, &second_commit).unwrap();
+    assert_eq!(diff.files.len(), second.len());
+
+    dit.hard_reset(&first_commit, CheckoutMode::Safe).unwrap();
+    assert_eq!(repo.read("a.txt"), "first\n");
+    assert_eq!(repo.read("src/module_7.txt"), first[7].1);
+
+    dit.hard_reset(&second_commit, CheckoutMode::Safe).unwrap();
+    assert_eq!(repo.read("a.txt"), "second\n");
+    assert_eq!(repo.read("src/module_7.txt"), second[7].1);
+}
+
+
+#[test]
+fn repacked_objects_are_read_back() {
+    check_round_trip("pack-plain", false);
+}
+
+#[test]
+fn repacked_objects_are_read_back_with_a_dictionary() {
+    check_round_trip("pack-dictionary", true);
+}
+
+#[test]
+fn repacking_again_replaces_the_pack() {
+    let mut repo = TestRepo::new("pack-again");
+    repo.commit(&similar_files(1));
+    let first = repo.dit.repack(false).unwrap();
+
+    let head = repo.commit(&similar_files(2));
+    assert!(repo.loose_objects() > 0);
+
+    let second = repo.dit.repack(false).unwrap();
+    assert!(second.packed > first.packed);
+    assert_ne!(second.pack, first.pack);
+    assert_eq!(repo.loose_objects(), 0);
+    assert_eq!(count_files(&repo.path.join(PACKS_ROOT)), 2);
+
+    let dit = Dit::from(&repo.path).unwrap();
+    assert!(dit.fsck().unwrap().is_ok());
+    assert_eq!(dit.get_head_commit().unwrap(), Some(head));
+}
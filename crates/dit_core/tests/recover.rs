@@ -0,0 +1,121 @@
+use dit_core::Dit;
+use dit_core::api_models::checkout::CheckoutMode;
+use dit_core::errors::{CheckoutError, DitCoreError};
+use std::fs;
+use std::path::PathBuf;
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, dit }
+    }
+
+    fn read(&self, rel_path: &str) -> String {
+        fs::read_to_string(self.path.join(rel_path)).unwrap()
+    }
+
+    fn commit(&mut self, files: &[(&str, &str)]) -> String {
+        for (rel_path, content) in files {
+            fs::write(self.path.join(rel_path), content).unwrap();
+        }
+        let paths: Vec<PathBuf> = files.iter().map(|(rel_path, _)| self.path.join(rel_path)).collect();
+        self.dit.add_files(&paths).unwrap();
+        self.dit.commit("commit", None::<String>).unwrap();
+        self.dit.get_head_commit().unwrap().unwrap()
+    }
+
+    /// Creates the branch `feature` which changes `a.txt` and adds `b.txt`, then
+    /// interrupts switching to it by a directory in the way of `b.txt`.
+    /// Returns the heads of `main` and `feature`
+    fn interrupt_switch(&mut self) -> (String, String) {
+        let main = self.commit(&[("a.txt", "main\n")]);
+        self.dit.create_branch("feature").unwrap();
+        self.dit.switch_branch("feature", false, CheckoutMode::Safe).unwrap();
+        let feature = self.commit(&[("a.txt", "feature\n"), ("b.txt", "b\n")]);
+        self.dit.switch_branch("main", false, CheckoutMode::Safe).unwrap();
+
+        fs::create_dir_all(self.path.join("b.txt/inner")).unwrap();
+        assert!(self.dit.switch_branch("feature", false, CheckoutMode::Safe).is_err());
+        assert!(self.dit.interrupted_checkout().unwrap().is_some());
+
+        // Nothing else can change the repository until the update is recovered
+        assert!(matches!(
+            self.dit.switch_branch("main", false, CheckoutMode::Safe),
+            Err(DitCoreError::CheckoutError(CheckoutError::Interrupted(_)))
+        ));
+
+        fs::remove_dir_all(self.path.join("b.txt")).unwrap();
+        (main, feature)
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+
+#[test]
+fn interrupted_switch_is_continued() {
+    let mut repo = TestRepo::new("recover-continue");
+    let (_, feature) = repo.interrupt_switch();
+
+    repo.dit.recover_continue().unwrap();
+
+    assert!(repo.dit.interrupted_checkout().unwrap().is_none());
+    assert_eq!(repo.dit.get_branch().unwrap().as_deref(), Some("feature"));
+    assert_eq!(repo.dit.get_head_commit().unwrap(), Some(feature));
+    assert_eq!(repo.read("a.txt"), "feature\n");
+    assert_eq!(repo.read("b.txt"), "b\n");
+    assert!(repo.dit.diff_unstaged().unwrap().files.is_empty());
+
+    // The switch is recorded like if it hadn't been interrupted
+    repo.dit.undo().unwrap();
+    assert_eq!(repo.dit.get_branch().unwrap().as_deref(), Some("main"));
+    assert_eq!(repo.read("a.txt"), "main\n");
+}
+
+#[test]
+fn interrupted_switch_is_rolled_back() {
+    let mut repo = TestRepo::new("recover-rollback");
+    let (main, _) = repo.interrupt_switch();
+
+    repo.dit.recover_rollback().unwrap();
+
+    assert!(repo.dit.interrupted_checkout().unwrap().is_none());
+    assert_eq!(repo.dit.get_branch().unwrap().as_deref(), Some("main"));
+    assert_eq!(repo.dit.get_head_commit().unwrap(), Some(main));
+    assert_eq!(repo.read("a.txt"), "main\n");
+    assert!(!repo.path.join("b.txt").exists());
+    assert!(repo.dit.diff_unstaged().unwrap().files.is_empty());
+}
+
+#[test]
+fn recovering_requires_an_interrupted_update() {
+    let mut repo = TestRepo::new("recover-nothing");
+    repo.commit(&[("a.txt", "a\n")]);
+
+    assert!(matches!(
+        repo.dit.recover_continue(),
+        Err(DitCoreError::CheckoutError(CheckoutError::NothingToRecover))
+    ));
+    assert!(matches!(
+        repo.dit.recover_rollback(),
+        Err(DitCoreError::CheckoutError(CheckoutError::NothingToRecover))
+    ));
+}
@@ -0,0 +1,59 @@
+use dit_core::Dit;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, dit }
+    }
+
+    fn commit(&mut self, rel_path: &str, content: &str) {
+        fs::write(self.path.join(rel_path), content).unwrap();
+        self.dit.add_files(&[self.path.join(rel_path)]).unwrap();
+        self.dit.commit(format!("change {rel_path}"), None::<String>).unwrap();
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+
+#[test]
+fn torn_entry_is_ignored_and_replaced() {
+    let mut repo = TestRepo::new("reflog-torn");
+    repo.commit("a.txt", "1");
+    repo.commit("a.txt", "2");
+
+    // An append interrupted in the middle of an entry
+    let log = repo.path.join(".dit/logs/HEAD");
+    let mut file = OpenOptions::new().append(true).open(&log).unwrap();
+    file.write_all(br#"{"old":"#).unwrap();
+    drop(file);
+
+    assert_eq!(repo.dit.get_reflog("HEAD", -1).unwrap().entries.len(), 2);
+
+    repo.commit("a.txt", "3");
+    let reflog = repo.dit.get_reflog("HEAD", -1).unwrap();
+    assert_eq!(reflog.entries.len(), 3);
+    assert_eq!(Some(&reflog.entries[0].new), repo.dit.get_head_commit().unwrap().as_ref());
+    assert!(fs::read_to_string(&log).unwrap().ends_with("}\n"));
+}
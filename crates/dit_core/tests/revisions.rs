@@ -0,0 +1,137 @@
+use dit_core::Dit;
+use dit_core::api_models::checkout::CheckoutMode;
+use dit_core::api_models::merge::MergeResult;
+use dit_core::errors::{DitCoreError, RevisionError};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, dit }
+    }
+
+    /// Commits the file with the given content and returns the hash of the commit
+    fn commit(&mut self, rel_path: &str, content: &str) -> String {
+        fs::write(self.path.join(rel_path), content).unwrap();
+        self.dit.add_files([self.path.join(rel_path)]).unwrap();
+        self.dit.commit(format!("change {rel_path}"), None::<String>).unwrap();
+        self.dit.get_head_commit().unwrap().unwrap()
+    }
+
+    fn resolve(&self, revision: &str) -> Result<String, DitCoreError> {
+        self.dit.resolve_revision(revision)
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+
+#[test]
+fn tilde_follows_the_first_parents() {
+    let mut repo = TestRepo::new("rev-tilde");
+    let commits: Vec<String> = (0..4).map(|i| repo.commit("a.txt", &format!("{i}\n"))).collect();
+
+    assert_eq!(repo.resolve("HEAD").unwrap(), commits[3]);
+    assert_eq!(repo.resolve("HEAD~0").unwrap(), commits[3]);
+    assert_eq!(repo.resolve("HEAD~").unwrap(), commits[2]);
+    assert_eq!(repo.resolve("HEAD~3").unwrap(), commits[0]);
+    assert_eq!(repo.resolve("main~1~1").unwrap(), commits[1]);
+    assert_eq!(repo.resolve("HEAD^^").unwrap(), commits[1]);
+
+    assert!(matches!(
+        repo.resolve("HEAD~4"),
+        Err(DitCoreError::RevisionError(RevisionError::NoSuchParent(commit, 1))) if commit == commits[0]
+    ));
+    assert!(matches!(
+        repo.resolve("HEAD~x"),
+        Err(DitCoreError::RevisionError(RevisionError::InvalidRevision(_)))
+    ));
+}
+
+#[test]
+fn caret_selects_the_parent_of_a_merge() {
+    let mut repo = TestRepo::new("rev-caret");
+    repo.commit("a.txt", "base\n");
+    repo.dit.create_branch("feature").unwrap();
+    repo.dit.switch_branch("feature", false, CheckoutMode::Safe).unwrap();
+    let theirs = repo.commit("b.txt", "feature\n");
+    repo.dit.switch_branch("main", false, CheckoutMode::Safe).unwrap();
+    let ours = repo.commit("c.txt", "main\n");
+
+    let MergeResult::Merged(merge) = repo.dit.merge_branch("feature", CheckoutMode::Safe).unwrap() else {
+        panic!("expected a merge commit");
+    };
+
+    assert_eq!(repo.resolve("HEAD^0").unwrap(), merge);
+    assert_eq!(repo.resolve("HEAD^").unwrap(), ours);
+    assert_eq!(repo.resolve("HEAD^1").unwrap(), ours);
+    assert_eq!(repo.resolve("HEAD^2").unwrap(), theirs);
+    assert_eq!(repo.resolve("HEAD^2~1").unwrap(), repo.resolve("HEAD~2").unwrap());
+
+    assert!(matches!(
+        repo.resolve("HEAD^3"),
+        Err(DitCoreError::RevisionError(RevisionError::NoSuchParent(commit, 3))) if commit == merge
+    ));
+    assert!(matches!(
+        repo.resolve("HEAD~1^2"),
+        Err(DitCoreError::RevisionError(RevisionError::NoSuchParent(commit, 2))) if commit == ours
+    ));
+}
+
+#[test]
+fn hash_prefixes_must_be_unique() {
+    let mut repo = TestRepo::new("rev-prefix");
+
+    // With more commits than hexadecimal digits, two of them start with the same one
+    let mut by_first_digit: BTreeMap<char, Vec<String>> = BTreeMap::new();
+    for i in 0..17 {
+        let commit = repo.commit("a.txt", &format!("{i}\n"));
+        by_first_digit.entry(commit.chars().next().unwrap()).or_default().push(commit);
+    }
+
+    for (digit, commits) in &by_first_digit {
+        match commits.as_slice() {
+            [commit] => assert_eq!(repo.resolve(&digit.to_string()).unwrap(), *commit),
+            _ => assert!(matches!(
+                repo.resolve(&digit.to_string()),
+                Err(DitCoreError::RevisionError(RevisionError::AmbiguousRevision(..)))
+            )),
+        }
+    }
+    assert!(by_first_digit.values().any(|commits| commits.len() > 1));
+
+    // A longer prefix tells them apart, in any case
+    for commit in by_first_digit.values().flatten() {
+        assert_eq!(repo.resolve(&commit[..12]).unwrap(), *commit);
+        assert_eq!(repo.resolve(&commit[..12].to_ascii_uppercase()).unwrap(), *commit);
+    }
+
+    let unknown = ('0'..='9').chain('a'..='f')
+        .find(|digit| !by_first_digit.contains_key(digit))
+        .map(|digit| format!("{digit}0"))
+        .unwrap_or_else(|| String::from("not-a-revision"));
+    assert!(matches!(
+        repo.resolve(&unknown),
+        Err(DitCoreError::RevisionError(RevisionError::UnknownRevision(_)))
+    ));
+}
@@ -0,0 +1,81 @@
+use dit_core::Dit;
+use dit_core::errors::{DitCoreError, SigningError};
+use std::fs;
+use std::path::PathBuf;
+
+/// A repository in a temporary directory with a signing key next to it, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    key_dir: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let key_dir = path.with_extension("keys");
+        for dir in [&path, &key_dir] {
+            let _ = fs::remove_dir_all(dir);
+            fs::create_dir_all(dir).unwrap();
+        }
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, key_dir, dit }
+    }
+
+    fn commit(&mut self, rel_path: &str, content: &str) -> String {
+        fs::write(self.path.join(rel_path), content).unwrap();
+        self.dit.add_files(&[self.path.join(rel_path)]).unwrap();
+        self.dit.commit(format!("change {rel_path}"), None::<String>).unwrap();
+        self.dit.get_head_commit().unwrap().unwrap()
+    }
+
+    /// Requires the commits of `main` to be signed with a newly generated key
+    fn protect_main(&mut self) {
+        let key = self.key_dir.join("signing_key");
+        let public_key = Dit::generate_signing_key(&key).unwrap();
+        self.dit.config_set_signing_key(&key).unwrap();
+        self.dit.config_set_trusted_keys(vec![public_key]).unwrap();
+        self.dit.config_set_signed_branches(vec![String::from("main")]).unwrap();
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+        let _ = fs::remove_dir_all(&self.key_dir);
+    }
+}
+
+fn assert_unverified(result: Result<impl std::fmt::Debug, DitCoreError>, commit: &str) {
+    match result {
+        Err(DitCoreError::SigningError(SigningError::UnverifiedCommit(hash, branch))) => {
+            assert_eq!(hash, commit);
+            assert_eq!(branch, "main");
+        }
+        other => panic!("expected the unsigned commit to be refused, got {other:?}"),
+    }
+}
+
+
+#[test]
+fn reset_refuses_unsigned_commits_on_a_protected_branch() {
+    let mut repo = TestRepo::new("signing-reset");
+    repo.commit("a.txt", "1");
+    let unsigned = repo.commit("a.txt", "2");
+    repo.protect_main();
+
+    // Moving back to an ancestor brings in no new commits
+    repo.dit.soft_reset("HEAD~1").unwrap();
+
+    assert_unverified(repo.dit.soft_reset(&unsigned), &unsigned);
+    assert_unverified(repo.dit.mixed_reset(&unsigned), &unsigned);
+    assert_unverified(repo.dit.undo(), &unsigned);
+
+    let signed = repo.commit("b.txt", "3");
+    assert!(repo.dit.verify_commit(&signed).unwrap().is_good());
+}
@@ -0,0 +1,104 @@
+use dit_core::Dit;
+use dit_core::helpers::DitHasher;
+use std::fs;
+use std::path::PathBuf;
+
+/// A temporary directory, removed when dropped
+struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        Self { path }
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Creates a repository in `dir/source` with a single commit of `a.txt`
+fn create_source(dir: &TempDir, content: &str) -> PathBuf {
+    let path = dir.path.join("source");
+    fs::create_dir_all(&path).unwrap();
+
+    let mut dit = Dit::from(&path).unwrap();
+    dit.create_branch("main").unwrap();
+    dit.config_set_user_name(String::from("Tester")).unwrap();
+    dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+    fs::write(path.join("a.txt"), content).unwrap();
+    dit.add_files(&[path.join("a.txt")]).unwrap();
+    dit.commit("add a.txt", None::<String>).unwrap();
+
+    path
+}
+
+
+#[test]
+fn clone_transfers_canonical_objects() {
+    let dir = TempDir::new("clone-canonical");
+    let source = create_source(&dir, "hello\n");
+    let target = dir.path.join("target");
+
+    Dit::clone_from(source.to_string_lossy(), &target).unwrap();
+    assert_eq!(fs::read_to_string(target.join("a.txt")).unwrap(), "hello\n");
+}
+
+#[test]
+fn clone_transfers_legacy_history() {
+    let dir = TempDir::new("clone-legacy");
+    let source = create_source(&dir, "hello\n");
+
+    // Point the branch to a commit and a tree stored as JSON by the original version,
+    // which are identified by the hashes of their content
+    let mut hasher = DitHasher::new();
+    hasher.update(b"hello\n");
+    let blob = hasher.finalize_string();
+
+    let mut hasher = DitHasher::new();
+    hasher.update(b"a.txt");
+    hasher.update(blob.as_bytes());
+    let tree = hasher.finalize_string();
+    let tree_json = format!(r#"{{ "index": {{ "files": {{ "a.txt": {{ "hash": "{blob}" }} }} }} }}"#);
+    fs::write(source.join(".dit/trees").join(&tree), tree_json).unwrap();
+
+    let (author, message, timestamp) = ("Tester <tester@example.com>", "legacy commit", 1_600_000_000u64);
+    let mut hasher = DitHasher::new();
+    hasher.update(author.as_bytes());
+    hasher.update(message.as_bytes());
+    hasher.update(&timestamp.to_le_bytes());
+    hasher.update(tree.as_bytes());
+    hasher.update(&[0]);
+    let legacy_commit = hasher.finalize_string();
+    let commit_json = format!(
+        r#"{{ "author": "{author}", "message": "{message}", "timestamp": {timestamp}, "tree": "{tree}", "parents": [] }}"#
+    );
+    fs::write(source.join(".dit/commits").join(&legacy_commit), commit_json).unwrap();
+    fs::write(source.join(".dit/branches/main"), &legacy_commit).unwrap();
+
+    // A commit of the current format on top of the legacy one
+    let mut dit = Dit::from(&source).unwrap();
+    fs::write(source.join("b.txt"), "world\n").unwrap();
+    dit.add_files(&[source.join("b.txt")]).unwrap();
+    dit.commit("add b.txt", None::<String>).unwrap();
+
+    let target = dir.path.join("target");
+    let (mut clone, _) = Dit::clone_from(source.to_string_lossy(), &target).unwrap();
+    assert_eq!(fs::read_to_string(target.join("a.txt")).unwrap(), "hello\n");
+    assert_eq!(fs::read_to_string(target.join("b.txt")).unwrap(), "world\n");
+
+    let history = clone.get_history(-1).unwrap();
+    let hashes: Vec<&str> = history.commits.iter().map(|commit| commit.hash.as_str()).collect();
+    assert_eq!(hashes.len(), 2);
+    assert_eq!(hashes[1], legacy_commit);
+    assert_eq!(history.commits[1].message, message);
+    assert!(clone.fsck().unwrap().problems.is_empty());
+}
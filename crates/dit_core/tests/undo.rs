@@ -0,0 +1,146 @@
+use dit_core::Dit;
+use dit_core::api_models::checkout::CheckoutMode;
+use dit_core::errors::{DitCoreError, OpLogError};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A repository in a temporary directory, removed when dropped
+struct TestRepo {
+    path: PathBuf,
+    dit: Dit,
+}
+
+impl TestRepo {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("dit-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let mut dit = Dit::from(&path).unwrap();
+        dit.create_branch("main").unwrap();
+        dit.config_set_user_name(String::from("Tester")).unwrap();
+        dit.config_set_user_email(String::from("tester@example.com")).unwrap();
+
+        Self { path, dit }
+    }
+
+    fn write(&self, rel_path: &str, content: &str) {
+        fs::write(self.path.join(rel_path), content).unwrap();
+    }
+
+    fn read(&self, rel_path: &str) -> String {
+        fs::read_to_string(self.path.join(rel_path)).unwrap()
+    }
+
+    fn add(&mut self, rel_path: &str, content: &str) {
+        self.write(rel_path, content);
+        self.dit.add_files([self.path.join(rel_path)]).unwrap();
+    }
+
+    /// Commits the file with the given content and returns the hash of the commit
+    fn commit(&mut self, rel_path: &str, content: &str) -> String {
+        self.add(rel_path, content);
+        self.dit.commit(format!("change {rel_path}"), None::<String>).unwrap();
+        self.head()
+    }
+
+    fn head(&self) -> String {
+        self.dit.get_head_commit().unwrap().unwrap()
+    }
+
+    fn staged(&self) -> Vec<PathBuf> {
+        self.dit.diff_staged().unwrap().files.into_iter().map(|file| file.path).collect()
+    }
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+
+#[test]
+fn undo_steps_back_through_the_operations() {
+    let mut repo = TestRepo::new("undo-steps");
+    let first = repo.commit("a.txt", "a\n");
+    repo.add("b.txt", "b\n");
+    repo.dit.commit("add b", None::<String>).unwrap();
+
+    let undone = repo.dit.undo().unwrap();
+    assert!(undone.description.starts_with("commit"), "{}", undone.description);
+    assert_eq!(repo.head(), first);
+    assert_eq!(repo.staged(), [Path::new("b.txt")]);
+
+    let undone = repo.dit.undo().unwrap();
+    assert!(undone.description.starts_with("add"), "{}", undone.description);
+    assert!(repo.staged().is_empty());
+    assert_eq!(repo.read("b.txt"), "b\n");
+
+    // The undone operations are removed from the log
+    let operations = repo.dit.list_operations(-1).unwrap();
+    assert!(operations.iter().all(|op| op.id < undone.id));
+}
+
+#[test]
+fn undoing_a_hard_reset_restores_the_files() {
+    let mut repo = TestRepo::new("undo-reset");
+    let first = repo.commit("a.txt", "first\n");
+    let second = repo.commit("a.txt", "second\n");
+
+    repo.dit.hard_reset(&first, CheckoutMode::Safe).unwrap();
+    assert_eq!(repo.read("a.txt"), "first\n");
+
+    repo.dit.undo().unwrap();
+    assert_eq!(repo.head(), second);
+    assert_eq!(repo.read("a.txt"), "second\n");
+}
+
+#[test]
+fn undoing_a_branch_switch_returns_to_the_branch() {
+    let mut repo = TestRepo::new("undo-switch");
+    repo.commit("a.txt", "main\n");
+    repo.dit.create_branch("feature").unwrap();
+    repo.dit.switch_branch("feature", false, CheckoutMode::Safe).unwrap();
+    let feature = repo.commit("a.txt", "feature\n");
+
+    repo.dit.switch_branch("main", false, CheckoutMode::Safe).unwrap();
+    assert_eq!(repo.read("a.txt"), "main\n");
+
+    repo.dit.undo().unwrap();
+    assert_eq!(repo.dit.get_branch().unwrap().as_deref(), Some("feature"));
+    assert_eq!(repo.head(), feature);
+    assert_eq!(repo.read("a.txt"), "feature\n");
+}
+
+#[test]
+fn undo_keeps_local_changes() {
+    let mut repo = TestRepo::new("undo-dirty");
+    let first = repo.commit("a.txt", "first\n");
+    repo.commit("a.txt", "second\n");
+    repo.dit.hard_reset(&first, CheckoutMode::Safe).unwrap();
+    repo.write("a.txt", "local\n");
+
+    assert!(matches!(
+        repo.dit.undo(),
+        Err(DitCoreError::OpLogError(OpLogError::LocalChanges(_)))
+    ));
+    assert_eq!(repo.head(), first);
+    assert_eq!(repo.read("a.txt"), "local\n");
+}
+
+#[test]
+fn undo_stops_at_the_start_of_the_log() {
+    let mut repo = TestRepo::new("undo-empty");
+    repo.commit("a.txt", "a\n");
+
+    let result = loop {
+        match repo.dit.undo() {
+            Ok(_) => continue,
+            other => break other,
+        }
+    };
+
+    assert!(matches!(result, Err(DitCoreError::OpLogError(OpLogError::NothingToUndo))));
+    assert!(repo.dit.get_head_commit().unwrap().is_none());
+}
@@ -0,0 +1,7 @@
+//! Serves a Dit repository over TCP, so that it can be cloned, fetched from
+//! and pushed to using `dit://host[:port]` remotes. The wire protocol is
+//! implemented by `dit_core`; this crate accepts the connections
+
+mod server;
+
+pub use server::Server;
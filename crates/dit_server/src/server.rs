@@ -0,0 +1,106 @@
+use dit_core::Dit;
+use dit_core::errors::DitResult;
+use dit_core::helpers::{resolve_absolute_path, SERVE_MAX_CONNECTIONS, SERVE_TIMEOUT_SECS};
+use std::io::{BufReader, BufWriter};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Serves a repository to `dit://` clients. The connections are handled by a fixed
+/// number of worker threads, so at most [`SERVE_MAX_CONNECTIONS`] clients are served
+/// at once and the rest wait until a worker is free
+pub struct Server {
+    repo_path: PathBuf,
+    listener: TcpListener,
+}
+
+/// Constructors
+impl Server {
+    /// Binds the server of the repository (where `.dit` is located) to the address
+    pub fn bind<P: AsRef<Path>, A: ToSocketAddrs>(repo_path: P, address: A) -> DitResult<Self> {
+        let repo_path = resolve_absolute_path(repo_path.as_ref())?;
+
+        // Fail early if the repository can't be opened
+        Dit::from(&repo_path)?;
+
+        let listener = TcpListener::bind(address)?;
+
+        Ok(Self { repo_path, listener })
+    }
+}
+
+/// Public
+impl Server {
+    /// Returns the address the server is listening on
+    pub fn local_addr(&self) -> DitResult<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accepts the connections until the process is stopped.
+    /// The errors of the individual connections are reported to stderr
+    pub fn run(&self) -> DitResult<()> {
+        // A connection is only accepted once a worker is ready to take it
+        let (sender, receiver) = mpsc::sync_channel(0);
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for _ in 0..SERVE_MAX_CONNECTIONS {
+            let receiver = receiver.clone();
+            let repo_path = self.repo_path.clone();
+            thread::spawn(move || Self::run_worker(&repo_path, &receiver));
+        }
+
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    eprintln!("failed to accept a connection: {e}");
+                    continue;
+                }
+            };
+
+            if sender.send(stream).is_err() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Private
+impl Server {
+    /// Handles the connections one by one until the server stops
+    fn run_worker(repo_path: &Path, receiver: &Mutex<Receiver<TcpStream>>) {
+        loop {
+            let Some(stream) = receiver.lock().ok().and_then(|receiver| receiver.recv().ok()) else {
+                return;
+            };
+
+            let peer = stream.peer_addr()
+                .map(|addr| addr.to_string())
+                .unwrap_or_else(|_| String::from("unknown peer"));
+
+            if let Err(e) = Self::handle_connection(repo_path, stream) {
+                eprintln!("{peer}: {e}");
+            }
+        }
+    }
+
+    /// Serves a single client. A client which doesn't send or receive anything
+    /// for [`SERVE_TIMEOUT_SECS`] is disconnected, so it can't hold a worker forever
+    fn handle_connection(repo_path: &Path, stream: TcpStream) -> DitResult<()> {
+        let timeout = Some(Duration::from_secs(SERVE_TIMEOUT_SECS));
+        stream.set_read_timeout(timeout)?;
+        stream.set_write_timeout(timeout)?;
+
+        let dit = Dit::from(repo_path)?;
+        let reader = BufReader::new(stream.try_clone()?);
+        let writer = BufWriter::new(stream);
+
+        dit.serve_connection(reader, writer)
+    }
+}